@@ -0,0 +1,302 @@
+/*
+ * A module for reading JSON, just far enough to turn it into a tree of values--which is all a Maze
+ * needs to read back what Maze::to_json writes, without pulling in a whole serialization crate.
+ *
+ * Authors: Brandon Ikeler, Travis Hahn
+ */
+
+/*
+ * A JSON value. Objects keep their fields in the order they were written. Only the names of
+ * fields are ever strings in a Maze, so the text of any other string isn't kept.
+ */
+pub enum Value {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String,
+    Array(Vec<Value>),
+    Object(Vec<(String, Value)>),
+}
+
+impl Value {
+    /*
+     * Returns the value of the given field, if this is an object that has it.
+     */
+    pub fn field(&self, name: &str) -> Option<&Value> {
+        match self {
+            Value::Object(fields) => fields
+                .iter()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value),
+            _ => None,
+        }
+    }
+
+    /*
+     * Returns how many values this is made of, counting itself and everything nested in it.
+     */
+    pub fn count(&self) -> usize {
+        match self {
+            Value::Array(items) => 1 + items.iter().map(Value::count).sum::<usize>(),
+            Value::Object(fields) => {
+                1 + fields.iter().map(|(_, value)| value.count()).sum::<usize>()
+            }
+            _ => 1,
+        }
+    }
+}
+
+// arrays and objects nested deeper than this are refused, rather than risk overflowing the stack
+const MAX_DEPTH: usize = 64;
+
+/*
+ * Parses the given text as a single JSON value, or says why it isn't one.
+ */
+pub fn parse(text: &str) -> Result<Value, String> {
+    let mut reader = Reader {
+        bytes: text.as_bytes(),
+        pos: 0,
+    };
+    let value = reader.value(0)?;
+    reader.skip_whitespace();
+    if reader.pos != reader.bytes.len() {
+        return Err(format!("unexpected text at byte {}", reader.pos));
+    }
+    Ok(value)
+}
+
+/*
+ * Steps through the bytes of some JSON.
+ */
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl Reader<'_> {
+    fn skip_whitespace(&mut self) {
+        while self
+            .bytes
+            .get(self.pos)
+            .is_some_and(|byte| matches!(byte, b' ' | b'\t' | b'\n' | b'\r'))
+        {
+            self.pos += 1;
+        }
+    }
+
+    /*
+     * Skips past the given byte, which has to be next.
+     */
+    fn expect(&mut self, byte: u8) -> Result<(), String> {
+        self.skip_whitespace();
+        if self.bytes.get(self.pos) != Some(&byte) {
+            return Err(format!("expected '{}' at byte {}", byte as char, self.pos));
+        }
+        self.pos += 1;
+        Ok(())
+    }
+
+    /*
+     * Skips past the given byte if it's next, and returns whether it was.
+     */
+    fn accept(&mut self, byte: u8) -> bool {
+        self.skip_whitespace();
+        let accepted = self.bytes.get(self.pos) == Some(&byte);
+        if accepted {
+            self.pos += 1;
+        }
+        accepted
+    }
+
+    fn value(&mut self, depth: usize) -> Result<Value, String> {
+        if depth > MAX_DEPTH {
+            return Err(String::from("nested too deeply"));
+        }
+        self.skip_whitespace();
+        match self.bytes.get(self.pos) {
+            Some(b'{') => {
+                self.pos += 1;
+                let mut fields: Vec<(String, Value)> = vec![];
+                if !self.accept(b'}') {
+                    loop {
+                        self.skip_whitespace();
+                        let key = self.string()?;
+                        self.expect(b':')?;
+                        fields.push((key, self.value(depth + 1)?));
+                        if !self.accept(b',') {
+                            self.expect(b'}')?;
+                            break;
+                        }
+                    }
+                }
+                Ok(Value::Object(fields))
+            }
+            Some(b'[') => {
+                self.pos += 1;
+                let mut items: Vec<Value> = vec![];
+                if !self.accept(b']') {
+                    loop {
+                        items.push(self.value(depth + 1)?);
+                        if !self.accept(b',') {
+                            self.expect(b']')?;
+                            break;
+                        }
+                    }
+                }
+                Ok(Value::Array(items))
+            }
+            Some(b'"') => self.string().map(|_| Value::String),
+            Some(b't') => self.word("true", Value::Bool(true)),
+            Some(b'f') => self.word("false", Value::Bool(false)),
+            Some(b'n') => self.word("null", Value::Null),
+            Some(b'-' | b'0'..=b'9') => self.number(),
+            Some(_) => Err(format!("unexpected character at byte {}", self.pos)),
+            None => Err(String::from("unexpected end of JSON")),
+        }
+    }
+
+    fn word(&mut self, word: &str, value: Value) -> Result<Value, String> {
+        if !self.bytes[self.pos..].starts_with(word.as_bytes()) {
+            return Err(format!("expected {} at byte {}", word, self.pos));
+        }
+        self.pos += word.len();
+        Ok(value)
+    }
+
+    fn number(&mut self) -> Result<Value, String> {
+        let start = self.pos;
+        while self
+            .bytes
+            .get(self.pos)
+            .is_some_and(|byte| matches!(byte, b'-' | b'+' | b'.' | b'e' | b'E' | b'0'..=b'9'))
+        {
+            self.pos += 1;
+        }
+        // the bytes are all ASCII, so they're sure to be valid UTF-8
+        std::str::from_utf8(&self.bytes[start..self.pos])
+            .ok()
+            .and_then(|number| number.parse().ok())
+            .map(Value::Number)
+            .ok_or_else(|| format!("expected a number at byte {}", start))
+    }
+
+    /*
+     * Reads a string, which has to be next, undoing any escapes in it.
+     */
+    fn string(&mut self) -> Result<String, String> {
+        let start = self.pos;
+        self.expect(b'"')?;
+        let mut string: Vec<u8> = vec![];
+        loop {
+            match self.bytes.get(self.pos) {
+                Some(b'"') => break,
+                Some(b'\\') => {
+                    let escaped = match self.bytes.get(self.pos + 1) {
+                        Some(b'"') => '"',
+                        Some(b'\\') => '\\',
+                        Some(b'/') => '/',
+                        Some(b'b') => '\u{8}',
+                        Some(b'f') => '\u{c}',
+                        Some(b'n') => '\n',
+                        Some(b'r') => '\r',
+                        Some(b't') => '\t',
+                        Some(b'u') => {
+                            // surrogate pairs aren't put back together; they never come up in a
+                            // Maze's field names
+                            let digits = self
+                                .bytes
+                                .get(self.pos + 2..self.pos + 6)
+                                .and_then(|digits| std::str::from_utf8(digits).ok())
+                                .and_then(|digits| u32::from_str_radix(digits, 16).ok())
+                                .ok_or_else(|| format!("bad escape at byte {}", self.pos))?;
+                            self.pos += 4;
+                            char::from_u32(digits).unwrap_or('\u{fffd}')
+                        }
+                        _ => return Err(format!("bad escape at byte {}", self.pos)),
+                    };
+                    string.extend_from_slice(escaped.encode_utf8(&mut [0; 4]).as_bytes());
+                    self.pos += 2;
+                }
+                Some(&byte) => {
+                    string.push(byte);
+                    self.pos += 1;
+                }
+                None => return Err(format!("unterminated string at byte {}", start)),
+            }
+        }
+        self.pos += 1;
+        // the text this came from was valid UTF-8, and escapes only add whole characters
+        Ok(String::from_utf8(string).expect("JSON strings are valid UTF-8"))
+    }
+}
+
+/*
+ * Reads a value out of whatever serde is deserializing, so a Maze in a readable format can be
+ * checked just as from_json checks one.
+ */
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Value {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(ValueVisitor)
+    }
+}
+
+#[cfg(feature = "serde")]
+struct ValueVisitor;
+
+#[cfg(feature = "serde")]
+impl<'de> serde::de::Visitor<'de> for ValueVisitor {
+    type Value = Value;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("a JSON value")
+    }
+
+    fn visit_unit<E>(self) -> Result<Value, E> {
+        Ok(Value::Null)
+    }
+
+    fn visit_none<E>(self) -> Result<Value, E> {
+        Ok(Value::Null)
+    }
+
+    fn visit_some<D: serde::Deserializer<'de>>(self, deserializer: D) -> Result<Value, D::Error> {
+        serde::Deserialize::deserialize(deserializer)
+    }
+
+    fn visit_bool<E>(self, value: bool) -> Result<Value, E> {
+        Ok(Value::Bool(value))
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Value, E> {
+        Ok(Value::Number(value as f64))
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Value, E> {
+        Ok(Value::Number(value as f64))
+    }
+
+    fn visit_f64<E>(self, value: f64) -> Result<Value, E> {
+        Ok(Value::Number(value))
+    }
+
+    fn visit_str<E>(self, _: &str) -> Result<Value, E> {
+        Ok(Value::String)
+    }
+
+    fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Value, A::Error> {
+        let mut items = Vec::new();
+        while let Some(item) = seq.next_element()? {
+            items.push(item);
+        }
+        Ok(Value::Array(items))
+    }
+
+    fn visit_map<A: serde::de::MapAccess<'de>>(self, mut map: A) -> Result<Value, A::Error> {
+        let mut fields = Vec::new();
+        while let Some(field) = map.next_entry::<String, Value>()? {
+            fields.push(field);
+        }
+        Ok(Value::Object(fields))
+    }
+}
@@ -0,0 +1,28 @@
+/*!
+ * Generating, representing, and solving two-dimensional Mazes. A Maze is carved out of a grid of
+ * cells by one of many creation algorithms, entered at the top-left and exited at the
+ * bottom-right, and can be solved by any of many solving algorithms, which mark the solution on
+ * it.
+ *
+ * ```
+ * use rust_maze::{CreationAlgorithm, Maze, SolvingAlgorithm};
+ *
+ * let mut maze = Maze::new_from((21, 41), CreationAlgorithm::Kruskal);
+ * let report = maze.solve_from(SolvingAlgorithm::BreadthFirst).unwrap();
+ * assert!(maze.is_solved());
+ * assert_eq!(report.path_length, maze.solution_length());
+ * println!("{}", maze);
+ * ```
+ *
+ * Everything lives in the maze_operations module, and is re-exported here.
+ *
+ * Authors: Brandon Ikeler, Travis Hahn
+ */
+
+mod json;
+mod maze;
+#[cfg(feature = "image")]
+mod netpbm;
+
+pub use maze::maze_operations;
+pub use maze::maze_operations::*;
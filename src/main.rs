@@ -1,80 +1,95 @@
 /*
  * Asks the user if they'd like to create and solve a maze. If they do, they're prompted for the
  * dimensions of their maze, and which maze generation algorithm they'd like to employ. Then, their
- * maze is printed with the solution computed via recursive backtracking and dead-end filling, along
- * with the amount of time it took to compute each solution.
+ * maze is solved via every solving algorithm, and a table of how long each one took and how much
+ * of the maze it explored is printed, along with the maze and its solution, which can then be
+ * saved to a file. A maze saved before can be loaded and solved instead.
  * This can be repeated as many times as the user requests, until they quit the program.
  *
  * Author: Brandon Ikeler, Travis Hahn
  */
 
-mod maze;
-use maze::maze_operations;
-use std::io;
-use std::time::Instant;
+use rand::Rng;
+use rust_maze::*;
+use std::io::{self, Write};
+use std::ops::{ControlFlow, RangeInclusive};
 
-use crate::maze_operations::*;
 fn main() {
     let mut maze;
 
     // Prompt the user whether they'd like to continue. If so, ask what dimensions they'd like it to
     // be and which algorithm should be used to generate it.
     loop {
-        let mut input = String::new();
-        let mut continue_choice = 0;
-
         // Get user's choice--do they want to keep generating mazes, or are they done?
-        while {
-            input.clear();
-            println!("Enter 1 to create and solve a maze.\nEnter 2 to quit.");
-            io::stdin()
-                .read_line(&mut input)
-                .expect("Failed to read line");
-
-            // User pressed enter without typing anything
-            input.trim().is_empty() && {
-                println!("No input detected."); // side-effects are allowed in expressions!
-                true
-            } || {
-                match input.trim().parse::<i32>() {
-                    // User correctly input a value of 1 or 2
-                    Ok(parsed) if 1 <= parsed && parsed <= 2 => {
-                        continue_choice = parsed;
-                        false
-                    }
-                    // User input an integer, but not a 1 or a 2
-                    Ok(_) => {
-                        println!("Please enter an acceptable integer.");
-                        true
-                    }
-                    // User didn't input an integer
-                    Err(_) => {
-                        println!("Expected an integer.");
-                        true
-                    }
-                }
-            }
-        } { /* this is technically the loop body */ }
+        let continue_choice = read_choice(
+            "Enter 1 to create and solve a maze.\nEnter 2 to load a maze from a file and solve it.\nEnter 3 to quit.",
+            1..=3,
+        );
 
         match continue_choice {
             // User is done making mazes. :(
-            2 => {
+            3 => {
                 break;
             }
+            // User wants to solve a maze they saved before
+            2 => {
+                let mut input = String::new();
+                println!("Enter the path of the maze file to load.");
+                io::stdin()
+                    .read_line(&mut input)
+                    .expect("Failed to read line.");
+                maze = match Maze::load(std::path::Path::new(input.trim())) {
+                    Ok(maze) => maze,
+                    Err(error) => {
+                        println!("Couldn't load that maze: {}.", error);
+                        continue;
+                    }
+                };
+                // solve it from scratch, even if it was saved solved
+                maze.unsolve();
+                match maze.solve_from(SolvingAlgorithm::BreadthFirst) {
+                    Ok(report) => {
+                        println!("{}", maze);
+                        println!(
+                            "Here's the path found via {}, {} cells long.",
+                            report.algorithm.name(),
+                            report.path_length
+                        );
+                        offer_save(&maze);
+                    }
+                    Err(error) => {
+                        println!("{}", maze);
+                        println!("Couldn't solve that maze: {}.", error);
+                    }
+                }
+            }
             // User wants to generate a maze!
             1 => {
                 let mut input = String::new();
                 let mut rows = 0;
                 let mut cols = 0;
 
+                // Should the maze be shaped like an image?
+                #[cfg(feature = "image")]
+                let image_path = {
+                    println!("Enter the path of a netpbm image to shape the maze like (leave blank to skip).");
+                    io::stdin()
+                        .read_line(&mut input)
+                        .expect("Failed to read line.");
+                    let path = input.trim().to_string();
+                    (!path.is_empty()).then_some(path)
+                };
+                #[cfg(not(feature = "image"))]
+                let image_path: Option<String> = None;
+
                 // What dimensions do they want the maze to be?
-                while {
+                while image_path.is_none() && {
                     input.clear();
                     println!("Enter the dimensions for the maze in format: rows cols.");
                     io::stdin()
                         .read_line(&mut input)
                         .expect("Failed to read line.");
-                    let mut nums = input.trim().split_whitespace();
+                    let mut nums = input.split_whitespace();
                     ({
                         match nums.next() {
                             Some(next) => match next.parse::<usize>() {
@@ -117,113 +132,530 @@ fn main() {
                         }
                     }) || {
                         // User input values for rows and cols, but at least one of them was less
-                        // than 3--and mazes smaller than 3x3 don't make sense.
-                        let unacceptable_size = cols < 3 || rows < 3;
-                        unacceptable_size && {
-                            println!("Rows and cols must be 3 or greater.");
-                            true
+                        // than 3--and mazes smaller than 3x3 don't make sense--or together they're
+                        // too big to fit in memory.
+                        match Maze::check_dimensions((rows, cols)) {
+                            Ok(()) => false,
+                            Err(MazeError::DimensionsTooLarge) => {
+                                println!("That maze is too big; try fewer rows or cols.");
+                                true
+                            }
+                            Err(_) => {
+                                println!("Rows and cols must be 3 or greater.");
+                                true
+                            }
                         }
                     }
                 } {}
 
-                let mut input = String::new();
-                let mut algorithm_choice = 0;
-
                 // Which maze generation algorithm would they like to employ?
-                while {
-                    input.clear();
-                    println!(concat!(
+                let algorithm_choice = read_choice(
+                    concat!(
                         "Choose which algorithm to use to generate the maze:\n",
                         "Enter 1 to use Prim's algorithm.\n",
                         "Enter 2 to perform a random walk.\n",
-                        "Enter 3 to recursively divide."
-                    ));
-                    io::stdin()
-                        .read_line(&mut input)
-                        .expect("Failed to read line");
-
-                    // User pressed enter without typing anything
-                    input.trim().is_empty() && {
-                        println!("No input detected.");
-                        true
-                    } || {
-                        match input.trim().parse::<i32>() {
-                            // User correctly input a value from 1 to 3
-                            Ok(parsed) if 1 <= parsed && parsed <= 3 => {
-                                algorithm_choice = parsed;
-                                false
-                            }
-                            // User input an integer, but it wasn't from 1 to 3
-                            Ok(_) => {
-                                println!("Please enter an acceptable integer.");
-                                true
-                            }
-                            // User typed something other than an integer
-                            Err(_) => {
-                                println!("Expected an integer.");
-                                true
-                            }
+                        "Enter 3 to recursively divide.\n",
+                        "Enter 4 to use Kruskal's algorithm.\n",
+                        "Enter 5 to use Eller's algorithm.\n",
+                        "Enter 6 to use Wilson's algorithm.\n",
+                        "Enter 7 to use the Aldous-Broder algorithm.\n",
+                        "Enter 8 to hunt and kill.\n",
+                        "Enter 9 to use the Sidewinder algorithm.\n",
+                        "Enter 10 to carve a binary tree.\n",
+                        "Enter 11 to grow a tree.\n",
+                        "Enter 12 to use a recursive backtracker.\n",
+                        "Enter 13 to shift the origin.\n",
+                        "Enter 14 to generate a dungeon of rooms and corridors.\n",
+                        "Enter 15 to carve a unicursal labyrinth.\n",
+                        "Enter 16 to grow a cave with a cellular automaton.\n",
+                        "Enter 17 to use Prim's algorithm, weighted toward straight corridors.\n",
+                        "Enter 18 to tessellate a maze out of copies of itself."
+                    ),
+                    1..=18,
+                );
+
+                let algorithm = match algorithm_choice {
+                    1 => CreationAlgorithm::Prim,
+                    2 => CreationAlgorithm::RandomWalk,
+                    3 => CreationAlgorithm::RecursiveDivision {
+                        room_chance: 0.0,
+                        min_room_size: 0,
+                    },
+                    4 => CreationAlgorithm::Kruskal,
+                    5 => CreationAlgorithm::Eller,
+                    6 => CreationAlgorithm::Wilson,
+                    7 => CreationAlgorithm::AldousBroder,
+                    8 => CreationAlgorithm::HuntAndKill,
+                    9 => CreationAlgorithm::Sidewinder,
+                    10 => {
+                        // Which corner should the binary tree's passages flow toward?
+                        let bias = match read_choice(
+                            concat!(
+                                "Choose which corner the binary tree should be biased toward:\n",
+                                "Enter 1 for the north-east corner.\n",
+                                "Enter 2 for the north-west corner.\n",
+                                "Enter 3 for the south-east corner.\n",
+                                "Enter 4 for the south-west corner."
+                            ),
+                            1..=4,
+                        ) {
+                            1 => Bias::NorthEast,
+                            2 => Bias::NorthWest,
+                            3 => Bias::SouthEast,
+                            _ => Bias::SouthWest,
+                        };
+                        CreationAlgorithm::BinaryTree(bias)
+                    }
+                    11 => {
+                        // Which cell should the tree grow from next?
+                        let strategy = match read_choice(
+                            concat!(
+                                "Choose which cell the tree should keep growing from:\n",
+                                "Enter 1 to grow from the newest cell.\n",
+                                "Enter 2 to grow from the oldest cell.\n",
+                                "Enter 3 to grow from a random cell.\n",
+                                "Enter 4 to mix the newest and random cells."
+                            ),
+                            1..=4,
+                        ) {
+                            1 => GrowStrategy::Newest,
+                            2 => GrowStrategy::Oldest,
+                            3 => GrowStrategy::Random,
+                            _ => GrowStrategy::Mix(read_choice(
+                                "Enter the percentage of the time to grow from the newest cell (0-100).",
+                                0..=100,
+                            ) as u8),
+                        };
+                        CreationAlgorithm::GrowingTree(strategy)
+                    }
+                    12 => CreationAlgorithm::RecursiveBacktracker,
+                    13 => {
+                        // How many times should the origin move? About ten times the number of
+                        // cells gives a well-mixed maze.
+                        let iterations =
+                            read_choice("Enter how many times to shift the origin.", 0..=i32::MAX)
+                                as usize;
+                        CreationAlgorithm::OriginShift { iterations }
+                    }
+                    14 => {
+                        // How many rooms should it try to fit, and how big should they be?
+                        let room_attempts = read_choice(
+                            "Enter how many times to try placing a room.",
+                            0..=i32::MAX,
+                        ) as usize;
+                        let min_room = read_choice(
+                            "Enter the smallest length (in cells) of a room's side.",
+                            1..=i32::MAX,
+                        ) as usize;
+                        let max_room = read_choice(
+                            "Enter the largest length (in cells) of a room's side.",
+                            min_room as i32..=i32::MAX,
+                        ) as usize;
+                        CreationAlgorithm::Dungeon {
+                            room_attempts,
+                            min_room,
+                            max_room,
                         }
                     }
-                } {}
-
-                match algorithm_choice {
-                    1 => {
-                        maze = Maze::new_from((rows, cols), CreationAlgorithm::Prim);
+                    15 => CreationAlgorithm::Unicursal,
+                    16 => {
+                        // How dense should the initial noise be, and how much should it be
+                        // smoothed? Around 45 percent and 4 or 5 passes makes for good caves.
+                        let fill_percentage = read_choice(
+                            "Enter the percentage of cells to start out as walls (0-100).",
+                            0..=100,
+                        );
+                        let iterations =
+                            read_choice("Enter how many times to smooth the cave.", 0..=i32::MAX)
+                                as usize;
+                        CreationAlgorithm::Cellular {
+                            fill_probability: fill_percentage as f64 / 100.0,
+                            iterations,
+                        }
                     }
-                    2 => {
-                        maze = Maze::new_from((rows, cols), CreationAlgorithm::RandomWalk);
+                    17 => {
+                        // How strongly should the corridors prefer to keep going straight?
+                        let straightness = read_choice(
+                            "Enter the percentage of the time to carry a corridor straight on (0-100).",
+                            0..=100,
+                        );
+                        CreationAlgorithm::WeightedPrim {
+                            straightness: straightness as f64 / 100.0,
+                        }
                     }
-                    3 => {
-                        maze = Maze::new_from((rows, cols), CreationAlgorithm::RecursiveDivision);
+                    18 => CreationAlgorithm::Fractal,
+                    // read_choice only hands back 1 through 18, so this can't ever execute
+                    _ => unreachable!("algorithm choices only go up to 18"),
+                };
+
+                // Should some of the dead ends be knocked out, leaving loops in the maze?
+                let braid_percentage = read_choice(
+                    "Enter the percentage of dead ends to braid out of the maze (0 to skip).",
+                    0..=100,
+                );
+                // images aren't shaped from a seed, so there's nothing to recreate them from
+                let seed_used: Option<i32>;
+                (maze, seed_used) = match image_path {
+                    #[cfg(feature = "image")]
+                    Some(path) => {
+                        // How bright does a pixel need to be to carve through?
+                        let threshold = read_choice(
+                            "Enter how bright (0-255) a pixel must be for the maze to pass through it.",
+                            0..=255,
+                        ) as u8;
+                        match Maze::from_image_mask(
+                            std::path::Path::new(&path),
+                            threshold,
+                            algorithm,
+                        ) {
+                            Ok(mut maze) => {
+                                if braid_percentage > 0 {
+                                    maze.braid(braid_percentage as f64 / 100.0);
+                                }
+                                (maze, None)
+                            }
+                            Err(error) => {
+                                println!("Couldn't shape a maze like that image: {}.", error);
+                                continue;
+                            }
+                        }
                     }
                     _ => {
-                        maze = Maze::new((rows, cols)); // unreachable
+                        // Should some extra walls be knocked down, leaving more than one route
+                        // through it?
+                        let loop_percentage = read_choice(
+                            "Enter the percentage of walls to knock down to create loops (0 to skip).",
+                            0..=100,
+                        );
+                        // Is this a maze the user has seen before?
+                        let seed = match read_choice(
+                            "Enter a seed to recreate a maze from before, or 0 for a new one.",
+                            0..=i32::MAX,
+                        ) {
+                            0 => rand::thread_rng().gen_range(1..=i32::MAX),
+                            seed => seed,
+                        };
+                        // Should the way in or out be somewhere other than the corners?
+                        let mut builder = Maze::builder()
+                            .dimensions(rows, cols)
+                            .algorithm(algorithm)
+                            .loops(loop_percentage as f64 / 100.0)
+                            .braid(braid_percentage as f64 / 100.0)
+                            .seed(seed as u64);
+                        if let Some((edge, offset)) = read_opening("entrance") {
+                            builder = builder.entrance(edge, offset);
+                        }
+                        if let Some((edge, offset)) = read_opening("exit") {
+                            builder = builder.exit(edge, offset);
+                        }
+                        // a big maze takes a while to carve out, so show how far along it is
+                        let big = rows * cols > 250_000;
+                        if big {
+                            let mut shown = None;
+                            builder = builder.on_progress(move |progress| {
+                                let percent = (progress.carved * 100 / progress.total).min(100);
+                                if shown != Some(percent) {
+                                    shown = Some(percent);
+                                    print!("\rGenerating the maze: {}%", percent);
+                                    let _ = io::stdout().flush();
+                                }
+                                ControlFlow::Continue(())
+                            });
+                        }
+                        let built = builder.build();
+                        if big {
+                            println!("\rGenerating the maze: done.");
+                        }
+                        match built {
+                            Ok(maze) => (maze, Some(seed)),
+                            Err(error) => {
+                                println!("Couldn't create that maze: {}.", error);
+                                continue;
+                            }
+                        }
                     }
-                }
+                };
                 println!("{}", maze);
+                if let Some(seed) = seed_used {
+                    println!("This maze's seed is {}; enter it again with the same choices to recreate it.", seed);
+                }
+                println!(
+                    "It has {} dead ends and {} junctions.",
+                    maze.dead_ends().len(),
+                    maze.junctions().len()
+                );
 
-                // Time solving via recursive backtracking
-                println!("Solving via recursive backtracking (press enter to continue).");
+                // Time solving via each algorithm, and count how many cells each one explored
+                let (height, width) = maze.dimensions();
+                if height * width > 100_000 {
+                    // recursing once per cell on the path can overflow the stack in a huge maze
+                    println!("This maze is too big to backtrack through recursively, so only iterative backtracking will be compared.");
+                }
+                if height * width > 40_000 {
+                    // iterative deepening searches the maze over again for each pass
+                    println!("This maze is too big to search over and over again, so IDA* search won't be compared.");
+                }
+                println!("Solving via every algorithm (press enter to continue).");
                 let mut input = String::new();
                 let _ = io::stdin().read_line(&mut input);
 
-                let timer = Instant::now();
-                maze.solve_from(SolvingAlgorithm::RecursiveBacktracking);
-                let duration = timer.elapsed().as_micros();
-
-                println!("{}", maze);
+                let comparisons = maze.compare_solvers();
                 println!(
-                    "It took {:?} microseconds to solve via recursive backtracking.",
-                    duration
+                    "{:<40}{:>14}{:>16}{:>14}{:>13}",
+                    "Algorithm", "Microseconds", "Cells explored", "Cells filled", "Path length"
                 );
+                for comparison in &comparisons {
+                    match &comparison.outcome {
+                        Ok(report) => println!(
+                            "{:<40}{:>14}{:>16}{:>14}{:>13}",
+                            report.algorithm.name(),
+                            report.elapsed.as_micros(),
+                            report.cells_visited,
+                            // only dead-end filling fills anything in
+                            report
+                                .cells_filled
+                                .map_or(String::from("-"), |filled| filled.to_string()),
+                            report.path_length
+                        ),
+                        Err(error) => println!("{:<40}{}", comparison.algorithm.name(), error),
+                    }
+                }
+                // IDA* trades time for memory, by searching over and over again
+                for comparison in &comparisons {
+                    if let Ok(report) = &comparison.outcome {
+                        if let Some(iterations) = report.iterations {
+                            println!(
+                                "{} took {} passes, exploring {} cells in all while only remembering the path it was on.",
+                                comparison.algorithm.name(),
+                                iterations,
+                                report.cells_visited
+                            );
+                        }
+                    }
+                }
+                if let Some(last) = comparisons.last().filter(|last| last.outcome.is_ok()) {
+                    println!("{}", maze);
+                    println!("Here's the path found via {}.", last.algorithm.name());
+                    offer_save(&maze);
+                }
 
-                maze.unsolve();
+                // Jump point search shines where there are choices to skip over, so compare it
+                // with A* on a copy of the maze with every dead end braided out
+                let mut braided = maze.clone();
+                braided.braid(1.0);
+                if braided
+                    .solve_from(SolvingAlgorithm::AStar(Heuristic::Manhattan))
+                    .is_ok()
+                {
+                    let (a_star, a_star_length) =
+                        (braided.cells_explored(), braided.solution_length());
+                    let _ = braided.solve_from(SolvingAlgorithm::AStar(Heuristic::Weighted(2.0)));
+                    let (weighted, weighted_length) =
+                        (braided.cells_explored(), braided.solution_length());
+                    let _ = braided.solve_from(SolvingAlgorithm::JumpPoint);
+                    println!(
+                        "With every dead end braided out, A* search explores {} cells, while jump point search explores {}.",
+                        a_star,
+                        braided.cells_explored()
+                    );
+                    println!(
+                        "Weighted A* search explores {} cells there, for a path {} cells long (instead of {}).",
+                        weighted, weighted_length, a_star_length
+                    );
+                }
 
-                // Time solving via dead-end filling
-                println!("Solving via dead-end filling (press enter to continue).");
-                let mut input = String::new();
-                let _ = io::stdin().read_line(&mut input);
+                // Compare how far each hand had to walk in the same maze
+                let mut walks: Vec<String> = vec![];
+                for (algorithm, hand) in [
+                    (SolvingAlgorithm::WallFollowerLeft, "left"),
+                    (SolvingAlgorithm::WallFollowerRight, "right"),
+                ] {
+                    if maze.solve_from(algorithm).is_ok() {
+                        walks.push(format!(
+                            "the {} hand marked {} cells in {} steps",
+                            hand,
+                            maze.solution_length(),
+                            maze.last_solution_steps().unwrap_or(0)
+                        ));
+                    } else {
+                        walks.push(format!("the {} hand went around in circles", hand));
+                    }
+                }
+                maze.unsolve();
+                println!("Following the wall, {}.", walks.join(", while "));
 
-                let timer = Instant::now();
-                maze.solve_from(SolvingAlgorithm::DeadEndFilling);
-                let duration = timer.elapsed().as_micros();
+                // How does a mouse wandering at random compare?
+                let runs = read_choice(
+                    "Enter how many times to send a random mouse through the maze (0 to skip).",
+                    0..=i32::MAX,
+                );
+                let mouse = SolvingAlgorithm::RandomMouse {
+                    max_steps: 100 * height * width,
+                };
+                let mut steps: Vec<usize> = vec![];
+                for _ in 0..runs {
+                    if maze.solve_from(mouse).is_ok() {
+                        steps.extend(maze.last_solution_steps());
+                    }
+                }
+                maze.unsolve();
+                if let (Some(min), Some(max)) = (steps.iter().min(), steps.iter().max()) {
+                    println!(
+                        "The mouse made it {} out of {} times, taking {} steps at least, {} on average, and {} at most.",
+                        steps.len(),
+                        runs,
+                        min,
+                        steps.iter().sum::<usize>() / steps.len(),
+                        max
+                    );
+                } else if runs > 0 {
+                    println!("The mouse never made it through the maze.");
+                }
 
-                println!("{}", maze);
-                println!(
-                    "It took {:?} microseconds to solve via dead-end filling.",
-                    duration
+                // Show the order each kind of search looked at the cells in
+                let watch = read_choice(
+                    "Enter 1 to see how breadth-first search and backtracking explore the maze, or 0 to skip.",
+                    0..=1,
                 );
+                if watch == 1 {
+                    let backtracking = if height * width > 100_000 {
+                        SolvingAlgorithm::IterativeBacktracking
+                    } else {
+                        SolvingAlgorithm::RecursiveBacktracking
+                    };
+                    for algorithm in [SolvingAlgorithm::BreadthFirst, backtracking] {
+                        let trace = maze.solve_traced(algorithm);
+                        println!("{}", maze.render_trace(&trace));
+                        println!(
+                            "Each cell is labeled with the last digit of when {} looked at it ({} looks in all).",
+                            algorithm.name(),
+                            trace.order().len()
+                        );
+                    }
+                    maze.unsolve();
+                }
 
                 let mut input = String::new();
                 println!("Press enter to continue.");
                 let _ = io::stdin().read_line(&mut input);
             }
             // the only possible values of continue_choice by the point the match statement is
-            // reached are 1 and 2, so this can't ever execute.
+            // reached are 1, 2, and 3, so this can't ever execute.
             _ => {
                 panic!("Unexpected error while processing decision to continue");
             }
         }
     }
 }
+
+/*
+ * Prints the given prompt until the user enters an integer within the range of choices, then
+ * returns it.
+ */
+fn read_choice(prompt: &str, choices: RangeInclusive<i32>) -> i32 {
+    let mut input = String::new();
+    let mut choice = 0;
+
+    while {
+        input.clear();
+        println!("{}", prompt);
+        io::stdin()
+            .read_line(&mut input)
+            .expect("Failed to read line");
+
+        // User pressed enter without typing anything
+        input.trim().is_empty() && {
+            println!("No input detected."); // side-effects are allowed in expressions!
+            true
+        } || {
+            match input.trim().parse::<i32>() {
+                // User correctly input a value within the range of choices
+                Ok(parsed) if choices.contains(&parsed) => {
+                    choice = parsed;
+                    false
+                }
+                // User input an integer, but it wasn't one of the choices
+                Ok(_) => {
+                    println!("Please enter an acceptable integer.");
+                    true
+                }
+                // User didn't input an integer
+                Err(_) => {
+                    println!("Expected an integer.");
+                    true
+                }
+            }
+        }
+    } { /* this is technically the loop body */ }
+
+    choice
+}
+
+/*
+ * Asks where the given opening should go, until the user enters an edge (top, bottom, left, or
+ * right) and how many cells along it to put the opening, or presses enter to leave it where it
+ * is.
+ */
+fn read_opening(opening: &str) -> Option<(Edge, usize)> {
+    let mut input = String::new();
+    loop {
+        input.clear();
+        println!(
+            "Enter where to put the {}, as top, bottom, left, or right and a number of cells along that edge (like \"left 3\"), or press enter to leave it in the corner.",
+            opening
+        );
+        io::stdin()
+            .read_line(&mut input)
+            .expect("Failed to read line");
+
+        let words: Vec<&str> = input.split_whitespace().collect();
+        // User pressed enter without typing anything
+        if words.is_empty() {
+            return None;
+        }
+        let edge = match words[0].to_lowercase().as_str() {
+            "top" => Edge::Top,
+            "bottom" => Edge::Bottom,
+            "left" => Edge::Left,
+            "right" => Edge::Right,
+            _ => {
+                println!("Expected top, bottom, left, or right.");
+                continue;
+            }
+        };
+        match words.get(1).map(|word| word.parse::<usize>()) {
+            Some(Ok(offset)) if words.len() == 2 => return Some((edge, offset)),
+            _ => println!("Expected an edge followed by a number of cells."),
+        }
+    }
+}
+
+/*
+ * Offers to save the given maze, until the user enters a filename it can be saved to or presses
+ * enter to skip. Files ending in .bin are saved in the packed binary format, and anything else as
+ * text.
+ */
+fn offer_save(maze: &Maze) {
+    let mut input = String::new();
+    loop {
+        input.clear();
+        println!("Save this maze? Enter a filename (ending in .bin to save it packed), or press enter to skip.");
+        io::stdin()
+            .read_line(&mut input)
+            .expect("Failed to read line");
+
+        // User pressed enter without typing anything
+        let filename = input.trim();
+        if filename.is_empty() {
+            return;
+        }
+        let path = std::path::Path::new(filename);
+        match maze.save(path, FileFormat::from_extension(path)) {
+            Ok(()) => {
+                println!("Saved the maze to {}.", path.display());
+                return;
+            }
+            Err(error) => println!("Couldn't save the maze: {}.", error),
+        }
+    }
+}
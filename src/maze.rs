@@ -10,38 +10,783 @@ extern crate rand;
 
 pub mod maze_operations {
     use colored::*;
-    use rand::{seq::SliceRandom, thread_rng, Rng};
+    use rand::{rngs::StdRng, seq::SliceRandom, thread_rng, Rng, RngCore, SeedableRng};
+    #[cfg(feature = "serde")]
+    use serde::{Deserialize, Serialize};
+    use std::cell::RefCell;
+    use std::cmp::Reverse;
+    use std::collections::{BinaryHeap, HashSet, VecDeque};
     use std::fmt;
+    use std::hash::{Hash, Hasher};
+    use std::ops::{ControlFlow, Index};
+    use std::rc::Rc;
+    use std::str::FromStr;
+    use std::time::{Duration, Instant};
 
     #[derive(Clone)]
     pub struct Maze {
-        dimensions: (usize, usize), // (height, width)
-        entrypoint: (usize, usize), // (y, x) of start
-        goalpoint: (usize, usize),  // (y, x) of end
+        dimensions: (usize, usize),           // (height, width)
+        entrypoint: (usize, usize),           // (y, x) of start
+        goalpoint: (usize, usize),            // (y, x) of end
+        exits: Vec<(usize, usize)>, // every exit, the goalpoint included while there is one
+        algorithm: Option<CreationAlgorithm>, // what carved it out, if it was a built-in one
         cells: Vec<Vec<Cell>>,
+        stats: SolveStats,                // how the last solve went
+        log: Option<Vec<CarveEvent>>,     // how it was generated, if that was recorded
+        overlay: Option<SolutionOverlay>, // what the last solve marked, kept apart from the walls
     }
 
-    #[derive(Clone)]
+    /**
+     * What it took to solve a Maze, for comparing solving algorithms.
+     */
+    #[derive(Clone, Default)]
+    struct SolveStats {
+        explored: usize,      // cells looked at while searching for the solution
+        cost: Option<usize>,  // the total cost of the path found, for solvers that weigh cells
+        steps: Option<usize>, // how many moves it took, for solvers that walk through the Maze
+        distances: Option<Vec<Vec<Option<usize>>>>, // how far each cell is from the goalpoint
+        waves: Option<Vec<Vec<Option<usize>>>>, // which wave reached each cell, for Lee's algorithm
+        iterations: Option<usize>, // how many deepening passes it took, for IDA* search
+        trace: Option<Vec<(usize, usize)>>, // every cell looked at in order, if it's being traced
+        events: Option<Vec<SolveEvent>>, // everything the solver did in order, for solve_stepwise
+        filled: Option<usize>, // how many cells were filled in, for dead-end filling
+        completed_by_bfs: bool, // whether filling left more than one path, so BFS had to finish
+        exits: Option<Vec<(usize, usize)>>, // exits a walker may stop at besides the goalpoint
+    }
+
+    /**
+     * A solve in progress: what a SolvingAlgorithm has marked and counted so far, kept apart from
+     * the Maze it's solving, which it only reads.
+     */
+    struct SolveRun<'a> {
+        maze: &'a Maze,
+        goalpoint: (usize, usize), // the exit it's headed for, or the one a walker stopped at
+        overlay: Option<SolutionOverlay>, // what it's marked
+        stats: SolveStats,         // what it's counted along the way
+    }
+
+    #[derive(Clone, PartialEq, Eq, Hash)]
     struct Cell {
         wall: bool,
-        visited: bool,
+        visited: bool, // only used while generating; solutions are marked on the overlay
+        tag: Option<u32>, // whatever the caller has attached to it, with set_tag
+        cost: u32,     // what stepping into it costs, with set_cost
     }
 
-    #[derive(Clone, Copy)]
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
     pub enum CreationAlgorithm {
         RandomWalk,
-        RecursiveDivision,
+        RecursiveDivision {
+            room_chance: f64,
+            min_room_size: usize,
+        },
         Prim,
+        Kruskal,
+        Eller,
+        Wilson,
+        AldousBroder,
+        HuntAndKill,
+        Sidewinder,
+        BinaryTree(Bias),
+        GrowingTree(GrowStrategy),
+        RecursiveBacktracker,
+        OriginShift {
+            iterations: usize,
+        },
+        Dungeon {
+            room_attempts: usize,
+            min_room: usize,
+            max_room: usize,
+        },
+        Unicursal,
+        Cellular {
+            fill_probability: f64,
+            iterations: usize,
+        },
+        WeightedPrim {
+            straightness: f64,
+        },
+        Fractal,
+    }
+
+    /**
+     * The corner a binary tree Maze's passages flow toward. Every cell carves toward one of the two
+     * sides of its corner, so the two walls meeting there end up as long, unbroken corridors.
+     */
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    pub enum Bias {
+        NorthEast,
+        NorthWest,
+        SouthEast,
+        SouthWest,
+    }
+
+    /**
+     * How the growing tree algorithm picks which active cell to grow from next. Always picking the
+     * newest cell behaves like a recursive backtracker, while picking at random behaves like Prim's
+     * algorithm. Mix(n) picks the newest cell n percent of the time, and a random one otherwise.
+     */
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    pub enum GrowStrategy {
+        Newest,
+        Oldest,
+        Random,
+        Mix(u8),
+    }
+
+    /**
+     * The symmetry of a Maze made with new_symmetric. Horizontal Mazes mirror their left and right
+     * halves, Vertical ones their top and bottom halves, and Quad ones all four quarters, while
+     * Rotational180 Mazes look the same after a half turn.
+     */
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    pub enum Symmetry {
+        None,
+        Horizontal,
+        Vertical,
+        Quad,
+        Rotational180,
+    }
+
+    /**
+     * How A* search estimates the distance left from a cell to the goalpoint. Manhattan distance
+     * is exact for a Maze without walls, so A* still finds the shortest path with it (or with
+     * the smaller Euclidean and Chebyshev distances, which would be exact if moving diagonally
+     * were allowed). Weighted(w) inflates the Manhattan distance by a factor of w, which for w
+     * over 1 tends to explore fewer cells but can find a longer path.
+     */
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    pub enum Heuristic {
+        Manhattan,
+        Euclidean,
+        Chebyshev,
+        Weighted(f32),
+    }
+
+    /**
+     * Features to build into a Maze no matter which algorithm generates the rest of it, for use
+     * with new_from_template. Open rectangles are carved out as chambers, forced walls are never
+     * carved through, and the entrance and goal (if set) are openings in the outer wall.
+     */
+    #[derive(Clone, Default)]
+    pub struct MazeTemplate {
+        open: Vec<((usize, usize), (usize, usize))>, // (top left, bottom right) of each chamber
+        walls: Vec<(usize, usize)>,
+        entrance: Option<(usize, usize)>,
+        goal: Option<(usize, usize)>,
+    }
+
+    /**
+     * What's at a single cell of a Maze, as returned by Maze::at. The entrance and exits are
+     * reported as themselves even while the solution runs through them.
+     */
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    pub enum CellKind {
+        Wall,
+        Passage,
+        Entrance,
+        Exit,
+        // a passage marked by the last solve, which for the solvers that mark everywhere they
+        // walked (and for mark_explored) is more than just the path
+        Solution,
+    }
+
+    /**
+     * A way to step from one cell of a Maze to its neighbor, as North (up), East (right), South
+     * (down), or West (left).
+     */
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    pub enum Direction {
+        North,
+        East,
+        South,
+        West,
+    }
+
+    /**
+     * One of the four sides of a Maze, for placing its entrance and exit along.
+     */
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    pub enum Edge {
+        Top,
+        Bottom,
+        Left,
+        Right,
+    }
+
+    /**
+     * The ways a Maze can be saved to a file: as Text, the same blocks it's printed as (without
+     * the colors), or as Binary, the much smaller form packed by to_bytes.
+     */
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    pub enum FileFormat {
+        Text,
+        Binary,
+    }
+
+    impl FileFormat {
+        /**
+         * Picks the format a file should be saved in by its extension: Binary for .bin, and Text
+         * for anything else.
+         */
+        pub fn from_extension(path: &std::path::Path) -> Self {
+            match path.extension() {
+                Some(extension) if extension.eq_ignore_ascii_case("bin") => FileFormat::Binary,
+                _ => FileFormat::Text,
+            }
+        }
+    }
+
+    /**
+     * The settings for creating a Maze, chained together before building it, for when a size and
+     * an algorithm aren't enough. Anything left unset is what new_from would do: Prim's
+     * algorithm, a fresh random seed, no loops or braiding, and the entrance and exit at the
+     * top-left and bottom-right. There's no default size, though.
+     *
+     * ```
+     * use rust_maze::{CreationAlgorithm, Edge, MazeBuilder};
+     *
+     * let mut maze = MazeBuilder::new()
+     *     .dimensions(21, 31)
+     *     .algorithm(CreationAlgorithm::Kruskal)
+     *     .seed(42)
+     *     .entrance(Edge::Top, 0)
+     *     .exit(Edge::Bottom, 14)
+     *     .loops(0.1)
+     *     .braid(0.5)
+     *     .build()
+     *     .unwrap();
+     * assert!(maze.solve());
+     * ```
+     */
+    #[derive(Clone)]
+    pub struct MazeBuilder {
+        dimensions: (usize, usize),
+        algorithm: CreationAlgorithm,
+        seed: Option<u64>,
+        entrance: Option<(Edge, usize)>,
+        exit: Option<(Edge, usize)>,
+        openings: Option<((usize, usize), (usize, usize))>, // exact cells, overriding the edges
+        loops: f64,               // the fraction of the walls between cells to knock down
+        braid: f64,               // the fraction of the dead ends to braid out
+        terrain: Vec<(f64, u32)>, // the fraction of the passages to cover and what it costs
+        progress: Option<ProgressCallback>, // told how generation is going, if anything is
+        log: bool,                // whether to keep a log of the generation on the Maze
+    }
+
+    // copies of a builder share the same callback
+    type ProgressCallback = Rc<RefCell<dyn FnMut(GenerationProgress) -> ControlFlow<()>>>;
+
+    /**
+     * The cells a solve marked on a Maze--its solution, plus whatever else the solver left
+     * marked, like the dead ends a wall follower walked into--kept apart from the walls, which
+     * solving never changes. A Maze shows the one it's holding (see Maze::overlay), but any number
+     * of them can be made from the same Maze with Maze::solve_overlay and drawn over it with
+     * Maze::with_overlay, to compare solvers side by side.
+     */
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    pub struct SolutionOverlay {
+        marked: Vec<Vec<bool>>,
+        count: usize, // how many cells are marked
+    }
+
+    /**
+     * A path through a Maze, as the (y, x) of each cell along it in order.
+     */
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    pub struct Path {
+        cells: Vec<(usize, usize)>,
+    }
+
+    /**
+     * How solving a Maze went, as reported by Maze::solve_from.
+     */
+    #[derive(Clone, Debug)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    pub struct SolveReport {
+        pub algorithm: SolvingAlgorithm, // which algorithm solved it
+        pub path_length: usize,          // how many cells the solution marked
+        // how many cells the algorithm looked at along the way, counting cells backtracking walked
+        // into and back out of
+        pub cells_visited: usize,
+        pub cells_filled: Option<usize>, // dead-end cells filled in, for dead-end filling
+        pub elapsed: Duration,           // how long it took
+        pub iterations: Option<usize>,   // how many deepening passes it took, for IDA* search
+        pub exit: (usize, usize),        // which exit the solution leads to
+        pub path: Path,                  // the solution, from the entrypoint to the exit
+        pub path_cost: usize,            // what walking the solution costs, as path_cost adds up
+    }
+
+    /**
+     * What the solution marked on a Maze looks like, as reported by Maze::verify_solution.
+     */
+    #[derive(Clone, Debug)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    pub struct SolutionCheck {
+        pub path_length: usize, // how many cells are marked
+        pub simple: bool,       // whether they make a single path, with no branches or loops
+    }
+
+    /**
+     * Something wrong with how a Maze is put together, as found by Maze::validate, along with
+     * the (y, x) cell where it shows up.
+     */
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    pub enum ValidationIssue {
+        // the entrance or an exit is outside the grid
+        OutsideGrid((usize, usize)),
+        // the entrance or an exit is a wall
+        BlockedOpening((usize, usize)),
+        // there are no exits at all, so there's nowhere for the way through to end
+        NoExit,
+        // the entrance is also an exit
+        EntranceIsExit((usize, usize)),
+        // the outer wall has a gap here that isn't the entrance or an exit
+        BrokenBorder((usize, usize)),
+        // this passage, and any joined to it, can't be reached from the entrance
+        Disconnected((usize, usize)),
+    }
+
+    /**
+     * How hard a Maze is to solve by hand, as scored by Maze::difficulty, along with the
+     * measurements the score comes from.
+     */
+    #[derive(Clone, Debug)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    pub struct DifficultyScore {
+        pub solution_length: usize, // how many cells the shortest path has, counting both ends
+        pub coverage: f64,          // the share of the open cells the shortest path goes through
+        pub junctions: usize,       // cells along the path with a wrong way to go, too
+        pub branches: usize,        // wrong ways leading off the path
+        pub branch_depth: f64,      // how many steps the cells off the path are from it, on average
+        pub dead_ends: usize,       // open cells with only one way out, apart from the openings
+        pub score: f64,             // from 0 (a straight corridor) toward 100
+    }
+
+    /**
+     * How one solving algorithm did on a Maze, as reported by Maze::compare_solvers.
+     */
+    #[derive(Debug)]
+    pub struct SolverComparison {
+        pub algorithm: SolvingAlgorithm,
+        pub outcome: Result<SolveReport, MazeError>,
+    }
+
+    /**
+     * The cells a solving algorithm looked at while solving a Maze, in the order it looked at
+     * them, from Maze::solve_traced.
+     */
+    pub struct SolveTrace {
+        order: Vec<(usize, usize)>,
+        index: Vec<Vec<Option<usize>>>, // when each cell was first looked at
+        solved: bool,
+    }
+
+    /**
+     * One step in solving a Maze, as handed out by Maze::solve_stepwise.
+     */
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    pub enum SolveEvent {
+        // the solver looked at the (y, x) cell, which is marked until it's backtracked or filled
+        Visited((usize, usize)),
+        // the solver backed out of the (y, x) cell, since it doesn't lead to an exit (recursive
+        // and iterative backtracking)
+        Backtracked((usize, usize)),
+        // the (y, x) cell was filled in as part of a dead end (dead-end filling)
+        Filled((usize, usize)),
+        // the solution was found, and is marked in place of everything else
+        Found(Path),
+    }
+
+    /**
+     * The steps of solving a Maze, in the order they happened, as returned by
+     * Maze::solve_stepwise. The Maze is marked a step at a time as they're taken, so between
+     * steps it can be drawn as a frame.
+     */
+    #[derive(Debug)]
+    pub struct SolveSteps<'a> {
+        maze: &'a mut Maze,
+        events: VecDeque<SolveEvent>,
+        overlay: Option<SolutionOverlay>, // what the solver left marked, put back at the end
+        path: Option<Path>,
+        finished: bool,
+    }
+
+    /**
+     * How a budgeted solve from Maze::solve_limited went: either it found the solution, or it ran
+     * out of expansions first. An exhausted solve with an empty frontier has expanded every cell
+     * it can reach without finding the goalpoint.
+     */
+    #[derive(Clone, Debug)]
+    pub enum SolveOutcome {
+        Solved(Path),
+        Exhausted {
+            visited: usize,                // how many cells have been expanded so far, in total
+            frontier: Vec<(usize, usize)>, // the cells found, but not expanded yet
+            progress: SolveProgress,       // where to pick back up via Maze::resume_limited
+        },
+    }
+
+    /**
+     * Where a budgeted solve left off, for handing back to Maze::resume_limited.
+     */
+    #[derive(Clone, Debug)]
+    pub struct SolveProgress {
+        algorithm: SolvingAlgorithm,
+        parents: Vec<Vec<Option<(usize, usize)>>>,
+        costs: Vec<Vec<usize>>, // how many steps each cell found is from the entrypoint
+        expanded: Vec<Vec<bool>>,
+        frontier: VecDeque<(usize, usize)>,
+        visited: usize,
+    }
+
+    /**
+     * The passages of a Maze as a graph, as returned by Maze::to_graph: a node for each passage
+     * (or, compressed, each junction, dead end, and opening), and an edge for each way between
+     * two of them that doesn't pass through another. Nodes are numbered by where their cells
+     * come row by row.
+     */
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct MazeGraph {
+        nodes: Vec<(usize, usize)>, // the (y, x) of each node, in the order cells gives them
+        edges: Vec<GraphEdge>,
+        adjacency: Vec<Vec<usize>>, // the edges touching each node
+    }
+
+    /**
+     * A way between two nodes of a MazeGraph.
+     */
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    pub struct GraphEdge {
+        pub from: usize,   // the lower-numbered node it joins
+        pub to: usize,     // the other node, which is the same one for a loop back to itself
+        pub length: usize, // how many steps it takes to get from one to the other
+    }
+
+    /**
+     * A way of finding a path through a Maze, for solving it with an algorithm of one's own via
+     * Maze::solve_with. Every SolvingAlgorithm is one too.
+     *
+     * ```
+     * use rust_maze::{Maze, MazeView, Path, Solver};
+     *
+     * // always takes the first way forward, so it only finds the way through some Mazes
+     * struct FirstTurn;
+     *
+     * impl Solver for FirstTurn {
+     *     fn solve(&self, maze: &MazeView) -> Option<Path> {
+     *         let mut cells = vec![maze.entrance()];
+     *         while *cells.last()? != maze.exit() {
+     *             let here = *cells.last()?;
+     *             let next = maze
+     *                 .neighbors(here)
+     *                 .into_iter()
+     *                 .find(|next| !cells.contains(next))?;
+     *             cells.push(next);
+     *         }
+     *         Some(Path::new(cells))
+     *     }
+     * }
+     *
+     * // every first way forward here leads on toward the exit
+     * let mut maze: Maze = concat!(
+     *     "██▒▒██████\n",
+     *     "██      ██\n",
+     *     "██  ██████\n",
+     *     "██      ░░\n",
+     *     "██████████",
+     * )
+     * .parse()
+     * .unwrap();
+     * assert!(maze.solve_with(&FirstTurn));
+     * assert!(maze.is_solved());
+     *
+     * // but here it heads South into the dead end first, and gives up there
+     * let mut maze: Maze = concat!(
+     *     "██▒▒████\n",
+     *     "██    ░░\n",
+     *     "██  ████\n",
+     *     "████████",
+     * )
+     * .parse()
+     * .unwrap();
+     * assert!(!maze.solve_with(&FirstTurn));
+     * assert!(!maze.is_solved());
+     * ```
+     */
+    pub trait Solver {
+        /**
+         * Returns a path from the maze's entrance to its exit, or None if there isn't one (or
+         * this Solver can't find it).
+         */
+        fn solve(&self, maze: &MazeView) -> Option<Path>;
+    }
+
+    /**
+     * A read-only look at a Maze, as handed to a Solver.
+     */
+    pub struct MazeView<'a> {
+        maze: &'a Maze,
+    }
+
+    /**
+     * The walls of a Maze, row by row: true for a wall, and false for a passage.
+     */
+    pub type WallGrid = Vec<Vec<bool>>;
+
+    /**
+     * A way of carving out a Maze, for creating one with an algorithm of one's own via
+     * Maze::new_with_generator. Every CreationAlgorithm is one too.
+     *
+     * ```
+     * use rand::RngCore;
+     * use rust_maze::{Generator, Maze, WallGrid};
+     *
+     * // a single passage snaking back and forth down the Maze
+     * struct Snake;
+     *
+     * impl Generator for Snake {
+     *     fn generate(&self, (height, width): (usize, usize), _: &mut dyn RngCore) -> WallGrid {
+     *         let mut walls = vec![vec![true; width]; height];
+     *         for y in (1..height - 1).step_by(2) {
+     *             for x in 1..width - 1 {
+     *                 walls[y][x] = false;
+     *             }
+     *             // join this row to the next one, on alternating sides
+     *             if y + 2 < height - 1 {
+     *                 let x = if y % 4 == 1 { width - 2 } else { 1 };
+     *                 walls[y + 1][x] = false;
+     *             }
+     *         }
+     *         walls
+     *     }
+     * }
+     *
+     * let maze = Maze::new_with_generator((11, 11), &Snake).unwrap();
+     * assert!(maze.validate_connected());
+     * ```
+     */
+    pub trait Generator {
+        /**
+         * Returns the walls of a new Maze of (about) the given size, making each of its random
+         * choices with rng. Every passage should be reachable from every other.
+         */
+        fn generate(&self, dimensions: (usize, usize), rng: &mut dyn RngCore) -> WallGrid;
+    }
+
+    /**
+     * One step in generating a Maze, as handed out by Maze::generate_stepwise. Walls are whole
+     * cells in a Maze, so knocking down the wall between two cells is carving out the cell in
+     * between them.
+     */
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum GenerationEvent {
+        // the (y, x) cell was carved out of the walls into a passage
+        CellCarved((usize, usize)),
+        // the (y, x) cell was filled in with a wall, which only the algorithms that build walls
+        // (like RecursiveDivision) or tidy up after themselves (like Dungeon) ever do
+        CellFilled((usize, usize)),
+        // the Maze is done, just as new_from would have made it
+        Finished(Box<Maze>),
+    }
+
+    /**
+     * The steps of generating a Maze, in the order they happened, as returned by
+     * Maze::generate_stepwise. Between steps, grid has the walls as they stood after the last
+     * one, to draw as a frame.
+     */
+    #[derive(Clone, Debug)]
+    pub struct GenerationSteps {
+        events: VecDeque<GenerationEvent>,
+        grid: WallGrid,
+        maze: Option<Maze>, // handed out once the events run out
+    }
+
+    /**
+     * One entry in the log of how a Maze was generated, as kept by a Maze built with
+     * MazeBuilder::log_generation and played back by Maze::replay. The log starts from solid
+     * walls.
+     */
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    pub enum CarveEvent {
+        // every cell was opened up at once, which is where RecursiveDivision starts from
+        Cleared,
+        // the (y, x) cell was carved out of the walls into a passage
+        Carved((usize, usize)),
+        // the (y, x) cell was filled in with a wall
+        Filled((usize, usize)),
+        // the entrance and exit were put at the given (y, x) cells, which ends the log
+        Openings {
+            entrance: (usize, usize),
+            exit: (usize, usize),
+        },
     }
 
-    #[derive(Clone, Copy)]
+    /**
+     * How far along generating a Maze is, as handed to the callback given to
+     * MazeBuilder::on_progress. How many cells an algorithm will carve is only known for certain
+     * for the ones that make perfect Mazes, so for the rest total is an estimate, and carved can
+     * end up short of it or past it.
+     */
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    pub struct GenerationProgress {
+        pub carved: usize, // how many cells have been carved out (or filled in) so far
+        pub total: usize,  // about how many will have been by the time the Maze is done
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
     pub enum SolvingAlgorithm {
         RecursiveBacktracking,
+        IterativeBacktracking,
         DeadEndFilling,
+        BreadthFirst,
+        AStar(Heuristic),
+        Dijkstra,
+        WallFollowerLeft,
+        WallFollowerRight,
+        Tremaux,
+        BidirectionalBfs,
+        GreedyBestFirst,
+        Pledge,
+        RandomMouse { max_steps: usize },
+        FloodFill,
+        JumpPoint,
+        Lee,
+        IdaStar,
+        ParallelBidirectional,
+    }
+
+    /**
+     * The ways working with a Maze can fail. Each variant notes which operations return it; the
+     * constructors that don't return a Result (new_from and the like) panic on dimensions that
+     * try_new would reject.
+     */
+    #[derive(Debug)]
+    pub enum MazeError {
+        // the Maze would be smaller than 3x3 (try_new, check_dimensions, MazeBuilder::build,
+        // new_symmetric, new_with_min_solution, new_with_text, new_from_template)
+        DimensionsTooSmall,
+        // the Maze would have too many cells to fit in memory (the same as DimensionsTooSmall,
+        // and new_masked)
+        DimensionsTooLarge,
+        // there's no cell that far along that edge of the Maze to open (MazeBuilder::build)
+        OpeningOutOfRange(Edge, usize),
+        // the entrance and an exit would be the same cell (MazeBuilder::build, set_openings,
+        // add_exit, from_walls)
+        SameOpenings,
+        // the (y, x) cell is a corner of the outer wall, which can't lead anywhere
+        // (MazeBuilder::build, set_openings, add_exit, from_walls)
+        CornerOpening((usize, usize)),
+        // every exit has been removed, so there's nowhere to solve to (solve_from)
+        NoExit,
+        // there's no exit at the (y, x) cell to remove (remove_exit)
+        NotAnExit((usize, usize)),
+        // the mask is empty, or its rows aren't all the same length (new_masked)
+        InvalidMask,
+        // the mask doesn't have room for at least two (odd, odd) cells (new_masked)
+        MaskTooSmall,
+        // the mask is split into pieces that can't be joined by a passage (new_masked,
+        // from_image_mask)
+        MaskDisconnected,
+        // the file at the path couldn't be read or written (from_image_mask, save, load)
+        Io(std::path::PathBuf, std::io::Error),
+        // the input isn't in the expected format, for the given reason (from_image_mask, from_str,
+        // from_bytes, from_json, from_grid, load, and deserializing a Maze with serde)
+        ParseError(String),
+        // the image doesn't have room for a 3x3 Maze (from_image_mask)
+        ImageTooSmall,
+        // no Maze generated had a solution through enough of its cells; the best went through
+        // this fraction of them (new_with_min_solution)
+        SolutionTooShort(f64),
+        // the region doesn't fit inside the Maze, or can't be generated (regenerate_region) or
+        // cropped (crop) at that size
+        InvalidRegion,
+        // regenerating the region kept cutting part of the Maze off (regenerate_region)
+        RegionDisconnected,
+        // the named parameter of the CreationAlgorithm isn't a finite number (try_new,
+        // MazeBuilder::build, and the constructors built on them, and regenerate_region)
+        InvalidParameter(&'static str),
+        // the text doesn't fit inside the Maze (new_with_text)
+        TextTooLarge,
+        // the font doesn't have a letter for this character (new_with_text)
+        UnsupportedCharacter(char),
+        // the template reaches outside the Maze, or doesn't leave room for a path through it
+        // (new_from_template)
+        InvalidTemplate,
+        // the template's walls split the Maze into pieces that can't be joined
+        // (new_from_template)
+        TemplateDisconnected,
+        // the (y, x) cell is outside the Maze (solve_between, solve_via, mark_path, set_openings,
+        // add_exit, apply_moves, crop, distance_map, carve, fill, MazeBuilder::build, from_walls)
+        InvalidCoordinate((usize, usize)),
+        // the (y, x) cell is a wall (solve_between, solve_via, mark_path, apply_moves,
+        // distance_map, from_walls)
+        NotOpen((usize, usize)),
+        // the solving algorithm couldn't find a path, though there is one (solve_from and the
+        // other solve_ methods that return a Result)
+        NoPath,
+        // the walls are smaller than 3x3, or their rows aren't all the same length
+        // (new_with_generator, from_walls)
+        InvalidWallGrid,
+        // the walls cut some passage off from the rest (new_with_generator)
+        WallGridDisconnected,
+        // the (y, x) entrypoint or goalpoint was blocked (solve_avoiding), or an opening would be
+        // (fill)
+        OpeningBlocked((usize, usize)),
+        // there's no way from the entrypoint to the goalpoint at all (solve_from and the other
+        // solve_ methods that return a Result, and from_walls)
+        Unsolvable,
+        // the cell at this index of the path isn't next to the one before it (Path::try_new)
+        PathGap(usize),
+        // the move at this index of the string isn't N, E, S, or W (apply_moves)
+        UnknownMove(usize, char),
+        // the move at this index of the string would leave the Maze (apply_moves)
+        MoveOffGrid(usize),
+        // the move at this index of the string would walk into a wall (apply_moves)
+        MoveIntoWall(usize),
+        // nothing is marked as the solution (verify_solution)
+        NotSolved,
+        // the marked solution runs through the (y, x) wall (verify_solution)
+        SolutionThroughWall((usize, usize)),
+        // the marked solution doesn't reach the (y, x) entrypoint or goalpoint (verify_solution)
+        SolutionMissesEnd((usize, usize)),
+        // the marked (y, x) cell can't be reached from the entrypoint through marked cells
+        // (verify_solution)
+        SolutionBroken((usize, usize)),
+        // the edges being joined aren't the same length (join)
+        EdgeMismatch,
+        // the Maze read in is broken in these ways, as found by validate (from_str, from_bytes,
+        // from_json, load)
+        Invalid(Vec<ValidationIssue>),
+        // the callback given to MazeBuilder::on_progress asked for generation to stop
+        // (MazeBuilder::build)
+        Cancelled,
+        // the (y, x) cell is part of the outer wall, which only openings can change (carve)
+        OuterWall((usize, usize)),
+        // putting a wall at the (y, x) cell would cut the entrance off from the goalpoint (fill)
+        WouldDisconnect((usize, usize)),
+        // the overlay was made for a Maze of other dimensions (set_overlay)
+        OverlayMismatch,
     }
 
     impl Maze {
-        /*
+        /**
          * Creates a new Maze of specified size. Since the user didn't specify an algorithm, we've
          * opted to make the default the Prim algolrithm, since it's our only iterative generation
          * implementation (and we just think it's neat).
@@ -50,21 +795,297 @@ pub mod maze_operations {
             Self::new_from(dimensions, CreationAlgorithm::Prim)
         }
 
-        /*
+        /**
          * Creates a new Maze of specified size and with the specified algorithm for doing so.
          * Expects the dimensions to be at least 3x3, and each should be odd; if an even number is
          * passed, the dimension will be incremented by 1 (for example, trying to create a 10x10
-         * Maze will result in an 11x11 Maze).
+         * Maze will result in an 11x11 Maze). Unicursal labyrinths are rounded up further, to one
+         * more than a multiple of four (so 11x11 becomes 13x13), and tessellated Mazes to one more
+         * than a power of two (so 11x11 becomes 17x17). The algorithm's probabilities are clamped
+         * from 0 to 1, and one that isn't a finite number counts as 0.
+         *
+         * ```
+         * use rust_maze::{CreationAlgorithm, Maze};
+         *
+         * let maze = Maze::new_from((10, 10), CreationAlgorithm::Wilson);
+         * assert_eq!(maze.dimensions(), (11, 11));
+         * assert!(maze.validate_connected());
+         * ```
          */
         pub fn new_from(dimensions: (usize, usize), algorithm: CreationAlgorithm) -> Self {
+            Self::builder()
+                .dimensions(dimensions.0, dimensions.1)
+                .algorithm(algorithm)
+                .build_clamped()
+                .expect("Can't create a maze this small")
+        }
+
+        /**
+         * Creates a new Maze like new_from, but makes each of its random choices from the given
+         * seed, so the same seed, size, and algorithm make the same Maze every time, on any
+         * platform.
+         *
+         * ```
+         * use rust_maze::{CreationAlgorithm, Maze};
+         *
+         * let first = Maze::new_seeded((21, 21), CreationAlgorithm::HuntAndKill, 1234);
+         * let second = Maze::new_seeded((21, 21), CreationAlgorithm::HuntAndKill, 1234);
+         * assert_eq!(first.to_string(), second.to_string());
+         * ```
+         */
+        pub fn new_seeded(
+            dimensions: (usize, usize),
+            algorithm: CreationAlgorithm,
+            seed: u64,
+        ) -> Self {
+            Self::builder()
+                .dimensions(dimensions.0, dimensions.1)
+                .algorithm(algorithm)
+                .seed(seed)
+                .build_clamped()
+                .expect("Can't create a maze this small")
+        }
+
+        /**
+         * Creates a new Maze like new_from, but a step at a time, for watching the algorithm at
+         * work: every cell it carves out (or fills in) comes as its own GenerationEvent, in the
+         * order it happened, and the finished Maze comes last. The Maze is generated all at once,
+         * by the same code as every other Maze, and the steps replay what was recorded along the
+         * way.
+         *
+         * ```
+         * use rust_maze::{CreationAlgorithm, GenerationEvent, Maze};
+         *
+         * let mut steps = Maze::generate_stepwise((11, 11), CreationAlgorithm::Kruskal);
+         * // Kruskal's algorithm starts out from solid walls
+         * assert!(steps.grid().iter().flatten().all(|&wall| wall));
+         * let mut carved = 0;
+         * let mut finished = None;
+         * while let Some(event) = steps.next() {
+         *     // steps.grid() is the frame to draw for this step
+         *     match event {
+         *         GenerationEvent::CellCarved((y, x)) => {
+         *             assert!(!steps.grid()[y][x]);
+         *             carved += 1;
+         *         }
+         *         GenerationEvent::CellFilled(_) => unreachable!("Kruskal only carves"),
+         *         GenerationEvent::Finished(maze) => finished = Some(maze),
+         *     }
+         * }
+         * let maze = *finished.unwrap();
+         * assert_eq!(carved, maze.passages().count());
+         * assert!(maze.validate_connected());
+         * ```
+         */
+        pub fn generate_stepwise(
+            dimensions: (usize, usize),
+            algorithm: CreationAlgorithm,
+        ) -> GenerationSteps {
+            Self::check_dimensions(dimensions)
+                .and(Self::check_dimensions(Self::rounded_dimensions(
+                    dimensions, algorithm,
+                )))
+                .expect("Can't create a maze this small");
+            let mut steps = GenerationSteps {
+                events: VecDeque::new(),
+                grid: vec![],
+                maze: None,
+            };
+            let maze = Self::new_from_rng(
+                dimensions,
+                algorithm,
+                &mut thread_rng(),
+                Some(&mut steps),
+                None,
+            );
+            steps.maze = Some(maze);
+            steps
+        }
+
+        /**
+         * Rebuilds a Maze of the given size from a log of how it was generated, starting from
+         * solid walls and carving and filling each cell in turn. Replaying a Maze's own
+         * generation_log gives back exactly the Maze as it was generated. If the log never says
+         * where the openings go, they're left at (1, 0) and (height - 2, width - 1). Returns
+         * DimensionsTooSmall or DimensionsTooLarge like try_new (without any rounding), or
+         * InvalidCoordinate if an event is outside the Maze.
+         *
+         * ```
+         * use rust_maze::{CreationAlgorithm, Maze};
+         *
+         * let maze = Maze::builder()
+         *     .dimensions(15, 25)
+         *     .algorithm(CreationAlgorithm::Wilson)
+         *     .braid(0.5)
+         *     .log_generation(true)
+         *     .build()
+         *     .unwrap();
+         * let log = maze.generation_log().unwrap();
+         * assert_eq!(Maze::replay(log, maze.dimensions()).unwrap(), maze);
+         *
+         * // logging is off unless it's asked for
+         * assert!(Maze::new((15, 25)).generation_log().is_none());
+         * ```
+         */
+        pub fn replay(
+            events: &[CarveEvent],
+            dimensions: (usize, usize),
+        ) -> Result<Self, MazeError> {
+            Self::check_dimensions(dimensions)?;
+            let (height, width) = dimensions;
+            let mut maze = Maze {
+                dimensions,
+                entrypoint: (1, 0),
+                goalpoint: (height - 2, width - 1),
+                exits: vec![(height - 2, width - 1)],
+                algorithm: None,
+                cells: vec![
+                    vec![
+                        Cell {
+                            wall: true,
+                            visited: false,
+                            tag: None,
+                            cost: 1,
+                        };
+                        width
+                    ];
+                    height
+                ],
+                stats: SolveStats::default(),
+                log: None,
+                overlay: None,
+            };
+            for &event in events {
+                match event {
+                    CarveEvent::Cleared => {
+                        for cell in maze.cells.iter_mut().flatten() {
+                            cell.wall = false;
+                        }
+                    }
+                    CarveEvent::Carved(pos) | CarveEvent::Filled(pos) => {
+                        maze.check_coordinate(pos)?;
+                        maze.cells[pos.0][pos.1].wall = matches!(event, CarveEvent::Filled(_));
+                    }
+                    CarveEvent::Openings { entrance, exit } => {
+                        maze.check_coordinate(entrance)?;
+                        maze.check_coordinate(exit)?;
+                        maze.entrypoint = entrance;
+                        maze.goalpoint = exit;
+                        maze.exits = vec![exit];
+                    }
+                }
+            }
+            Ok(maze)
+        }
+
+        /**
+         * Returns the log of how this Maze was generated, if it was built with
+         * MazeBuilder::log_generation turned on. It describes the Maze as it was generated, so
+         * any changes made to it since then aren't in it.
+         */
+        pub fn generation_log(&self) -> Option<&[CarveEvent]> {
+            self.log.as_deref()
+        }
+
+        /**
+         * Returns a MazeBuilder, for creating a Maze with more settings than new_from takes.
+         */
+        pub fn builder() -> MazeBuilder {
+            MazeBuilder::new()
+        }
+
+        /**
+         * Creates a new Maze like new_from, but returns an error instead of panicking if the
+         * dimensions are smaller than 3x3 (DimensionsTooSmall), or the Maze would have more cells
+         * than could ever fit in memory (DimensionsTooLarge), and instead of clamping one of the
+         * algorithm's probabilities if it isn't a finite number (InvalidParameter).
+         */
+        pub fn try_new(
+            dimensions: (usize, usize),
+            algorithm: CreationAlgorithm,
+        ) -> Result<Self, MazeError> {
+            Self::builder()
+                .dimensions(dimensions.0, dimensions.1)
+                .algorithm(algorithm)
+                .build()
+        }
+
+        /**
+         * Creates a new Maze like try_new, making each of its random choices with rng.
+         */
+        fn try_new_from_rng(
+            dimensions: (usize, usize),
+            algorithm: CreationAlgorithm,
+            rng: &mut dyn RngCore,
+        ) -> Result<Self, MazeError> {
+            algorithm.check_parameters()?;
+            Self::check_dimensions(dimensions)?;
+            Self::check_dimensions(Self::rounded_dimensions(dimensions, algorithm))?;
+            Ok(Self::new_from_rng(dimensions, algorithm, rng, None, None))
+        }
+
+        /**
+         * Returns whether a Maze of the given dimensions can be created: DimensionsTooSmall if
+         * either is less than 3, or DimensionsTooLarge if the Maze would have more than
+         * 4,294,967,295 cells (about 8 GB of them). Some algorithms round the dimensions up
+         * further, so they can still turn out too large once the algorithm is picked.
+         */
+        pub fn check_dimensions(dimensions: (usize, usize)) -> Result<(), MazeError> {
+            if dimensions.0 < 3 || dimensions.1 < 3 {
+                return Err(MazeError::DimensionsTooSmall);
+            }
+            match dimensions.0.checked_mul(dimensions.1) {
+                Some(cells) if cells <= u32::MAX as usize => Ok(()),
+                _ => Err(MazeError::DimensionsTooLarge),
+            }
+        }
+
+        /**
+         * Returns the dimensions a Maze made by the given algorithm actually has, once they're
+         * rounded up to suit it.
+         */
+        fn rounded_dimensions(
+            dimensions: (usize, usize),
+            algorithm: CreationAlgorithm,
+        ) -> (usize, usize) {
+            use CreationAlgorithm::*;
+            // mazes only work well with odd-number dimensions
+            let height = dimensions.0 + if dimensions.0.is_multiple_of(2) { 1 } else { 0 };
+            let width = dimensions.1 + if dimensions.1.is_multiple_of(2) { 1 } else { 0 };
+            // a unicursal labyrinth is twice the size of a Maze, so it needs one more than a
+            // multiple of four
+            match algorithm {
+                Unicursal => (height + (height - 1) % 4, width + (width - 1) % 4),
+                // a tessellated Maze doubles in size each time, so it needs one more than a power
+                // of two
+                Fractal => (
+                    (height - 1).next_power_of_two() + 1,
+                    (width - 1).next_power_of_two() + 1,
+                ),
+                _ => (height, width),
+            }
+        }
+
+        /**
+         * Creates a new Maze like new_from, making each of its random choices with rng. If steps
+         * are given, they start out with the walls the algorithm starts from, and every change
+         * it makes to them is recorded. If progress is given, it's told how generation is going
+         * as the cells are carved, and if it cancels, the algorithm stops wherever it's gotten
+         * to (leaving a Maze that's no use to anyone).
+         */
+        fn new_from_rng(
+            dimensions: (usize, usize),
+            algorithm: CreationAlgorithm,
+            rng: &mut dyn RngCore,
+            steps: Option<&mut GenerationSteps>,
+            mut progress: Option<&mut Progress>,
+        ) -> Self {
             use CreationAlgorithm::*;
             // mazes smaller than 3x3 don't make sense
             if dimensions.0 <= 2 || dimensions.1 <= 2 {
                 panic!("Can't create a maze this small")
             }
-            // mazes only work well with odd-number dimensions
-            let height = dimensions.0 + if dimensions.0 % 2 == 0 { 1 } else { 0 };
-            let width = dimensions.1 + if dimensions.1 % 2 == 0 { 1 } else { 0 };
+            let (height, width) = Self::rounded_dimensions(dimensions, algorithm);
 
             let cells: Vec<Vec<Cell>> = vec![
                 vec![
@@ -72,511 +1093,12232 @@ pub mod maze_operations {
                         wall: match algorithm {
                             // recursive division starts with a grid of paths and constricts the
                             // movable spaces with new walls
-                            RecursiveDivision => false,
+                            RecursiveDivision { .. } => false,
                             // all other algorithms start with a grid of walls and carve out a path
                             _ => true,
                         },
-                        visited: false
+                        visited: false,
+                        tag: None,
+                        cost: 1,
                     };
                     width
                 ];
                 height
             ];
-
-            match algorithm {
-                RandomWalk => Self::gen_from_walk(cells),
-                RecursiveDivision => Self::gen_from_divide(cells),
-                Prim => Self::gen_from_prim(cells),
+            let log = steps.map(|steps| {
+                steps.grid = cells
+                    .iter()
+                    .map(|row| row.iter().map(|cell| cell.wall).collect())
+                    .collect();
+                &mut steps.events
+            });
+            if let Some(progress) = &mut progress {
+                // every cell of a perfect Maze is carved once: each (odd, odd) cell, a passage to
+                // all but one of them, and the two openings
+                let rooms = (height / 2) * (width / 2);
+                progress.total = match algorithm {
+                    // the walls recursive division builds, and the holes it knocks back through
+                    // them, come to about 70% of the cells
+                    RecursiveDivision { .. } => height * width * 7 / 10,
+                    // seeding the noise carves out most of the cells, and smoothing it changes
+                    // plenty more
+                    Cellular { .. } => height * width,
+                    _ => 2 * rooms + 1,
+                };
+                progress.next = progress.batch();
             }
-        }
-
-        /*
-         * Solves this Maze--sets the visited bool of each Cell on the way to the goalpoint to
-         * true--using our default choice of recursive backtracking.
-         */
-        #[allow(dead_code)]
-        pub fn solve(&mut self) {
-            self.solve_from(SolvingAlgorithm::RecursiveBacktracking);
-        }
+            let cells = Canvas {
+                cells,
+                log,
+                progress,
+            };
 
-        /*
-         * Solves this Maze--sets the visited bool of each Cell on the way to the goalpoint to
-         * true--using the specified algorithm for doing so.
-         */
-        pub fn solve_from(&mut self, algorithm: SolvingAlgorithm) {
-            if !self.is_solved() {
-                self.unsolve()
-            }
-            use SolvingAlgorithm::*;
-            match algorithm {
-                RecursiveBacktracking => self.solve_from_backtracking(self.entrypoint),
-                DeadEndFilling => self.solve_from_dead_end_filling(),
+            let mut maze = match algorithm {
+                RandomWalk => Self::gen_from_walk(cells, rng),
+                RecursiveDivision {
+                    room_chance,
+                    min_room_size,
+                } => Self::gen_from_divide(cells, room_chance, min_room_size, rng),
+                Prim => Self::gen_from_prim(cells, rng),
+                Kruskal => Self::gen_from_kruskal(cells, rng),
+                Eller => Self::gen_from_eller(cells, rng),
+                Wilson => Self::gen_from_wilson(cells, rng),
+                AldousBroder => Self::gen_from_aldous_broder(cells, rng),
+                HuntAndKill => Self::gen_from_hunt_and_kill(cells, rng),
+                Sidewinder => Self::gen_from_sidewinder(cells, rng),
+                BinaryTree(bias) => Self::gen_from_binary_tree(cells, bias, rng),
+                GrowingTree(strategy) => Self::gen_from_growing_tree(cells, strategy, rng),
+                RecursiveBacktracker => Self::gen_from_backtracker(cells, rng),
+                OriginShift { iterations } => Self::gen_from_origin_shift(cells, iterations, rng),
+                Dungeon {
+                    room_attempts,
+                    min_room,
+                    max_room,
+                } => Self::gen_from_dungeon(cells, room_attempts, (min_room, max_room), rng),
+                Unicursal => Self::gen_from_unicursal(cells, rng),
+                Cellular {
+                    fill_probability,
+                    iterations,
+                } => Self::gen_from_cellular(cells, fill_probability, iterations, rng),
+                WeightedPrim { straightness } => {
+                    Self::gen_from_weighted_prim(cells, straightness, rng)
+                }
+                Fractal => Self::gen_from_fractal(cells, rng),
             };
+            maze.algorithm = Some(algorithm);
+            maze
         }
 
-        /*
-         * Returns whether this Maze currently has the solution computed.
+        /**
+         * Creates a new Maze out of the walls the given Generator comes up with for the specified
+         * size. The entrypoint and goalpoint go in the usual spots at the top-left and
+         * bottom-right, (1, 0) and (height - 2, width - 1), and are opened if the Generator didn't.
+         * Returns an error if the walls aren't a rectangle at least 3x3, or they leave some
+         * passage cut off from the rest.
          */
-        pub fn is_solved(&self) -> bool {
-            self.cells[self.goalpoint.0][self.goalpoint.1].visited
+        pub fn new_with_generator(
+            dimensions: (usize, usize),
+            generator: &dyn Generator,
+        ) -> Result<Self, MazeError> {
+            Self::new_with_generator_from_rng(dimensions, generator, &mut thread_rng())
         }
 
-        /*
-         * Removes the solution to this Maze.
+        /**
+         * Creates a new Maze like new_with_generator, handing the Generator rng to make its
+         * random choices with.
          */
-        pub fn unsolve(&mut self) {
-            for row in &mut self.cells {
-                for cell in row {
-                    cell.visited = false;
-                }
+        fn new_with_generator_from_rng(
+            dimensions: (usize, usize),
+            generator: &dyn Generator,
+            rng: &mut dyn RngCore,
+        ) -> Result<Self, MazeError> {
+            let walls = generator.generate(dimensions, rng);
+            if walls.len() < 3
+                || walls[0].len() < 3
+                || walls.iter().any(|row| row.len() != walls[0].len())
+            {
+                return Err(MazeError::InvalidWallGrid);
             }
-        }
-
-        /*
-         * Generates a Maze using a random non-self-intersecting walk, beginning at a
-         * randomly-selected cell.
-         * Only good for small Mazes--causes stack overflows for Mazes with more than ~32,000 Cells
-         */
-        fn gen_from_walk(mut cells: Vec<Vec<Cell>>) -> Self {
-            let dimensions: (usize, usize) = (cells.len(), cells[0].len());
 
+            let dimensions = (walls.len(), walls[0].len());
+            let mut cells: Vec<Vec<Cell>> = walls
+                .iter()
+                .map(|row| {
+                    row.iter()
+                        .map(|&wall| Cell {
+                            wall,
+                            visited: false,
+                            tag: None,
+                            cost: 1,
+                        })
+                        .collect()
+                })
+                .collect();
             let entrypoint: (usize, usize) = (1, 0);
-            cells[entrypoint.0][entrypoint.1].wall = false;
-
             let goalpoint: (usize, usize) = (dimensions.0 - 2, dimensions.1 - 1);
+            cells[entrypoint.0][entrypoint.1].wall = false;
             cells[goalpoint.0][goalpoint.1].wall = false;
 
-            // random starting point
-            let mut rng = thread_rng();
-            let mut starter: (usize, usize) = (0, 0);
-            while starter.0 % 2 == 0 || starter.1 % 2 == 0 {
-                starter = (
-                    rng.gen_range(1..(dimensions.0 - 1)),
-                    rng.gen_range(1..(dimensions.1 - 1)),
-                );
+            let maze = Maze {
+                dimensions,
+                entrypoint,
+                goalpoint,
+                exits: vec![goalpoint],
+                algorithm: None,
+                cells,
+                stats: SolveStats::default(),
+                log: None,
+                overlay: None,
+            };
+            if maze.validate_connected() {
+                Ok(maze)
+            } else {
+                Err(MazeError::WallGridDisconnected)
             }
+        }
 
-            /*
-             * Recursively steps forward in a non-self-intersecting random walk and carves out the
-             * path in the Maze as it goes.
-             */
-            fn walk(cells: &mut Vec<Vec<Cell>>, pos: (usize, usize), dimensions: (usize, usize)) {
-                // remove wall at current cell and mark it as visited
-                let current: &mut Cell = &mut cells[pos.0][pos.1];
-                current.wall = false;
-                current.visited = true;
-
-                let get_two_offset_shuffle = || -> [(isize, isize); 4] {
-                    let mut two_offsets: [(isize, isize); 4] = [(-2, 0), (2, 0), (0, -2), (0, 2)];
-                    let mut rng = thread_rng();
-                    two_offsets.shuffle(&mut rng);
-                    two_offsets
-                };
-
-                let mut walk_to = |two_offset: (isize, isize)| {
-                    let two_neighbor: (isize, isize) =
-                        (pos.0 as isize + two_offset.0, pos.1 as isize + two_offset.1);
+        /**
+         * Creates a new imperfect Maze of specified size: after generating it with the specified
+         * algorithm, an additional loop_chance fraction of the walls between (odd, odd) cells are
+         * knocked down, so there's more than one route from the entrypoint to the goalpoint.
+         * Dead-end filling can't narrow the resulting Maze down to one path on its own.
+         */
+        pub fn new_with_loops(
+            dimensions: (usize, usize),
+            algorithm: CreationAlgorithm,
+            loop_chance: f64,
+        ) -> Self {
+            Self::builder()
+                .dimensions(dimensions.0, dimensions.1)
+                .algorithm(algorithm)
+                .loops(loop_chance)
+                .build_clamped()
+                .expect("Can't create a maze this small")
+        }
 
-                    // if the cell specified by the offset is within the allowable area,
-                    if two_neighbor.0 > 0
-                        && two_neighbor.0 < dimensions.0 as isize - 1
-                        && two_neighbor.1 > 0
-                        && two_neighbor.1 < dimensions.1 as isize - 1
-                        // and hasn't yet been visited,
-                        && !cells[two_neighbor.0 as usize][two_neighbor.1 as usize].visited
-                    {
-                        // set the cell between them to not a wall (sneaky integer division)
-                        cells[(pos.0 as isize + two_offset.0 / 2) as usize]
-                            [(pos.1 as isize + two_offset.1 / 2) as usize]
-                            .wall = false;
-                        // and walk from said cell specified by the offset
-                        walk(
-                            cells,
-                            (
-                                (pos.0 as isize + two_offset.0) as usize,
-                                (pos.1 as isize + two_offset.1) as usize,
-                            ),
-                            dimensions,
-                        );
+        /**
+         * Knocks down the given fraction of the walls still standing between two open (odd, odd)
+         * cells, picked with rng, so there's more than one route between them.
+         */
+        fn knock_down_walls(&mut self, fraction: f64, rng: &mut dyn RngCore) {
+            // find the walls still standing between two open (odd, odd) cells
+            let mut walls: Vec<(usize, usize)> = vec![];
+            for y in 1..self.dimensions.0 - 1 {
+                for x in 1..self.dimensions.1 - 1 {
+                    let separated = match (y % 2, x % 2) {
+                        (0, 1) => (self.cells[y - 1][x].wall, self.cells[y + 1][x].wall),
+                        (1, 0) => (self.cells[y][x - 1].wall, self.cells[y][x + 1].wall),
+                        _ => continue,
+                    };
+                    if self.cells[y][x].wall && separated == (false, false) {
+                        walls.push((y, x));
                     }
-                };
-
-                for two_offset in get_two_offset_shuffle() {
-                    walk_to(two_offset);
                 }
             }
 
-            walk(&mut cells, starter, dimensions);
+            walls.shuffle(rng);
+            let count = (fraction.clamp(0.0, 1.0) * walls.len() as f64).round() as usize;
+            for &(y, x) in &walls[..count] {
+                self.cells[y][x].wall = false;
+            }
+        }
 
-            // set each cell back to unvisited, so the Maze will print properly
-            for row in &mut cells {
-                for cell in row {
-                    cell.visited = false;
+        /**
+         * Moves the entrypoint and goalpoint to the given (y, x) cells, walling up the old ones
+         * (any other exits stay where they are).
+         * Each can be any cell along the outer wall but a corner, or even a cell inside the Maze
+         * to start or finish in the middle of it. If the new cell doesn't touch a passage, a way
+         * is carved inward from it until it does. Any solution is removed, since it leads between
+         * the old openings. Returns an error if either cell is outside the Maze
+         * (InvalidCoordinate) or a corner (CornerOpening), or if they're the same cell
+         * (SameOpenings).
+         */
+        pub fn set_openings(
+            &mut self,
+            entrance: (usize, usize),
+            exit: (usize, usize),
+        ) -> Result<(), MazeError> {
+            let (height, width) = self.dimensions;
+            for opening in [entrance, exit] {
+                self.check_coordinate(opening)?;
+                if (opening.0 == 0 || opening.0 == height - 1)
+                    && (opening.1 == 0 || opening.1 == width - 1)
+                {
+                    return Err(MazeError::CornerOpening(opening));
                 }
             }
+            if entrance == exit || (entrance != self.goalpoint && self.exits.contains(&entrance)) {
+                return Err(MazeError::SameOpenings);
+            }
 
-            Maze {
-                dimensions,
-                entrypoint,
-                goalpoint,
-                cells,
+            self.unsolve();
+            // openings in the middle of the Maze were passages already, so they stay open
+            for (y, x) in [self.entrypoint, self.goalpoint] {
+                if y == 0 || x == 0 || y == height - 1 || x == width - 1 {
+                    self.cells[y][x].wall = true;
+                }
             }
+            for opening in [entrance, exit] {
+                self.open_into_passages(opening);
+            }
+            self.entrypoint = entrance;
+            self.set_goalpoint(exit);
+            Ok(())
         }
 
-        /*
-         * Generates a Maze by recursively adding walls at random positions that divide the
-         * remaining accessible area.
+        /**
+         * Adds another exit at the given (y, x) cell, opening it up like set_openings does, so
+         * the Maze can be escaped more than one way. Adding an exit that's already there does
+         * nothing. Returns an error if the cell is outside the Maze (InvalidCoordinate), a corner
+         * (CornerOpening), or the entrypoint (SameOpenings).
          */
-        fn gen_from_divide(mut cells: Vec<Vec<Cell>>) -> Self {
-            let dimensions: (usize, usize) = (cells.len(), cells[0].len());
-
-            let entrypoint: (usize, usize) = (1, 0);
+        pub fn add_exit(&mut self, cell: (usize, usize)) -> Result<(), MazeError> {
+            self.check_coordinate(cell)?;
+            let (height, width) = self.dimensions;
+            if (cell.0 == 0 || cell.0 == height - 1) && (cell.1 == 0 || cell.1 == width - 1) {
+                return Err(MazeError::CornerOpening(cell));
+            }
+            if cell == self.entrypoint {
+                return Err(MazeError::SameOpenings);
+            }
+            if !self.exits.contains(&cell) {
+                self.open_into_passages(cell);
+                self.exits.push(cell);
+            }
+            Ok(())
+        }
 
-            let goalpoint: (usize, usize) = (dimensions.0 - 2, dimensions.1 - 1);
+        /**
+         * Removes the exit at the given (y, x) cell, walling it back up if it's in the outer
+         * wall. If it was the goalpoint, the solution is removed, and the first exit left becomes
+         * the goalpoint; once the last exit is gone, solve_from returns NoExit. Returns NotAnExit
+         * if there's no exit at that cell.
+         */
+        pub fn remove_exit(&mut self, cell: (usize, usize)) -> Result<(), MazeError> {
+            let Some(index) = self.exits.iter().position(|&exit| exit == cell) else {
+                return Err(MazeError::NotAnExit(cell));
+            };
+            self.exits.remove(index);
+            let (height, width) = self.dimensions;
+            if cell.0 == 0 || cell.1 == 0 || cell.0 == height - 1 || cell.1 == width - 1 {
+                self.cells[cell.0][cell.1].wall = true;
+            }
+            if cell == self.goalpoint {
+                self.unsolve();
+                if let Some(&exit) = self.exits.first() {
+                    self.goalpoint = exit;
+                }
+            }
+            Ok(())
+        }
 
-            // set walls on top and bottom
-            for i in 0..cells[0].len() {
-                cells[0][i].wall = true;
-                cells[dimensions.0 - 1][i].wall = true;
+        /**
+         * Knocks down the wall at the given (y, x) cell, opening a shortcut or a new side
+         * passage. The outer wall is left alone, since only the openings belong in it (see
+         * set_openings and add_exit). Any solution is removed, since there may be a shorter way
+         * through now. Carving a passage does nothing. Returns InvalidCoordinate if the cell is
+         * outside the Maze, or OuterWall if it's part of the outer wall.
+         */
+        pub fn carve(&mut self, cell: (usize, usize)) -> Result<(), MazeError> {
+            self.check_coordinate(cell)?;
+            let (height, width) = self.dimensions;
+            if cell.0 == 0 || cell.1 == 0 || cell.0 == height - 1 || cell.1 == width - 1 {
+                return Err(MazeError::OuterWall(cell));
             }
+            if self.cells[cell.0][cell.1].wall {
+                self.cells[cell.0][cell.1].wall = false;
+                self.unsolve();
+            }
+            Ok(())
+        }
 
-            // set walls on left and right
-            for row in &mut cells {
-                row[0].wall = true;
-                row[dimensions.1 - 1].wall = true;
+        /**
+         * Puts up a wall at the given (y, x) cell, closing off a passage, unless that would cut
+         * the entrance off from the goalpoint (if it wasn't already); openings are closed with
+         * set_openings or remove_exit instead. Any solution is removed, since it may have run
+         * through the cell. Filling a wall does nothing. Returns InvalidCoordinate if the cell is
+         * outside the Maze, OpeningBlocked if it's the entrance or an exit, or WouldDisconnect if
+         * there'd be no way left from the entrance to the goalpoint; the Maze is left as it was.
+         */
+        pub fn fill(&mut self, cell: (usize, usize)) -> Result<(), MazeError> {
+            self.check_coordinate(cell)?;
+            if cell == self.entrypoint || self.exits.contains(&cell) {
+                return Err(MazeError::OpeningBlocked(cell));
+            }
+            if self.cells[cell.0][cell.1].wall {
+                return Ok(());
             }
+            let (y, x) = self.goalpoint;
+            let connected = self.distance_grid(self.entrypoint)[y][x].is_some();
+            self.cells[cell.0][cell.1].wall = true;
+            if connected && self.distance_grid(self.entrypoint)[y][x].is_none() {
+                self.cells[cell.0][cell.1].wall = false;
+                return Err(MazeError::WouldDisconnect(cell));
+            }
+            self.unsolve();
+            Ok(())
+        }
 
-            // make sure the entrypoint and goalpoint aren't walls
-            cells[entrypoint.0][entrypoint.1].wall = false;
-            cells[goalpoint.0][goalpoint.1].wall = false;
+        /**
+         * Attaches a number of the caller's choosing to the given (y, x) cell--a room, a spawn
+         * point, a kind of terrain--replacing any it had. Tags stay with their cells when the
+         * Maze is rotated, mirrored, transposed, cropped, or joined, and are saved by to_bytes
+         * and to_json (but not the text format, which has no room for them). Returns
+         * InvalidCoordinate if the cell is outside the Maze.
+         *
+         * ```
+         * use rust_maze::{CreationAlgorithm, Maze};
+         *
+         * let mut maze = Maze::new_from((11, 21), CreationAlgorithm::Kruskal);
+         * maze.set_tag((1, 3), 7).unwrap();
+         * // a quarter turn clockwise sends the top row down the right side
+         * let turned = maze.rotate_cw();
+         * assert_eq!(turned.get_tag((3, 9)), Some(7));
+         * assert_eq!(turned.tagged().count(), 1);
+         * assert_eq!(turned.rotate_ccw(), maze);
+         * ```
+         */
+        pub fn set_tag(&mut self, cell: (usize, usize), tag: u32) -> Result<(), MazeError> {
+            self.check_coordinate(cell)?;
+            self.cells[cell.0][cell.1].tag = Some(tag);
+            Ok(())
+        }
 
-            /*
-             * Recursively divide the section of the Maze uniquely defined by its specified top-left
-             * and bottom-right corners in half, leaving a hole in the wall to maintain access
-             * between the left/right or top/bottom halves.
-             */
-            fn divide(
-                cells: &mut Vec<Vec<Cell>>,
-                top_left: (usize, usize),
-                bottom_right: (usize, usize),
-            ) {
-                let height = bottom_right.0 - top_left.0;
-                let width = bottom_right.1 - top_left.1;
+        /**
+         * Returns the tag attached to the given (y, x) cell, or None if it has none (or it's
+         * outside the Maze).
+         */
+        pub fn get_tag(&self, (y, x): (usize, usize)) -> Option<u32> {
+            self.cells.get(y)?.get(x)?.tag
+        }
 
-                // we can't divide further if the area to divide is already 1 cell narrow
-                if height == 2 || width == 2 {
-                    return;
-                }
+        /**
+         * Takes the tag off of the given (y, x) cell, returning what it was.
+         */
+        pub fn remove_tag(&mut self, (y, x): (usize, usize)) -> Option<u32> {
+            self.cells.get_mut(y)?.get_mut(x)?.tag.take()
+        }
 
-                let mut rng = thread_rng();
-                // if the area to divide is at least as tall as it is wide,
-                if height >= width {
-                    // pick an even y-index to draw a wall that divides the remaining area, and draw
-                    // it
-                    let mut wall_index = 1;
-                    while wall_index % 2 != 0 {
-                        wall_index = rng.gen_range((top_left.0 + 1)..bottom_right.0);
-                    }
-                    for i in (top_left.1 + 1)..bottom_right.1 {
-                        cells[wall_index][i].wall = true;
-                    }
+        /**
+         * Returns every tagged cell and its tag, row by row.
+         */
+        pub fn tagged(&self) -> impl Iterator<Item = ((usize, usize), u32)> + '_ {
+            self.cells.iter().enumerate().flat_map(|(y, row)| {
+                row.iter()
+                    .enumerate()
+                    .filter_map(move |(x, cell)| cell.tag.map(|tag| ((y, x), tag)))
+            })
+        }
 
-                    // pick an odd x-index to draw the hole on, and punch it out
-                    let mut hole_index = 0;
-                    while hole_index % 2 != 1 {
-                        hole_index = rng.gen_range((top_left.1 + 1)..bottom_right.1);
-                    }
-                    cells[wall_index][hole_index].wall = false;
+        /**
+         * Sets what stepping into the given (y, x) cell costs--mud, water, a crowd to push
+         * through--for the solvers that weigh cells (Dijkstra's algorithm and A* search) to take
+         * into account, so the cheapest path can be a longer one around it. Every cell costs 1
+         * until this changes it. A wall keeps the cost it's given, but it never counts unless
+         * the wall is carved into a passage. Costs stay with their cells like tags do, and are
+         * saved by to_bytes and to_json; the text format only shows which cells cost more than
+         * 1. Returns InvalidCoordinate if the cell is outside the Maze.
+         *
+         * ```
+         * use rust_maze::{Maze, SolvingAlgorithm};
+         *
+         * // two ways past the wall in the middle: straight down the left side, or the long way
+         * // around the right
+         * let mut maze: Maze = concat!(
+         *     "██▒▒████████\n",
+         *     "██        ██\n",
+         *     "██  ████  ██\n",
+         *     "██        ██\n",
+         *     "██░░████████\n",
+         * )
+         * .parse()
+         * .unwrap();
+         * assert_eq!(maze.solve_from(SolvingAlgorithm::Dijkstra).unwrap().path_length, 5);
+         * maze.set_cost((2, 1), 10).unwrap();
+         * let report = maze.solve_from(SolvingAlgorithm::Dijkstra).unwrap();
+         * assert_eq!(report.path_length, 11);
+         * assert_eq!(report.path_cost, 10);
+         * ```
+         */
+        pub fn set_cost(&mut self, cell: (usize, usize), cost: u32) -> Result<(), MazeError> {
+            self.check_coordinate(cell)?;
+            self.cells[cell.0][cell.1].cost = cost;
+            Ok(())
+        }
 
-                    // recursively divide the remaining halves
-                    divide(cells, top_left, (wall_index, bottom_right.1));
-                    divide(cells, (wall_index, top_left.1), bottom_right);
-                }
-                // if the area to divide is wider than it is tall (same procedure as above)
-                else {
-                    let mut wall_index = rng.gen_range((top_left.1 + 1)..bottom_right.1);
-                    while wall_index % 2 != 0 {
-                        wall_index = rng.gen_range((top_left.1 + 1)..bottom_right.1);
-                    }
-                    for i in (top_left.0 + 1)..bottom_right.0 {
-                        cells[i][wall_index].wall = true;
-                    }
+        /**
+         * Returns what stepping into the given (y, x) cell costs, or None if it's outside the
+         * Maze.
+         */
+        pub fn get_cost(&self, (y, x): (usize, usize)) -> Option<u32> {
+            Some(self.cells.get(y)?.get(x)?.cost)
+        }
 
-                    let mut hole_index = rng.gen_range((top_left.0 + 1)..bottom_right.0);
-                    while hole_index % 2 != 1 {
-                        hole_index = rng.gen_range((top_left.0 + 1)..bottom_right.0);
-                    }
-                    cells[hole_index][wall_index].wall = false;
+        /**
+         * Returns every cell that doesn't cost 1 to step into and what it costs, row by row.
+         */
+        pub fn weighted_cells(&self) -> impl Iterator<Item = ((usize, usize), u32)> + '_ {
+            self.cells.iter().enumerate().flat_map(|(y, row)| {
+                row.iter()
+                    .enumerate()
+                    .filter(|(_, cell)| cell.cost != 1)
+                    .map(move |(x, cell)| ((y, x), cell.cost))
+            })
+        }
 
-                    divide(cells, top_left, (bottom_right.0, wall_index));
-                    divide(cells, (top_left.0, wall_index), bottom_right);
-                }
+        /**
+         * Covers fraction (from 0 to 1) of the passages, picked at random, in terrain that costs
+         * cost to step into, like set_cost does one cell at a time. The openings are left out.
+         * Calling this again lays more terrain over whatever's there, so a little water can go
+         * on top of a lot of mud.
+         */
+        pub fn add_terrain(&mut self, fraction: f64, cost: u32) {
+            self.add_terrain_from_rng(fraction, cost, &mut thread_rng());
+        }
+
+        /**
+         * Lays terrain over this Maze like add_terrain, making each of its random choices with
+         * rng.
+         */
+        fn add_terrain_from_rng(&mut self, fraction: f64, cost: u32, rng: &mut dyn RngCore) {
+            let mut passages: Vec<(usize, usize)> = self
+                .open_cells()
+                .filter(|&pos| pos != self.entrypoint && !self.exits.contains(&pos))
+                .collect();
+            passages.shuffle(rng);
+            let count = (fraction.clamp(0.0, 1.0) * passages.len() as f64).round() as usize;
+            for &(y, x) in &passages[..count] {
+                self.cells[y][x].cost = cost;
             }
+        }
 
-            divide(&mut cells, (0, 0), (dimensions.0 - 1, dimensions.1 - 1));
+        /**
+         * Returns every exit from this Maze, in the order they were added.
+         */
+        pub fn exits(&self) -> &[(usize, usize)] {
+            &self.exits
+        }
 
-            Maze {
-                dimensions,
-                goalpoint,
-                entrypoint,
-                cells,
+        /**
+         * Moves the goalpoint to the given cell, keeping its place in the list of exits.
+         */
+        fn set_goalpoint(&mut self, goalpoint: (usize, usize)) {
+            let old = self.goalpoint;
+            self.exits.retain(|&exit| exit != goalpoint);
+            match self.exits.iter().position(|&exit| exit == old) {
+                Some(index) => self.exits[index] = goalpoint,
+                None => self.exits.insert(0, goalpoint),
             }
+            self.goalpoint = goalpoint;
         }
 
-        /*
-         * Iteratively generates a Maze using iterative randomized Prim's algorithm. The starting
-         * point is randomly selected.
+        /**
+         * Opens up the given cell, then carves a way straight inward from it (or downward, for a
+         * cell inside the Maze) until it touches one of the passages inside the outer wall.
          */
-        fn gen_from_prim(mut cells: Vec<Vec<Cell>>) -> Self {
-            let dimensions: (usize, usize) = (cells.len(), cells[0].len());
+        fn open_into_passages(&mut self, opening: (usize, usize)) {
+            let (height, width) = self.dimensions;
+            let inside = |pos: (isize, isize)| {
+                pos.0 > 0 && pos.1 > 0 && pos.0 < height as isize - 1 && pos.1 < width as isize - 1
+            };
+            let (dy, dx): (isize, isize) = match opening {
+                (0, _) => (1, 0),
+                (_, 0) => (0, 1),
+                (y, _) if y == height - 1 => (-1, 0),
+                (_, x) if x == width - 1 => (0, -1),
+                (y, _) if y + 2 < height => (1, 0),
+                _ => (-1, 0),
+            };
 
-            let entrypoint: (usize, usize) = (1, 0);
-            cells[entrypoint.0][entrypoint.1].wall = false;
+            self.cells[opening.0][opening.1].wall = false;
+            let mut pos = (opening.0 as isize, opening.1 as isize);
+            let mut previous: Option<(isize, isize)> = None;
+            loop {
+                let touching = [(1, 0), (-1, 0), (0, 1), (0, -1)].iter().any(|&(y, x)| {
+                    let neighbor = (pos.0 + y, pos.1 + x);
+                    Some(neighbor) != previous
+                        && inside(neighbor)
+                        && !self.cells[neighbor.0 as usize][neighbor.1 as usize].wall
+                });
+                let next = (pos.0 + dy, pos.1 + dx);
+                if touching || !inside(next) {
+                    break;
+                }
+                self.cells[next.0 as usize][next.1 as usize].wall = false;
+                previous = Some(pos);
+                pos = next;
+            }
+        }
 
-            let goalpoint: (usize, usize) = (dimensions.0 - 2, dimensions.1 - 1);
-            cells[goalpoint.0][goalpoint.1].wall = false;
+        /**
+         * Creates a new Maze in the shape of the given mask, using the specified algorithm. Cells
+         * that are true in the mask may be carved into passages, while false ones are always
+         * walls, as is anything outside the mask. The entrypoint is the first spot on the mask's
+         * boundary (reading top to bottom, left to right) where a passage can lead west or north
+         * out of it, and the goalpoint is the last spot where one can lead east or south.
+         * Returns an error if the mask isn't rectangular, doesn't have room for at least two
+         * (odd, odd) cells, or is split into pieces that can't be connected.
+         */
+        pub fn new_masked(
+            mask: &[Vec<bool>],
+            algorithm: CreationAlgorithm,
+        ) -> Result<Self, MazeError> {
+            if mask.is_empty()
+                || mask[0].is_empty()
+                || mask.iter().any(|row| row.len() != mask[0].len())
+            {
+                return Err(MazeError::InvalidMask);
+            }
+            if mask.len() < 3 || mask[0].len() < 3 {
+                return Err(MazeError::MaskTooSmall);
+            }
 
-            let two_offsets = [(-2, 0), (2, 0), (0, 2), (0, -2)];
+            let mut maze = Self::try_new((mask.len(), mask[0].len()), algorithm)?;
+            let dimensions = maze.dimensions;
+            let allowed = |pos: (isize, isize)| {
+                pos.0 > 0
+                    && pos.0 < dimensions.0 as isize - 1
+                    && pos.1 > 0
+                    && pos.1 < dimensions.1 as isize - 1
+                    && mask
+                        .get(pos.0 as usize)
+                        .and_then(|row| row.get(pos.1 as usize))
+                        .copied()
+                        .unwrap_or(false)
+            };
 
-            // compute frontier cells of a random (odd, odd) cell and add them to a list
-            let mut frontiers: Vec<(usize, usize)> = vec![];
-            let mut rng = thread_rng();
-            let mut starter: (usize, usize) = (0, 0);
-            while starter.0 % 2 == 0 || starter.1 % 2 == 0 {
-                starter = (
-                    rng.gen_range(1..(dimensions.0 - 1)),
-                    rng.gen_range(1..(dimensions.1 - 1)),
-                );
+            // there has to be room for at least two (odd, odd) cells inside the mask
+            let rooms = (1..dimensions.0 - 1)
+                .step_by(2)
+                .flat_map(|y| (1..dimensions.1 - 1).step_by(2).map(move |x| (y, x)))
+                .filter(|&(y, x)| allowed((y as isize, x as isize)))
+                .count();
+            if rooms < 2 {
+                return Err(MazeError::MaskTooSmall);
             }
-            cells[starter.0][starter.1].wall = false;
 
-            let append_frontiers = |cells: &Vec<Vec<Cell>>,
-                                    frontiers: &mut Vec<(usize, usize)>,
-                                    pos: (usize, usize)| {
-                for &(dy, dx) in &two_offsets {
-                    let two_neighbor: (isize, isize) = (pos.0 as isize + dy, pos.1 as isize + dx);
+            if !maze.fit_to_mask_from_rng(&allowed, &[], &mut thread_rng()) {
+                return Err(MazeError::MaskDisconnected);
+            }
+            let (entrypoint, goalpoint) = maze
+                .boundary_openings(&allowed)
+                .expect("the mask has room for a cell");
+            maze.entrypoint = entrypoint;
+            maze.set_goalpoint(goalpoint);
+            maze.cells[maze.entrypoint.0][maze.entrypoint.1].wall = false;
+            maze.cells[maze.goalpoint.0][maze.goalpoint.1].wall = false;
 
-                    // as long as the two-neighbor is in bounds (and not on the edge),
-                    if two_neighbor.0 > 0
-                        && two_neighbor.0 < cells.len() as isize - 1
-                        && two_neighbor.1 > 0
-                        && two_neighbor.1 < cells[0].len() as isize - 1
-                        // not a wall,
-                        && cells[two_neighbor.0 as usize][two_neighbor.1 as usize].wall
-                        // and not already in the list,
-                        && !frontiers.contains(&(two_neighbor.0 as usize, two_neighbor.1 as usize))
-                    {
-                        // add it to the list of frontier cells
-                        frontiers.push((two_neighbor.0 as usize, two_neighbor.1 as usize));
-                    }
+            Ok(maze)
+        }
+
+        /**
+         * Creates a new Maze in the shape of the netpbm (PBM, PGM, or PPM) image at the given path,
+         * using the specified algorithm. Each pixel becomes one Cell: pixels at least as bright as
+         * the threshold may be carved into passages, while darker ones are always walls. Returns
+         * an error if the image can't be read, is too small to hold a 3x3 Maze, or its bright
+         * areas are split into pieces.
+         */
+        #[cfg(feature = "image")]
+        pub fn from_image_mask(
+            path: &std::path::Path,
+            threshold: u8,
+            algorithm: CreationAlgorithm,
+        ) -> Result<Self, MazeError> {
+            let pixels = crate::netpbm::read_brightness(path).map_err(|error| {
+                match error.kind() {
+                    // the file was read fine, it just isn't a netpbm image
+                    std::io::ErrorKind::InvalidData => MazeError::ParseError(error.to_string()),
+                    _ => MazeError::Io(path.to_path_buf(), error),
                 }
+            })?;
+            if pixels.len() < 3 || pixels[0].len() < 3 {
+                return Err(MazeError::ImageTooSmall);
+            }
+
+            let mask: Vec<Vec<bool>> = pixels
+                .iter()
+                .map(|row| row.iter().map(|&pixel| pixel >= threshold).collect())
+                .collect();
+            Self::new_masked(&mask, algorithm).map_err(|error| match error {
+                MazeError::MaskTooSmall => MazeError::ImageTooSmall,
+                error => error,
+            })
+        }
+
+        /**
+         * Creates a new symmetric Maze of (about) the specified size, using the specified
+         * algorithm. One fundamental region (a half, or a quarter for Quad) is generated, then
+         * reflected or rotated into the rest of the Maze, with a passage knocked through each seam
+         * between them so everything stays connected. The goalpoint is the image of the entrypoint,
+         * wherever that lands. Mirrored dimensions are rounded up to one more than a multiple of
+         * four, so that the seams fall on walls. Returns an error like try_new does if the
+         * dimensions (or what they're rounded up to) are too small or too large, or one of the
+         * algorithm's probabilities isn't a finite number.
+         */
+        pub fn new_symmetric(
+            dimensions: (usize, usize),
+            algorithm: CreationAlgorithm,
+            symmetry: Symmetry,
+        ) -> Result<Self, MazeError> {
+            Self::new_symmetric_from_rng(dimensions, algorithm, symmetry, &mut thread_rng())
+        }
+
+        /**
+         * Creates a new symmetric Maze like new_symmetric, making each of its random choices with
+         * rng.
+         */
+        fn new_symmetric_from_rng(
+            dimensions: (usize, usize),
+            algorithm: CreationAlgorithm,
+            symmetry: Symmetry,
+            rng: &mut dyn RngCore,
+        ) -> Result<Self, MazeError> {
+            Self::check_dimensions(dimensions)?;
+            // mazes only work well with odd-number dimensions, and mirrored ones need a wall down
+            // the middle
+            let odd = |side: usize| side + if side.is_multiple_of(2) { 1 } else { 0 };
+            let mirrored = |side: usize| odd(side) + (odd(side) - 1) % 4;
+            let (mirror_rows, mirror_cols) = match symmetry {
+                Symmetry::None => return Self::try_new_from_rng(dimensions, algorithm, rng),
+                Symmetry::Horizontal | Symmetry::Rotational180 => (false, true),
+                Symmetry::Vertical => (true, false),
+                Symmetry::Quad => (true, true),
             };
+            let height = if mirror_rows {
+                mirrored(dimensions.0)
+            } else {
+                dimensions.0
+            };
+            let width = if mirror_cols {
+                mirrored(dimensions.1)
+            } else {
+                dimensions.1
+            };
+            Self::check_dimensions((height, width))?;
 
-            append_frontiers(&cells, &mut frontiers, starter);
+            // generate the fundamental region, and size the Maze to fit however it came out
+            let mut region = Self::try_new_from_rng(
+                (
+                    if mirror_rows { height / 2 + 1 } else { height },
+                    if mirror_cols { width / 2 + 1 } else { width },
+                ),
+                algorithm,
+                rng,
+            )?;
+            region.cells[region.goalpoint.0][region.goalpoint.1].wall = true;
+            let (region_height, region_width) = region.dimensions;
+            let dimensions: (usize, usize) = (
+                if mirror_rows {
+                    2 * region_height - 1
+                } else {
+                    region_height
+                },
+                if mirror_cols {
+                    2 * region_width - 1
+                } else {
+                    region_width
+                },
+            );
+            let (seam_row, seam_col) = (region_height - 1, region_width - 1);
 
-            // while the list of frontier cells is not empty:
-            while frontiers.len() > 0 {
-                // pick a random frontier cell from the list, and mark it as not a wall
-                let rand_frontier_index: usize = rng.gen_range(0..frontiers.len());
-                let current: (usize, usize) = frontiers[rand_frontier_index];
-                cells[current.0][current.1].wall = false;
+            // each copy of the fundamental region, as whether it's flipped (rows, cols); a half
+            // turn flips both
+            let images: Vec<(bool, bool)> = match symmetry {
+                Symmetry::Horizontal => vec![(false, false), (false, true)],
+                Symmetry::Vertical => vec![(false, false), (true, false)],
+                Symmetry::Quad => vec![(false, false), (false, true), (true, false), (true, true)],
+                _ => vec![(false, false), (true, true)],
+            };
+            let image = |(flip_rows, flip_cols): (bool, bool), pos: (usize, usize)| {
+                (
+                    if flip_rows {
+                        dimensions.0 - 1 - pos.0
+                    } else {
+                        pos.0
+                    },
+                    if flip_cols {
+                        dimensions.1 - 1 - pos.1
+                    } else {
+                        pos.1
+                    },
+                )
+            };
 
-                // compute its two-neighbors (that aren't walls)
-                let mut two_neighbors = vec![];
-                for &(dy, dx) in &two_offsets {
-                    let two_neighbor: (isize, isize) =
-                        (current.0 as isize + dy, current.1 as isize + dx);
+            // the cells along the seams that a passage can cross from, which have to be reachable
+            // from the entrypoint (and, when rotating, have reachable partners across the seam)
+            let mut reachable = Self::flood(&region.cells, region.entrypoint);
+            let mut col_crossings: Vec<usize> = (1..region_height - 1)
+                .step_by(2)
+                .filter(|&y| {
+                    reachable[y][seam_col - 1]
+                        && (!matches!(symmetry, Symmetry::Rotational180)
+                            || reachable[region_height - 1 - y][seam_col - 1])
+                })
+                .collect();
+            if mirror_cols && col_crossings.is_empty() {
+                // only a Maze with open areas can get here; carve down the side of the seam to give
+                // the rotated halves somewhere to meet
+                for row in &mut region.cells[1..region_height - 1] {
+                    row[seam_col - 1].wall = false;
+                }
+                reachable = Self::flood(&region.cells, region.entrypoint);
+                col_crossings = (1..region_height - 1).step_by(2).collect();
+            }
+            let row_crossings: Vec<usize> = (1..region_width - 1)
+                .step_by(2)
+                .filter(|&x| reachable[seam_row - 1][x])
+                .collect();
 
-                    // if the two-neighbor is in the allowable area (not OOB and not on the very
-                    // edge),
-                    if two_neighbor.0 > 0
-                        && two_neighbor.0 < dimensions.0 as isize - 1
-                        && two_neighbor.1 > 0
-                        && two_neighbor.1 < dimensions.1 as isize - 1
-                        // and not a wall,
-                        && !cells[two_neighbor.0 as usize][two_neighbor.1 as usize].wall
-                    {
-                        // add it to the list of two-neighbors
-                        two_neighbors.push((two_neighbor.0 as usize, two_neighbor.1 as usize));
+            // copy the fundamental region into place
+            let mut cells = vec![
+                vec![
+                    Cell {
+                        wall: true,
+                        visited: false,
+                        tag: None,
+                        cost: 1
+                    };
+                    dimensions.1
+                ];
+                dimensions.0
+            ];
+            for &flips in &images {
+                for (y, row) in region.cells.iter().enumerate() {
+                    for (x, cell) in row.iter().enumerate() {
+                        let (image_y, image_x) = image(flips, (y, x));
+                        cells[image_y][image_x].wall = cell.wall;
                     }
                 }
+            }
 
-                // Pick a random two-neighbor and connect the randomly chosen frontier cell with it
-                // by setting the cell in-between to not a wall
-                if let Some((two_neighbor_y, two_neighbor_x)) = two_neighbors.choose(&mut rng) {
-                    cells[(*two_neighbor_y + current.0) / 2][(*two_neighbor_x + current.1) / 2]
-                        .wall = false;
+            // knock a passage through each seam
+            if mirror_cols {
+                let y = *col_crossings
+                    .choose(rng)
+                    .expect("the seam can always be crossed");
+                cells[y][seam_col].wall = false;
+                if mirror_rows {
+                    let y = *col_crossings
+                        .choose(rng)
+                        .expect("the seam can always be crossed");
+                    cells[dimensions.0 - 1 - y][seam_col].wall = false;
                 }
+            }
+            if mirror_rows {
+                let x = *row_crossings
+                    .choose(rng)
+                    .expect("the seam can always be crossed");
+                cells[seam_row][x].wall = false;
+            }
 
-                // compute the frontier cells of the randomly chosen frontier cell and add them to
-                // the list, if they aren't already in the list
-                append_frontiers(&cells, &mut frontiers, current);
-
-                // remove the (current) randomly chosen frontier cell from the list
-                frontiers.remove(rand_frontier_index);
+            // only the first and last copies of the entrypoint lead out of the Maze
+            let entrypoint = region.entrypoint;
+            let goalpoint = image(images[images.len() - 1], entrypoint);
+            for &flips in &images {
+                let (y, x) = image(flips, entrypoint);
+                cells[y][x].wall = (y, x) != entrypoint && (y, x) != goalpoint;
             }
 
-            Maze {
+            Ok(Maze {
                 dimensions,
                 entrypoint,
                 goalpoint,
+                exits: vec![goalpoint],
+                algorithm: None,
                 cells,
+                stats: SolveStats::default(),
+                log: None,
+                overlay: None,
+            })
+        }
+
+        /**
+         * Creates a new Maze of specified size whose shortest solution passes through at least
+         * min_fraction of its open cells. After generating it with the specified algorithm, the
+         * entrypoint and goalpoint are moved to the two spots on the outer wall that are farthest
+         * apart; if the solution still falls short, the Maze is regenerated, up to 100 times. If
+         * none of them make it (some sizes and algorithms just can't), SolutionTooShort says how
+         * close the best one came. Returns an error like try_new if the dimensions or the
+         * algorithm's parameters are ones it would reject.
+         */
+        pub fn new_with_min_solution(
+            dimensions: (usize, usize),
+            algorithm: CreationAlgorithm,
+            min_fraction: f64,
+        ) -> Result<Self, MazeError> {
+            let mut best: f64 = 0.0;
+            for _ in 0..100 {
+                let mut maze = Self::try_new(dimensions, algorithm)?;
+                let length = maze.move_openings_apart();
+                let open_cells: usize = maze
+                    .cells
+                    .iter()
+                    .map(|row| row.iter().filter(|cell| !cell.wall).count())
+                    .sum();
+                let fraction = length as f64 / open_cells as f64;
+
+                if fraction >= min_fraction {
+                    return Ok(maze);
+                }
+                best = best.max(fraction);
             }
+            Err(MazeError::SolutionTooShort(best))
         }
 
-        /*
-         * Solves this Maze via recursive depth-first search. Returns true as long as the Maze has
-         * been successfully solved--false otherwise, though this should never occur.
+        /**
+         * Creates a new Maze of specified size with the given text written across the middle of
+         * it, using the specified algorithm to generate the rest. Each line of text is drawn in a
+         * 3x5 font as open plazas inside a walled-off box, and each letter gets a short tunnel out
+         * to the nearest passage so everything stays connected. Letters like A and O enclose
+         * loops, so the result isn't a perfect Maze.
+         * Returns an error if the text has a character the font doesn't have, or doesn't fit.
          */
-        fn solve_from_backtracking(&mut self, pos: (usize, usize)) -> bool {
-            // base case: if we're on a wall, or a path we've been before, we can't be going towards
-            // the solution
-            if self.cells[pos.0][pos.1].wall || self.cells[pos.0][pos.1].visited {
-                return false;
+        pub fn new_with_text(
+            dimensions: (usize, usize),
+            text: &str,
+            algorithm: CreationAlgorithm,
+        ) -> Result<Self, MazeError> {
+            let lines: Vec<Vec<[u8; 5]>> = text
+                .lines()
+                .map(|line| {
+                    line.chars()
+                        .map(|character| {
+                            glyph(character).ok_or(MazeError::UnsupportedCharacter(character))
+                        })
+                        .collect()
+                })
+                .collect::<Result<_, _>>()?;
+            let longest = lines.iter().map(|line| line.len()).max().unwrap_or(0);
+            if longest == 0 {
+                return Self::try_new(dimensions, algorithm);
             }
 
-            // mark current cell as visited
-            self.cells[pos.0][pos.1].visited = true;
+            // mazes only work well with odd-number dimensions
+            let height = dimensions.0 + if dimensions.0.is_multiple_of(2) { 1 } else { 0 };
+            let width = dimensions.1 + if dimensions.1.is_multiple_of(2) { 1 } else { 0 };
 
-            // base case: if we're at the goalpoint, no more work needs to be done
-            if pos == self.goalpoint {
-                return true;
+            // letters are a column apart and lines a row apart, inside a box that leaves room for
+            // passages all the way around it
+            let block = (6 * lines.len() - 1, 4 * longest - 1);
+            if block.0 + 6 > height || block.1 + 6 > width {
+                return Err(MazeError::TextTooLarge);
             }
+            let even = |side: usize| side - side % 2;
+            let top_left = (
+                even((height - 2 - block.0) / 2).max(2),
+                even((width - 2 - block.1) / 2).max(2),
+            );
+            let bottom_right = (top_left.0 + block.0 + 1, top_left.1 + block.1 + 1);
+            let in_box = |(y, x): (usize, usize)| {
+                (top_left.0..=bottom_right.0).contains(&y)
+                    && (top_left.1..=bottom_right.1).contains(&x)
+            };
 
-            // recursive case: try going each direction
-            // this function's return type--bool--stops future recursion branches as it unwinds; we
-            // take advantage of short-circuit boolean evaluation
-            ({
-                // South
-                pos.0 + 1 < self.dimensions.0 && self.solve_from_backtracking((pos.0 + 1, pos.1))
-            }) || ({
-                // East
-                pos.1 + 1 < self.dimensions.1 && self.solve_from_backtracking((pos.0, pos.1 + 1))
-            }) || ({
-                // North
-                pos.0 as isize - 1 > 0 && self.solve_from_backtracking((pos.0 - 1, pos.1))
-            }) || ({
-                // West
-                pos.1 as isize - 1 > 0 && self.solve_from_backtracking((pos.0, pos.1 - 1))
-            }) || ({
-                // This cell isn't on the path to the solution, since going each direction results
-                // in a dead end
-                self.cells[pos.0][pos.1].visited = false;
-                false
-            })
-        }
+            let mask: Vec<Vec<bool>> = (0..height)
+                .map(|y| (0..width).map(|x| !in_box((y, x))).collect())
+                .collect();
+            let mut maze = Self::new_masked(&mask, algorithm)?;
 
-        /*
-         * Solves this Maze iteratively by filling in the dead-ends, which leaves only the correct
-         * path.
-         */
-        fn solve_from_dead_end_filling(&mut self) -> bool {
-            self.cells[self.entrypoint.0][self.entrypoint.1].visited = true;
-            self.cells[self.goalpoint.0][self.goalpoint.1].visited = true;
+            // draw the letters
+            let mut letters: Vec<(usize, usize)> = vec![];
+            for (i, line) in lines.iter().enumerate() {
+                let top = top_left.0 + 1 + 6 * i;
+                let left = top_left.1 + 1 + (block.1 - (4 * line.len() - 1)) / 2;
+                for (j, rows) in line.iter().enumerate() {
+                    for (dy, row) in rows.iter().enumerate() {
+                        for dx in 0..3 {
+                            if row & (0b100 >> dx) != 0 {
+                                letters.push((top + dy, left + 4 * j + dx));
+                            }
+                        }
+                    }
+                }
+            }
+            for &(y, x) in &letters {
+                maze.cells[y][x].wall = false;
+            }
 
+            // give each piece of each letter a tunnel out of the box to the rest of the Maze,
+            // taking the shortest route that doesn't brush up against any other letter
             let directions: [(isize, isize); 4] = [(1, 0), (-1, 0), (0, 1), (0, -1)];
+            let mut piece: Vec<Vec<Option<usize>>> = vec![vec![None; width]; height];
+            let mut pieces = 0;
+            for &start in &letters {
+                if piece[start.0][start.1].is_some() {
+                    continue;
+                }
+                let reached = Self::flood(&maze.cells, start);
+                for &(y, x) in &letters {
+                    if reached[y][x] {
+                        piece[y][x] = Some(pieces);
+                    }
+                }
+                pieces += 1;
+            }
+            for current_piece in 0..pieces {
+                // an earlier tunnel might've run into this piece already
+                let connected = Self::flood(&maze.cells, maze.entrypoint);
+                let start = letters
+                    .iter()
+                    .find(|&&(y, x)| piece[y][x] == Some(current_piece))
+                    .expect("every piece has a letter in it");
+                if connected[start.0][start.1] {
+                    continue;
+                }
 
-            // find the dead-ends
-            let mut dead_ends: Vec<(usize, usize)> = vec![];
-            for i in 1..self.cells.len() - 1 {
-                for j in 1..self.cells[0].len() - 1 {
-                    // if the cell isn't a wall, check each neighboring cell to see if it's a dead
-                    // end--if it is, it's marked unvisited and added to the list; otherwise, it's
-                    // marked visited
-                    if !self.cells[i][j].wall {
-                        let mut neighbor_count = 0;
+                let brushes_other_letter = |(y, x): (usize, usize)| {
+                    directions.iter().any(|&(dy, dx)| {
+                        let (ny, nx) = ((y as isize + dy) as usize, (x as isize + dx) as usize);
+                        piece[ny][nx].is_some_and(|other| other != current_piece)
+                    })
+                };
+                // if it's hemmed in by other letters (like the middle of an X), it has to join up
+                // with one of them instead
+                for hemmed_in in [false, true] {
+                    let mut parents: Vec<Vec<Option<(usize, usize)>>> =
+                        vec![vec![None; width]; height];
+                    let mut queue: VecDeque<(usize, usize)> = VecDeque::new();
+                    for &(y, x) in &letters {
+                        if piece[y][x] == Some(current_piece) {
+                            parents[y][x] = Some((y, x));
+                            queue.push_back((y, x));
+                        }
+                    }
+
+                    let mut exit = None;
+                    while let Some(current) = queue.pop_front() {
+                        if connected[current.0][current.1] {
+                            exit = Some(current);
+                            break;
+                        }
                         for &(dy, dx) in &directions {
-                            let neighbor = ((i as isize + dy) as usize, (j as isize + dx) as usize);
-                            if !self.cells[neighbor.0][neighbor.1].wall {
-                                neighbor_count += 1;
+                            let next = (
+                                (current.0 as isize + dy) as usize,
+                                (current.1 as isize + dx) as usize,
+                            );
+                            if next.0 > 0
+                                && next.0 < height - 1
+                                && next.1 > 0
+                                && next.1 < width - 1
+                                && (in_box(next) || !maze.cells[next.0][next.1].wall)
+                                && parents[next.0][next.1].is_none()
+                                && (hemmed_in || !brushes_other_letter(next))
+                            {
+                                parents[next.0][next.1] = Some(current);
+                                queue.push_back(next);
                             }
                         }
+                    }
 
-                        self.cells[i][j].visited = neighbor_count != 1;
-
-                        if !self.cells[i][j].visited {
-                            dead_ends.push((i as usize, j as usize));
+                    if let Some(mut current) = exit {
+                        while let Some(parent) = parents[current.0][current.1] {
+                            if parent == current {
+                                break;
+                            }
+                            maze.cells[current.0][current.1].wall = false;
+                            current = parent;
                         }
+                        break;
                     }
                 }
             }
-            while dead_ends.len() != 0 {
-                // pick a dead end out of the list, and find the cell in the Maze that connects to
-                // it
-                let dead_end: (usize, usize) = dead_ends.pop().unwrap();
-                let connector = directions
-                    .iter()
-                    .find_map(|&(dy, dx)| {
-                        let neighbor = (
-                            (dead_end.0 as isize + dy) as usize,
-                            (dead_end.1 as isize + dx) as usize,
-                        );
 
-                        if !self.cells[neighbor.0][neighbor.1].wall
-                            && self.cells[neighbor.0][neighbor.1].visited
-                        {
-                            Some(neighbor)
-                        } else {
-                            None // unreachable
+            Ok(maze)
+        }
+
+        /**
+         * Creates a new Maze of specified size with the features of the given template built in,
+         * using the specified algorithm to generate the rest. The Maze is fitted to the template
+         * the same way new_masked fits one to a mask, with the template's chambers opened up
+         * before the pieces are joined, so they end up connected to everything else. Without an
+         * entrance or goal set, they're put as near the top-left and bottom-right corners as the
+         * template's walls allow.
+         * Returns an error if the template reaches outside the Maze, or its walls split it up.
+         */
+        pub fn new_from_template(
+            dimensions: (usize, usize),
+            template: &MazeTemplate,
+            algorithm: CreationAlgorithm,
+        ) -> Result<Self, MazeError> {
+            let mut maze = Self::try_new(dimensions, algorithm)?;
+            let dimensions = maze.dimensions;
+            let inner = |(y, x): (usize, usize)| {
+                y > 0 && y < dimensions.0 - 1 && x > 0 && x < dimensions.1 - 1
+            };
+            if template.open.iter().any(|&(top_left, bottom_right)| {
+                !inner(top_left)
+                    || !inner(bottom_right)
+                    || top_left.0 > bottom_right.0
+                    || top_left.1 > bottom_right.1
+            }) || template
+                .walls
+                .iter()
+                .any(|&(y, x)| y >= dimensions.0 || x >= dimensions.1)
+            {
+                return Err(MazeError::InvalidTemplate);
+            }
+
+            let mut walled = vec![vec![false; dimensions.1]; dimensions.0];
+            for &(y, x) in &template.walls {
+                walled[y][x] = true;
+            }
+            let allowed = |pos: (isize, isize)| {
+                pos.0 >= 0
+                    && pos.1 >= 0
+                    && inner((pos.0 as usize, pos.1 as usize))
+                    && !walled[pos.0 as usize][pos.1 as usize]
+            };
+
+            let mut open: Vec<(usize, usize)> = vec![];
+            for &(top_left, bottom_right) in &template.open {
+                for y in top_left.0..=bottom_right.0 {
+                    for x in top_left.1..=bottom_right.1 {
+                        if allowed((y as isize, x as isize)) {
+                            open.push((y, x));
                         }
-                    })
-                    .unwrap();
+                    }
+                }
+            }
+            // an opening in the outer wall needs an open cell just inside it to lead to
+            for (y, x) in template.entrance.into_iter().chain(template.goal) {
+                let inside = if y >= dimensions.0 || x >= dimensions.1 {
+                    None
+                } else if x == 0 || x == dimensions.1 - 1 {
+                    inner((y, 1)).then_some((y, if x == 0 { 1 } else { x - 1 }))
+                } else if y == 0 || y == dimensions.0 - 1 {
+                    inner((1, x)).then_some((if y == 0 { 1 } else { y - 1 }, x))
+                } else {
+                    None
+                };
+                match inside {
+                    Some((y, x)) if !walled[y][x] => open.push((y, x)),
+                    _ => return Err(MazeError::InvalidTemplate),
+                }
+            }
 
-                // if there are two or more ways to go from connector (excluding dead_end), we've
-                // finished closing in a dead end; otherwise, we need to add connector as a new dead
-                // end
-                let mut paths_out_of_connector = 0;
+            if !maze.fit_to_mask_from_rng(&allowed, &open, &mut thread_rng()) {
+                return Err(MazeError::TemplateDisconnected);
+            }
+            let (entrypoint, goalpoint) = maze
+                .boundary_openings(&allowed)
+                .ok_or(MazeError::InvalidTemplate)?;
+            maze.entrypoint = template.entrance.unwrap_or(entrypoint);
+            maze.set_goalpoint(template.goal.unwrap_or(goalpoint));
+            maze.cells[maze.entrypoint.0][maze.entrypoint.1].wall = false;
+            maze.cells[maze.goalpoint.0][maze.goalpoint.1].wall = false;
 
-                for &(dy, dx) in &directions {
-                    let neighbor: (usize, usize) = (
-                        (connector.0 as isize + dx) as usize,
-                        (connector.1 as isize + dy) as usize,
-                    );
+            Ok(maze)
+        }
 
-                    if !self.cells[neighbor.0][neighbor.1].wall
-                        && self.cells[neighbor.0][neighbor.1].visited
+        /**
+         * Solves this Maze--marks each cell on the way to the goalpoint on its overlay--using our
+         * default choice of recursive backtracking. Returns whether a solution was
+         * found.
+         */
+        pub fn solve(&mut self) -> bool {
+            self.solve_from(SolvingAlgorithm::RecursiveBacktracking)
+                .is_ok()
+        }
+
+        /**
+         * Solves this Maze--marks each cell on the way to the goalpoint on its overlay--using the
+         * specified algorithm for doing so, and reports how it went. If no solution
+         * was found, the Maze is left unsolved, and the error says whether there's no way through
+         * the Maze at all, or the algorithm just couldn't find it.
+         * Dead-end filling only narrows a perfect Maze down to its solution: on a Maze with loops
+         * (after braiding, for example), filling in the dead ends leaves more than one path, so
+         * the path is completed by a breadth-first search through what's left, as reported by
+         * last_completed_by_bfs.
+         * With more than one exit, the algorithms that search the Maze head for the nearest exit
+         * they can reach, while the ones that walk it (backtracking, wall following, Tremaux's,
+         * the Pledge algorithm, and the random mouse) stop at the first exit they come to. That
+         * exit becomes the goalpoint, and the report says which one it was. Once every exit has
+         * been removed, this returns NoExit.
+         *
+         * ```
+         * use rust_maze::{CreationAlgorithm, Heuristic, Maze, SolvingAlgorithm};
+         *
+         * let mut maze = Maze::new_from((31, 31), CreationAlgorithm::Prim);
+         * let report = maze
+         *     .solve_from(SolvingAlgorithm::AStar(Heuristic::Manhattan))
+         *     .unwrap();
+         * assert_eq!(report.path_length, maze.solution_length());
+         * maze.unsolve();
+         * assert!(!maze.is_solved());
+         * ```
+         */
+        pub fn solve_from(
+            &mut self,
+            algorithm: SolvingAlgorithm,
+        ) -> Result<SolveReport, MazeError> {
+            use SolvingAlgorithm::*;
+            if self.exits.is_empty() {
+                self.unsolve();
+                return Err(MazeError::NoExit);
+            }
+            if self.exits.len() == 1 {
+                return self.solve_to_goalpoint(algorithm, false);
+            }
+
+            // head for the nearest exit there's a way to, skipping any that can't be reached
+            let distances = self.distance_grid(self.entrypoint);
+            if let Some(&nearest) = self
+                .exits
+                .iter()
+                .filter(|&&(y, x)| distances[y][x].is_some())
+                .min_by_key(|&&(y, x)| distances[y][x])
+            {
+                self.goalpoint = nearest;
+            }
+            let walks = matches!(
+                algorithm,
+                RecursiveBacktracking
+                    | IterativeBacktracking
+                    | WallFollowerLeft
+                    | WallFollowerRight
+                    | Tremaux
+                    | Pledge
+                    | RandomMouse { .. }
+            );
+            self.solve_to_goalpoint(algorithm, walks)
+        }
+
+        /**
+         * Solves this Maze like solve_from with a single exit: from the entrypoint to the
+         * goalpoint, or to whichever exit a walking algorithm reaches first if any_exit is set.
+         */
+        fn solve_to_goalpoint(
+            &mut self,
+            algorithm: SolvingAlgorithm,
+            any_exit: bool,
+        ) -> Result<SolveReport, MazeError> {
+            self.unsolve();
+            let mut stats = SolveStats {
+                events: self.stats.events.take(),
+                ..SolveStats::default()
+            };
+            if any_exit {
+                stats.exits = Some(self.exits.clone());
+            }
+            let timer = Instant::now();
+            let (path, run) = self.run_solver(algorithm, stats);
+            let elapsed = timer.elapsed();
+            (self.goalpoint, self.overlay, self.stats) = run.finish();
+
+            if let Some(path) = path {
+                Ok(SolveReport {
+                    algorithm,
+                    path_length: self.solution_length(),
+                    cells_visited: self.stats.explored,
+                    cells_filled: self.stats.filled,
+                    elapsed,
+                    iterations: self.stats.iterations,
+                    exit: self.goalpoint,
+                    path_cost: self.path_cost(&path),
+                    path,
+                })
+            } else if Self::flood(&self.cells, self.entrypoint)[self.goalpoint.0][self.goalpoint.1]
+            {
+                Err(MazeError::NoPath)
+            } else {
+                Err(MazeError::Unsolvable)
+            }
+        }
+
+        /**
+         * Solves this Maze via every solving algorithm in turn, and reports how each one did. A*
+         * search is run both with the Manhattan distance and weighted by 2, and the random mouse
+         * gets up to 100 steps per cell. Recursive backtracking is left out of Mazes over 100,000
+         * cells, since it could overflow the stack, and IDA* search is left out of Mazes over
+         * 40,000 cells or with loops, since it would search them over and over again for far too
+         * long. The Maze is left solved by the last algorithm, jump point search, which marks a
+         * shortest path.
+         */
+        pub fn compare_solvers(&mut self) -> Vec<SolverComparison> {
+            use SolvingAlgorithm::*;
+            let mut algorithms: Vec<SolvingAlgorithm> = vec![];
+            if self.dimensions.0 * self.dimensions.1 <= 100_000 {
+                algorithms.push(RecursiveBacktracking);
+            }
+            algorithms.extend([
+                IterativeBacktracking,
+                DeadEndFilling,
+                BreadthFirst,
+                AStar(Heuristic::Manhattan),
+                AStar(Heuristic::Weighted(2.0)),
+                Dijkstra,
+                WallFollowerLeft,
+                WallFollowerRight,
+                Tremaux,
+                BidirectionalBfs,
+                ParallelBidirectional,
+                GreedyBestFirst,
+                Pledge,
+                RandomMouse {
+                    max_steps: 100 * self.dimensions.0 * self.dimensions.1,
+                },
+                FloodFill,
+                Lee,
+            ]);
+            if self.dimensions.0 * self.dimensions.1 <= 40_000 && !self.has_loops() {
+                algorithms.push(IdaStar);
+            }
+            algorithms.push(JumpPoint);
+
+            algorithms
+                .into_iter()
+                .map(|algorithm| SolverComparison {
+                    algorithm,
+                    outcome: self.solve_from(algorithm),
+                })
+                .collect()
+        }
+
+        /**
+         * Solves this Maze like solve_from, but gives up after expanding max_expansions cells,
+         * and returns where it left off, so the search can be picked back up later via
+         * resume_limited. The search spreads out like the algorithm does: toward the goalpoint
+         * for A*, greedy best-first, and jump point search, depth first for the algorithms that
+         * walk the Maze (backtracking, wall following, Tremaux's, the Pledge algorithm, and the
+         * random mouse), and in waves from the entrypoint for the rest. A solve that runs out of
+         * expansions leaves the Maze unsolved; mark_explored shows how far it got.
+         */
+        pub fn solve_limited(
+            &mut self,
+            algorithm: SolvingAlgorithm,
+            max_expansions: usize,
+        ) -> SolveOutcome {
+            let (height, width) = self.dimensions;
+            let mut parents: Vec<Vec<Option<(usize, usize)>>> = vec![vec![None; width]; height];
+            parents[self.entrypoint.0][self.entrypoint.1] = Some(self.entrypoint);
+            let progress = SolveProgress {
+                algorithm,
+                parents,
+                costs: vec![vec![0; width]; height],
+                expanded: vec![vec![false; width]; height],
+                frontier: VecDeque::from([self.entrypoint]),
+                visited: 0,
+            };
+            self.resume_limited(progress, max_expansions)
+        }
+
+        /**
+         * Picks an exhausted solve_limited back up where it left off, expanding up to
+         * max_expansions more cells. The progress should come from this Maze, with its walls
+         * unchanged since.
+         */
+        pub fn resume_limited(
+            &mut self,
+            mut progress: SolveProgress,
+            max_expansions: usize,
+        ) -> SolveOutcome {
+            use SolvingAlgorithm::*;
+            let mut run = SolveRun::new(self, SolveStats::default());
+
+            // best-first searches take the cell that looks closest to the goalpoint, the rest
+            // take either the newest or the oldest cell found
+            let best_first = match progress.algorithm {
+                AStar(heuristic) => Some((heuristic, false)),
+                JumpPoint => Some((Heuristic::Manhattan, false)),
+                GreedyBestFirst => Some((Heuristic::Manhattan, true)),
+                _ => None,
+            };
+            let depth_first = matches!(
+                progress.algorithm,
+                RecursiveBacktracking
+                    | IterativeBacktracking
+                    | WallFollowerLeft
+                    | WallFollowerRight
+                    | Tremaux
+                    | Pledge
+                    | RandomMouse { .. }
+            );
+            let goal = self.goalpoint;
+            let key = |cost: usize, pos: (usize, usize)| match best_first {
+                Some((heuristic, greedy)) => {
+                    let distance_left = heuristic.distance(pos, goal);
+                    let estimate = if greedy {
+                        distance_left
+                    } else {
+                        cost as f64 + distance_left
+                    };
+                    estimate.to_bits()
+                }
+                None => 0,
+            };
+            let mut open: BinaryHeap<Reverse<(u64, (usize, usize))>> = BinaryHeap::new();
+            if best_first.is_some() {
+                open.extend(
+                    progress
+                        .frontier
+                        .drain(..)
+                        .map(|pos| Reverse((key(progress.costs[pos.0][pos.1], pos), pos))),
+                );
+            }
+
+            let mut expansions = 0;
+            while expansions < max_expansions {
+                let next = if best_first.is_some() {
+                    open.pop().map(|Reverse((_, pos))| pos)
+                } else if depth_first {
+                    progress.frontier.pop_back()
+                } else {
+                    progress.frontier.pop_front()
+                };
+                let Some(current) = next else {
+                    break;
+                };
+                // a cell can be in the open set more than once, if a shorter way to it was found
+                if progress.expanded[current.0][current.1] {
+                    continue;
+                }
+                progress.expanded[current.0][current.1] = true;
+                progress.visited += 1;
+                expansions += 1;
+                run.explore(current);
+
+                if current == goal {
+                    run.mark_trail(&progress.parents, current);
+                    let path = run
+                        .marked_path()
+                        .expect("the trail runs back to the entrypoint");
+                    (self.goalpoint, self.overlay, self.stats) = run.finish();
+                    return SolveOutcome::Solved(path);
+                }
+
+                let cost = progress.costs[current.0][current.1] + 1;
+                for next in (MazeView { maze: self }).neighbors(current) {
+                    let found = progress.parents[next.0][next.1].is_some();
+                    if !found
+                        || (best_first.is_some()
+                            && !progress.expanded[next.0][next.1]
+                            && cost < progress.costs[next.0][next.1])
                     {
-                        paths_out_of_connector += 1;
+                        progress.parents[next.0][next.1] = Some(current);
+                        progress.costs[next.0][next.1] = cost;
+                        if best_first.is_some() {
+                            open.push(Reverse((key(cost, next), next)));
+                        } else {
+                            progress.frontier.push_back(next);
+                        }
                     }
                 }
+            }
 
-                // if we're still at a dead end, make the connector a new dead end
-                if paths_out_of_connector == 1 {
-                    self.cells[connector.0][connector.1].visited = false;
-                    dead_ends.push(connector);
+            if best_first.is_some() {
+                let mut kept: HashSet<(usize, usize)> = HashSet::new();
+                progress.frontier = open
+                    .into_sorted_vec()
+                    .into_iter()
+                    .rev()
+                    .map(|Reverse((_, pos))| pos)
+                    .filter(|&pos| !progress.expanded[pos.0][pos.1] && kept.insert(pos))
+                    .collect();
+            }
+            (self.goalpoint, self.overlay, self.stats) = run.finish();
+            SolveOutcome::Exhausted {
+                visited: progress.visited,
+                frontier: progress.frontier.iter().copied().collect(),
+                progress,
+            }
+        }
+
+        /**
+         * Marks every cell the given budgeted solve has expanded so far, to show how far it got.
+         * Like any solution, unsolve clears it.
+         */
+        pub fn mark_explored(&mut self, progress: &SolveProgress) {
+            self.unsolve();
+            for (y, row) in progress.expanded.iter().enumerate().take(self.dimensions.0) {
+                for (x, &expanded) in row.iter().enumerate().take(self.dimensions.1) {
+                    if expanded && !self.cells[y][x].wall {
+                        self.mark((y, x));
+                    }
                 }
             }
-            true
         }
-    }
 
-    /*
-     * Formats a Maze to be pretty-printable with the println!() macro.
-     */
-    impl fmt::Display for Maze {
-        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        /**
+         * Solves this Maze using the specified algorithm like solve_from, but also records every
+         * cell the algorithm looked at along the way, in order. The solution marked is the same
+         * one solve_from would mark. Use render_trace to see how the search spread out.
+         */
+        pub fn solve_traced(&mut self, algorithm: SolvingAlgorithm) -> SolveTrace {
+            let stats = SolveStats {
+                trace: Some(vec![]),
+                ..SolveStats::default()
+            };
+            let (path, run) = self.run_solver(algorithm, stats);
+            (self.goalpoint, self.overlay, self.stats) = run.finish();
+            let solved = path.is_some();
+
+            let order = self.stats.trace.take().unwrap_or_default();
+            let mut index: Vec<Vec<Option<usize>>> =
+                vec![vec![None; self.dimensions.1]; self.dimensions.0];
+            for (i, &(y, x)) in order.iter().enumerate() {
+                index[y][x].get_or_insert(i);
+            }
+            SolveTrace {
+                order,
+                index,
+                solved,
+            }
+        }
+
+        /**
+         * Solves this Maze using the specified algorithm like solve_from, but hands back what the
+         * solver did a step at a time, for watching it work: each cell it looks at, backs out
+         * of, or fills in comes as its own SolveEvent, in order, and then the solution (if it
+         * found one). The Maze is solved all at once, by the same code solve_from uses, then
+         * unsolved, and each step marks it again as it's taken; after the last, it's marked just
+         * as solve_from would leave it. If the steps are dropped before then, the Maze is left
+         * unsolved. If there's no way through, the steps end without a Found.
+         *
+         * ```
+         * use rust_maze::{CreationAlgorithm, Maze, SolveEvent, SolvingAlgorithm};
+         *
+         * let mut maze = Maze::new_seeded((21, 21), CreationAlgorithm::Kruskal, 3);
+         * maze.braid(0.5);
+         * for algorithm in [
+         *     SolvingAlgorithm::IterativeBacktracking,
+         *     SolvingAlgorithm::DeadEndFilling,
+         *     SolvingAlgorithm::BreadthFirst,
+         *     SolvingAlgorithm::WallFollowerLeft,
+         * ] {
+         *     let mut batch = maze.clone();
+         *     let report = batch.solve_from(algorithm).unwrap();
+         *
+         *     let mut steps = maze.solve_stepwise(algorithm);
+         *     // nothing's marked until the first step
+         *     assert!(!steps.maze().is_solved());
+         *     let mut found = None;
+         *     while let Some(event) = steps.next() {
+         *         // steps.maze() is the frame to draw for this step
+         *         if let SolveEvent::Found(path) = event {
+         *             found = Some(path);
+         *         }
+         *     }
+         *     drop(steps);
+         *     assert_eq!(found, Some(report.path));
+         *     assert_eq!(maze, batch);
+         * }
+         *
+         * // stopping partway leaves the Maze unsolved
+         * maze.solve_stepwise(SolvingAlgorithm::BreadthFirst).take(10).count();
+         * assert!(!maze.is_solved());
+         * ```
+         */
+        pub fn solve_stepwise(&mut self, algorithm: SolvingAlgorithm) -> SolveSteps<'_> {
+            self.stats = SolveStats {
+                events: Some(vec![]),
+                ..SolveStats::default()
+            };
+            let path = self.solve_from(algorithm).ok().map(|report| report.path);
+            let events = self.stats.events.take().unwrap_or_default();
+            let overlay = self.overlay.take();
+            SolveSteps {
+                maze: self,
+                events: events.into(),
+                overlay,
+                path,
+                finished: false,
+            }
+        }
+
+        /**
+         * Returns this Maze drawn like it's printed, except that each cell the trace looked at
+         * is labeled with the last digit of when it was first looked at--so the waves of a
+         * breadth-first search show up as runs of digits spreading out from the entrypoint.
+         */
+        pub fn render_trace(&self, trace: &SolveTrace) -> String {
+            self.render_labeled(|pos| Self::digit(&trace.index, pos))
+        }
+
+        /**
+         * Returns this Maze drawn like it's printed, except that each cell reached by the waves of
+         * the last solve is labeled with the last digit of its wave number, if it was solved by
+         * Lee's algorithm.
+         */
+        pub fn render_waves(&self) -> Option<String> {
+            self.stats
+                .waves
+                .as_ref()
+                .map(|waves| self.render_labeled(|pos| Self::digit(waves, pos)))
+        }
+
+        /**
+         * Returns this Maze drawn like it's printed, except that each tagged passage is labeled
+         * with whatever character glyph picks for its tag.
+         */
+        pub fn render_tags(&self, glyph: impl Fn(u32) -> char) -> String {
+            self.render_labeled(|pos| self.get_tag(pos).map(|tag| format!("{:>2}", glyph(tag))))
+        }
+
+        /**
+         * Returns the last digit of the number at the given (y, x) of numbers, as a label.
+         */
+        fn digit(numbers: &[Vec<Option<usize>>], (y, x): (usize, usize)) -> Option<String> {
+            numbers
+                .get(y)
+                .and_then(|row| row.get(x))
+                .copied()
+                .flatten()
+                .map(|index| format!("{:>2}", index % 10))
+        }
+
+        /**
+         * Returns this Maze drawn like it's printed, except that any cell label comes up with a
+         * (two-character) label for is drawn as that label: black on blue along the solution, and
+         * yellow elsewhere.
+         */
+        fn render_labeled(&self, label: impl Fn((usize, usize)) -> Option<String>) -> String {
+            let mut rendered = String::new();
             for (y, row) in self.cells.iter().enumerate() {
                 for (x, cell) in row.iter().enumerate() {
-                    write!(f, "{}", {
-                        if (y, x) == self.entrypoint {
-                            "\u{2592}\u{2592}".red().on_red()
-                        } else if (y, x) == self.goalpoint {
-                            "\u{2592}\u{2592}".green().on_green()
-                        } else if cell.wall {
-                            "\u{2588}\u{2588}".white().on_white()
-                        } else if cell.visited {
-                            "\u{2593}\u{2593}".blue().on_blue()
+                    let label = label((y, x));
+                    let drawn = if (y, x) == self.entrypoint {
+                        label
+                            .unwrap_or("\u{2592}\u{2592}".to_string())
+                            .black()
+                            .on_red()
+                    } else if self.exits.contains(&(y, x)) {
+                        label
+                            .unwrap_or("\u{2591}\u{2591}".to_string())
+                            .black()
+                            .on_green()
+                    } else if cell.wall {
+                        "\u{2588}\u{2588}".white().on_white()
+                    } else if let Some(label) = label {
+                        // the solution stands out from the cells that were only looked at
+                        if self.is_marked((y, x)) {
+                            label.black().on_blue()
                         } else {
-                            "\u{00a0}\u{00a0}".clear()
+                            label.yellow()
                         }
-                    })?;
+                    } else {
+                        "\u{00a0}\u{00a0}".clear()
+                    };
+                    rendered.push_str(&drawn.to_string());
                 }
-                write!(f, "{}", "\u{00a0}".clear())?;
+                rendered.push_str(&"\u{00a0}".clear().to_string());
                 if y != self.dimensions.0 - 1 {
-                    write!(f, "\n")?;
+                    rendered.push('\n');
                 }
             }
-            Ok(())
+            rendered
+        }
+
+        /**
+         * Runs the specified algorithm over this Maze without changing it, counting on top of
+         * whatever's already in stats (which also say which exits a walker may stop at). Returns
+         * the path it found, if it did, along with everything it marked and counted; solve_from
+         * and the Solver impl both go through here.
+         */
+        fn run_solver(
+            &self,
+            algorithm: SolvingAlgorithm,
+            stats: SolveStats,
+        ) -> (Option<Path>, SolveRun<'_>) {
+            let mut run = SolveRun::new(self, stats);
+            use SolvingAlgorithm::*;
+            let solved = match algorithm {
+                RecursiveBacktracking => {
+                    let mut explored = vec![vec![false; self.dimensions.1]; self.dimensions.0];
+                    run.solve_from_backtracking(self.entrypoint, &mut explored)
+                }
+                IterativeBacktracking => run.solve_from_iterative_backtracking(),
+                DeadEndFilling => run.solve_from_dead_end_filling(),
+                BreadthFirst => run.solve_from_breadth_first(),
+                AStar(heuristic) => {
+                    let goalpoint = self.goalpoint;
+                    // every step costs at least the cheapest one, so the distance left times that
+                    // still never overestimates
+                    let cheapest = self.cheapest_step() as f64;
+                    run.solve_from_best_first(false, &|pos| {
+                        heuristic.distance(pos, goalpoint) * cheapest
+                    })
+                }
+                GreedyBestFirst => {
+                    let goalpoint = self.goalpoint;
+                    run.solve_from_best_first(true, &|pos| {
+                        Heuristic::Manhattan.distance(pos, goalpoint)
+                    })
+                }
+                Dijkstra => {
+                    let costs = self.step_costs();
+                    run.solve_from_dijkstra(&|(y, x)| costs[y][x])
+                }
+                WallFollowerLeft => run.solve_from_wall_follower([3, 0, 1, 2]),
+                WallFollowerRight => run.solve_from_wall_follower([1, 0, 3, 2]),
+                Tremaux => run.solve_from_tremaux(),
+                Pledge => run.solve_from_pledge(),
+                RandomMouse { max_steps } => run.solve_from_random_mouse(max_steps),
+                FloodFill => run.solve_from_flood_fill(),
+                JumpPoint => run.solve_from_jump_point(),
+                Lee => run.solve_from_lee(),
+                IdaStar => run.solve_from_ida_star(),
+                ParallelBidirectional => run.solve_from_parallel_bidirectional(),
+                BidirectionalBfs => run.solve_from_bidirectional_bfs(),
+            };
+
+            // don't leave a partial (or wrong) solution lying around
+            let path = solved.then(|| {
+                run.marked_path()
+                    .expect("a solved Maze has a path through its marks")
+            });
+            if path.is_none() {
+                run.overlay = None;
+            }
+            (path, run)
+        }
+
+        /**
+         * Solves the path between two cells of this Maze rather than from the entrypoint to the
+         * goalpoint, using the specified algorithm, and marks it like any other solution (so
+         * unsolve clears it). The entrypoint and goalpoint stay where they are. Returns an error
+         * if either cell is outside the Maze or a wall, or the algorithm can't find a path
+         * between them.
+         */
+        pub fn solve_between(
+            &mut self,
+            start: (usize, usize),
+            goal: (usize, usize),
+            algorithm: SolvingAlgorithm,
+        ) -> Result<Path, MazeError> {
+            for pos in [start, goal] {
+                self.check_coordinate(pos)?;
+                if !self.is_passage(pos) {
+                    return Err(MazeError::NotOpen(pos));
+                }
+            }
+
+            // the solvers all go from the entrypoint to the goalpoint, so stand in for them
+            let openings = (self.entrypoint, self.goalpoint);
+            (self.entrypoint, self.goalpoint) = (start, goal);
+            let path = self
+                .solve_to_goalpoint(algorithm, false)
+                .and_then(|_| self.marked_path().ok_or(MazeError::NoPath));
+            (self.entrypoint, self.goalpoint) = openings;
+            path
+        }
+
+        /**
+         * Solves a route from the entrypoint through each of the waypoints in order, then on to
+         * the goalpoint, using the specified algorithm for each leg, and marks the whole route.
+         * Legs may cross or double back over each other, in which case the returned Path visits
+         * those cells more than once; its length is the length of the entire route. Returns an
+         * error (leaving the Maze unsolved) if a waypoint is a wall, or any leg can't be solved.
+         */
+        pub fn solve_via(
+            &mut self,
+            waypoints: &[(usize, usize)],
+            algorithm: SolvingAlgorithm,
+        ) -> Result<Path, MazeError> {
+            let mut stops = vec![self.entrypoint];
+            stops.extend_from_slice(waypoints);
+            stops.push(self.goalpoint);
+
+            let mut cells: Vec<(usize, usize)> = vec![self.entrypoint];
+            let mut explored = 0;
+            for leg in stops.windows(2) {
+                let path = match self.solve_between(leg[0], leg[1], algorithm) {
+                    Ok(path) => path,
+                    Err(error) => {
+                        self.unsolve();
+                        return Err(error);
+                    }
+                };
+                explored += self.stats.explored;
+                // each leg starts where the last one ended
+                cells.extend_from_slice(&path.cells()[1..]);
+            }
+
+            // solving each leg cleared the one before it, so mark the whole route at the end
+            self.unsolve();
+            self.stats = SolveStats {
+                explored,
+                ..SolveStats::default()
+            };
+            for &(y, x) in &cells {
+                self.mark((y, x));
+            }
+            Ok(Path { cells })
+        }
+
+        /**
+         * Solves this Maze using the specified algorithm as though the blocked cells were walls,
+         * without changing its walls, and marks the path like any other solution. Blocked cells
+         * that are already walls (or outside the Maze) don't matter. Returns an error if the
+         * entrypoint or goalpoint is blocked, the blocked cells cut the goalpoint off entirely, or
+         * the algorithm can't find a path anyway.
+         */
+        pub fn solve_avoiding(
+            &mut self,
+            blocked: &HashSet<(usize, usize)>,
+            algorithm: SolvingAlgorithm,
+        ) -> Result<Path, MazeError> {
+            for pos in [self.entrypoint, self.goalpoint] {
+                if blocked.contains(&pos) {
+                    return Err(MazeError::OpeningBlocked(pos));
+                }
+            }
+
+            // wall off the blocked passages for now, remembering which ones to reopen
+            let walled: Vec<(usize, usize)> = blocked
+                .iter()
+                .copied()
+                .filter(|&pos| self.is_passage(pos))
+                .collect();
+            for &(y, x) in &walled {
+                self.cells[y][x].wall = true;
+            }
+
+            let result = self
+                .solve_from(algorithm)
+                .and_then(|_| self.marked_path().ok_or(MazeError::NoPath));
+
+            for &(y, x) in &walled {
+                self.cells[y][x].wall = false;
+            }
+            result
+        }
+
+        /**
+         * Solves this Maze with the given Solver, and marks the path it returns. Returns whether
+         * the Solver found a path; one that isn't an unbroken run of open cells from the
+         * entrypoint to the goalpoint doesn't count, and leaves the Maze unsolved.
+         */
+        pub fn solve_with(&mut self, solver: &dyn Solver) -> bool {
+            self.unsolve();
+            self.stats = SolveStats::default();
+            let Some(path) = solver.solve(&MazeView { maze: self }) else {
+                return false;
+            };
+
+            let cells = path.cells();
+            let valid = cells.first() == Some(&self.entrypoint)
+                && cells.last() == Some(&self.goalpoint)
+                && cells.iter().all(|&cell| self.is_passage(cell))
+                && cells
+                    .windows(2)
+                    .all(|step| step[0].0.abs_diff(step[1].0) + step[0].1.abs_diff(step[1].1) == 1);
+            if valid {
+                for &(y, x) in cells {
+                    self.mark((y, x));
+                }
+            }
+            valid
+        }
+
+        /**
+         * Solves this Maze via Dijkstra's algorithm, finding the path from the entrypoint to the
+         * goalpoint with the lowest total cost, where cost gives what stepping into each cell
+         * costs in place of what set_cost gave it. Returns whether a solution was found; if not,
+         * the Maze is left unsolved. The total cost is available from last_solution_cost
+         * afterward.
+         */
+        pub fn solve_with_costs(&mut self, cost: impl Fn((usize, usize)) -> usize) -> bool {
+            let mut run = SolveRun::new(self, SolveStats::default());
+            let solved = run.solve_from_dijkstra(&cost);
+            if !solved {
+                run.overlay = None;
+            }
+            (self.goalpoint, self.overlay, self.stats) = run.finish();
+            solved
+        }
+
+        /**
+         * Returns the (height, width) of this Maze, which may be bigger than was asked for.
+         */
+        pub fn dimensions(&self) -> (usize, usize) {
+            self.dimensions
+        }
+
+        /**
+         * Returns how many rows of cells this Maze has, top to bottom.
+         */
+        pub fn rows(&self) -> usize {
+            self.dimensions.0
+        }
+
+        /**
+         * Returns how many columns of cells this Maze has, left to right.
+         */
+        pub fn cols(&self) -> usize {
+            self.dimensions.1
+        }
+
+        /**
+         * Returns what's at the cell in the given row (counting down from 0 at the top) and
+         * column (counting across from 0 at the left), or None if it's outside this Maze.
+         */
+        pub fn at(&self, row: usize, col: usize) -> Option<CellKind> {
+            let cell = self.cells.get(row)?.get(col)?;
+            Some(if (row, col) == self.entrypoint {
+                CellKind::Entrance
+            } else if self.exits.contains(&(row, col)) {
+                CellKind::Exit
+            } else if cell.wall {
+                CellKind::Wall
+            } else if self.is_marked((row, col)) {
+                CellKind::Solution
+            } else {
+                CellKind::Passage
+            })
+        }
+
+        /**
+         * Returns what's at the given (row, col) cell like at, but borrowed, the way indexing
+         * hands it back. Returns None if it's outside this Maze.
+         */
+        pub fn get(&self, (row, col): (usize, usize)) -> Option<&CellKind> {
+            // every kind of cell is a constant, so there's always one to borrow
+            Some(match self.at(row, col)? {
+                CellKind::Wall => &CellKind::Wall,
+                CellKind::Passage => &CellKind::Passage,
+                CellKind::Entrance => &CellKind::Entrance,
+                CellKind::Exit => &CellKind::Exit,
+                CellKind::Solution => &CellKind::Solution,
+            })
+        }
+
+        /**
+         * Iterates over every cell of this Maze along with what's there, in row-major order:
+         * left to right along the top row, then along each row below it in turn. The cells are
+         * looked at as the iterator goes, so nothing is copied, and it can run from the bottom
+         * right corner backwards as well.
+         */
+        pub fn cells(
+            &self,
+        ) -> impl DoubleEndedIterator<Item = ((usize, usize), CellKind)> + ExactSizeIterator + '_
+        {
+            let width = self.dimensions.1;
+            (0..self.dimensions.0 * width).map(move |index| {
+                let (row, col) = (index / width, index % width);
+                (
+                    (row, col),
+                    self.at(row, col).expect("the index is inside the Maze"),
+                )
+            })
+        }
+
+        /**
+         * Iterates over the cells that aren't walls (passages, the entrance, the exits, and the
+         * solution) in the same order as cells.
+         */
+        pub fn passages(&self) -> impl DoubleEndedIterator<Item = ((usize, usize), CellKind)> + '_ {
+            self.cells().filter(|&(_, kind)| kind != CellKind::Wall)
+        }
+
+        /**
+         * Returns the passages that lead nowhere--the ones with just one neighbor that isn't a
+         * wall--in the same order as cells, leaving out the entrance and exits. They're found
+         * fresh from the walls each time, whatever's marked on the Maze.
+         *
+         * ```
+         * use rust_maze::Maze;
+         *
+         * let maze: Maze = concat!(
+         *     "██▒▒██████\n",
+         *     "██      ██\n",
+         *     "██  ██████\n",
+         *     "██      ░░\n",
+         *     "██████████",
+         * )
+         * .parse()
+         * .unwrap();
+         * assert_eq!(maze.dead_ends(), vec![(1, 3)]);
+         * assert_eq!(maze.junctions(), vec![(1, 1)]);
+         * ```
+         */
+        pub fn dead_ends(&self) -> Vec<(usize, usize)> {
+            self.passages()
+                .map(|(pos, _)| pos)
+                .filter(|&pos| {
+                    pos != self.entrypoint && !self.exits.contains(&pos) && self.degree(pos) == 1
+                })
+                .collect()
+        }
+
+        /**
+         * Returns the passages where the way splits--the ones with three or four neighbors that
+         * aren't walls--in the same order as cells, found fresh like dead_ends.
+         */
+        pub fn junctions(&self) -> Vec<(usize, usize)> {
+            self.passages()
+                .map(|(pos, _)| pos)
+                .filter(|&pos| self.degree(pos) >= 3)
+                .collect()
+        }
+
+        /**
+         * Returns the passages of this Maze as a graph, as they are right now: a node for every
+         * passage, and an edge of length 1 between every two that are next to each other.
+         *
+         * ```
+         * use rust_maze::{CreationAlgorithm, Maze};
+         *
+         * // a 5x3 maze only has room for one passage, straight down the middle
+         * let maze = Maze::new_from((5, 3), CreationAlgorithm::Prim);
+         * let graph = maze.to_graph();
+         * assert_eq!(graph.nodes().len(), 5);
+         * assert_eq!(graph.edges().len(), 4);
+         * let middle = graph.node((2, 1)).unwrap();
+         * assert_eq!(graph.neighbors(middle).count(), 2);
+         *
+         * // compressed, it's just the way in and the way out, four steps apart
+         * let graph = maze.to_graph_compressed();
+         * assert_eq!(graph.nodes(), &[(1, 0), (3, 2)]);
+         * assert_eq!(graph.edges()[0].length, 4);
+         * ```
+         */
+        pub fn to_graph(&self) -> MazeGraph {
+            self.graph(|_| true)
+        }
+
+        /**
+         * Returns the passages of this Maze as a graph like to_graph, but with the corridors
+         * collapsed: nodes are only the junctions, dead ends, and openings, and each edge is a
+         * corridor between two of them, as long as it takes to walk. A ring of passages with
+         * none of those on it gets a node at its first cell, so nothing is left out.
+         */
+        pub fn to_graph_compressed(&self) -> MazeGraph {
+            let mut nodes: Vec<Vec<bool>> = self
+                .cells
+                .iter()
+                .enumerate()
+                .map(|(y, row)| {
+                    (0..row.len())
+                        .map(|x| {
+                            self.is_passage((y, x))
+                                && (self.degree((y, x)) != 2
+                                    || (y, x) == self.entrypoint
+                                    || self.exits.contains(&(y, x)))
+                        })
+                        .collect()
+                })
+                .collect();
+            // flood each piece of the Maze, to find any without a node on it
+            let mut covered = vec![vec![false; self.dimensions.1]; self.dimensions.0];
+            let mut stack: Vec<(usize, usize)> = vec![];
+            for (pos, _) in self.passages() {
+                if covered[pos.0][pos.1] || !self.is_passage(pos) {
+                    continue;
+                }
+                let mut has_node = false;
+                stack.push(pos);
+                covered[pos.0][pos.1] = true;
+                while let Some(current) = stack.pop() {
+                    has_node |= nodes[current.0][current.1];
+                    for next in self.open_neighbors(current) {
+                        if !covered[next.0][next.1] {
+                            covered[next.0][next.1] = true;
+                            stack.push(next);
+                        }
+                    }
+                }
+                if !has_node {
+                    nodes[pos.0][pos.1] = true;
+                }
+            }
+            self.graph(|(y, x)| nodes[y][x])
+        }
+
+        /**
+         * Returns the graph whose nodes are the passages is_node picks out, joined by the ways
+         * between them that don't pass through another node.
+         */
+        fn graph(&self, is_node: impl Fn((usize, usize)) -> bool) -> MazeGraph {
+            let nodes: Vec<(usize, usize)> = self
+                .passages()
+                .map(|(pos, _)| pos)
+                // an opening that's been walled up doesn't go anywhere
+                .filter(|&pos| self.is_passage(pos) && is_node(pos))
+                .collect();
+            let mut graph = MazeGraph {
+                adjacency: vec![vec![]; nodes.len()],
+                nodes,
+                edges: vec![],
+            };
+            for (from, &start) in graph.nodes.iter().enumerate() {
+                for first in self.open_neighbors(start) {
+                    // follow the corridor until it comes to a node
+                    let (mut previous, mut current, mut length) = (start, first, 1);
+                    while !is_node(current) {
+                        let next = self
+                            .open_neighbors(current)
+                            .find(|&next| next != previous)
+                            .expect("a corridor cell has a way on");
+                        (previous, current, length) = (current, next, length + 1);
+                    }
+                    let to = graph.node(current).expect("the corridor ends at a node");
+                    // every corridor is followed from both ends, so only keep it from one
+                    if from < to || (from == to && first < previous) {
+                        graph.adjacency[from].push(graph.edges.len());
+                        if to != from {
+                            graph.adjacency[to].push(graph.edges.len());
+                        }
+                        graph.edges.push(GraphEdge { from, to, length });
+                    }
+                }
+            }
+            graph
+        }
+
+        /**
+         * Iterates over the walls, in the same order as cells.
+         */
+        pub fn walls(&self) -> impl DoubleEndedIterator<Item = ((usize, usize), CellKind)> + '_ {
+            self.cells().filter(|&(_, kind)| kind == CellKind::Wall)
+        }
+
+        /**
+         * Iterates over the cells around the edge of this Maze--its outer wall and any openings
+         * in it--in the same order as cells.
+         */
+        pub fn border_cells(
+            &self,
+        ) -> impl DoubleEndedIterator<Item = ((usize, usize), CellKind)> + '_ {
+            let (height, width) = self.dimensions;
+            self.cells().filter(move |&((row, col), _)| {
+                row == 0 || col == 0 || row == height - 1 || col == width - 1
+            })
+        }
+
+        /**
+         * Returns whether the cell in the given row and column is a wall. Cells outside this Maze
+         * aren't walls, or anything else.
+         */
+        pub fn is_wall(&self, row: usize, col: usize) -> bool {
+            self.at(row, col) == Some(CellKind::Wall)
+        }
+
+        /**
+         * Returns whether the cell in the given row and column can be walked through: a passage,
+         * the entrance, or an exit.
+         */
+        pub fn is_open(&self, row: usize, col: usize) -> bool {
+            self.is_passage((row, col))
+        }
+
+        /**
+         * Returns the (y, x) of the entrance and of the exit the solvers head for.
+         */
+        pub fn openings(&self) -> ((usize, usize), (usize, usize)) {
+            (self.entrypoint, self.goalpoint)
+        }
+
+        /**
+         * Returns the (y, x) of the entrance.
+         */
+        pub fn entrance(&self) -> (usize, usize) {
+            self.entrypoint
+        }
+
+        /**
+         * Returns the (y, x) of the exit the solvers head for (see exits for all of them).
+         */
+        pub fn exit(&self) -> (usize, usize) {
+            self.goalpoint
+        }
+
+        /**
+         * Swaps the entrance and the exit the solvers head for, so the Maze is solved the other
+         * way around; any other exits stay where they are. A solution marked on the Maze still
+         * joins the two, so it's kept, and now leads from the old exit back to the old entrance.
+         */
+        pub fn swap_openings(&mut self) {
+            let entrance = self.entrypoint;
+            self.entrypoint = self.goalpoint;
+            self.set_goalpoint(entrance);
+        }
+
+        /**
+         * Returns how many cells the last call to solve_from looked at while searching for the
+         * solution, to compare how much work each solving algorithm does.
+         */
+        pub fn cells_explored(&self) -> usize {
+            self.stats.explored
+        }
+
+        /**
+         * Returns the total cost of the path found by the last solve, if it was solved by
+         * Dijkstra's algorithm (with each step costing what set_cost gave the cell unless
+         * solve_with_costs was used).
+         */
+        pub fn last_solution_cost(&self) -> Option<usize> {
+            self.stats.cost
+        }
+
+        /**
+         * Returns what walking the given path costs: the cost (see set_cost) of every cell it
+         * steps into after its first, added up. The total stops at usize::MAX rather than
+         * overflowing, however absurd the costs.
+         */
+        pub fn path_cost(&self, path: &Path) -> usize {
+            path.cells()
+                .iter()
+                .skip(1)
+                .map(|&(y, x)| self.get_cost((y, x)).unwrap_or(1) as usize)
+                .fold(0, usize::saturating_add)
+        }
+
+        /**
+         * Returns how many steps it takes to get from each cell to the goalpoint (or None for cells
+         * that can't reach it), if the last solve was by flood fill. Handy for heatmaps, or for
+         * steering something toward the goalpoint from anywhere in the Maze.
+         */
+        pub fn last_distance_map(&self) -> Option<&[Vec<Option<usize>>]> {
+            self.stats.distances.as_deref()
+        }
+
+        /**
+         * Returns which wave of the last solve reached each cell--the entrypoint is wave 0, its
+         * neighbors wave 1, and so on, with None for cells the waves never got to--if it was
+         * solved by Lee's algorithm. render_waves draws it.
+         */
+        pub fn last_wave_grid(&self) -> Option<&[Vec<Option<usize>>]> {
+            self.stats.waves.as_deref()
+        }
+
+        /**
+         * Returns how many cells the last solve filled in, if it was solved by dead-end filling.
+         */
+        pub fn last_cells_filled(&self) -> Option<usize> {
+            self.stats.filled
+        }
+
+        /**
+         * Returns whether the last solve was by dead-end filling on a Maze with loops, which
+         * leaves more than one path, so the solution was picked out of what was left by a
+         * breadth-first search.
+         */
+        pub fn last_completed_by_bfs(&self) -> bool {
+            self.stats.completed_by_bfs
+        }
+
+        /**
+         * Returns how many moves the last solve took to walk from the entrypoint to the goalpoint,
+         * if it was solved by walking through the Maze (following a wall, for example).
+         */
+        pub fn last_solution_steps(&self) -> Option<usize> {
+            self.stats.steps
+        }
+
+        /**
+         * Returns how many cells are marked as part of the solution.
+         */
+        pub fn solution_length(&self) -> usize {
+            self.overlay.as_ref().map_or(0, SolutionOverlay::len)
+        }
+
+        /**
+         * Returns whether this Maze currently has the solution marked, until unsolve (or an edit
+         * that would make it wrong) removes it.
+         */
+        pub fn is_solved(&self) -> bool {
+            self.is_marked(self.goalpoint)
+        }
+
+        /**
+         * Returns the (y, x) of each cell of the marked solution in order, from the entrypoint to
+         * the goalpoint, or None if this Maze isn't solved. It's worked out from the marked cells
+         * each time, by walking them from the entrypoint, so it doesn't matter what order the
+         * solver found them in--for solvers that mark everywhere they walked, it's the shortest
+         * way through the marks--and there's nothing to go stale once unsolve clears them.
+         */
+        pub fn solution(&self) -> Option<Vec<(usize, usize)>> {
+            self.marked_path().map(|path| path.cells)
+        }
+
+        /**
+         * Checks the solution currently marked on this Maze, however it got there--by a solving
+         * algorithm, by mark_path, or by whatever marked the Maze it was loaded from. The marked
+         * cells must include the entrypoint and the goalpoint, none of them may be walls, and
+         * each one must be reachable from the entrypoint by stepping between marked neighbors.
+         * This is checked from scratch, without any of the solvers' own code. The check reports
+         * whether the marked cells are a simple path: one that never branches, loops, or doubles
+         * back, so only its two ends have fewer than two marked neighbors.
+         */
+        pub fn verify_solution(&self) -> Result<SolutionCheck, MazeError> {
+            let (height, width) = self.dimensions;
+            let marked = |pos: (usize, usize)| self.is_marked(pos);
+            let marked_neighbors = |(y, x): (usize, usize)| {
+                [
+                    (y + 1, x),
+                    (y, x + 1),
+                    (y.wrapping_sub(1), x),
+                    (y, x.wrapping_sub(1)),
+                ]
+                .into_iter()
+                .filter(|&neighbor| marked(neighbor))
+                .collect::<Vec<(usize, usize)>>()
+            };
+
+            let mut path_length = 0;
+            for (y, row) in self.cells.iter().enumerate() {
+                for (x, cell) in row.iter().enumerate() {
+                    if marked((y, x)) && cell.wall {
+                        return Err(MazeError::SolutionThroughWall((y, x)));
+                    }
+                    path_length += marked((y, x)) as usize;
+                }
+            }
+            if path_length == 0 {
+                return Err(MazeError::NotSolved);
+            }
+            for end in [self.entrypoint, self.goalpoint] {
+                if !marked(end) {
+                    return Err(MazeError::SolutionMissesEnd(end));
+                }
+            }
+
+            // walk out from the entrypoint, through marked cells only
+            let mut reached = vec![vec![false; width]; height];
+            reached[self.entrypoint.0][self.entrypoint.1] = true;
+            let mut stack: Vec<(usize, usize)> = vec![self.entrypoint];
+            while let Some(pos) = stack.pop() {
+                for (y, x) in marked_neighbors(pos) {
+                    if !reached[y][x] {
+                        reached[y][x] = true;
+                        stack.push((y, x));
+                    }
+                }
+            }
+            if !reached[self.goalpoint.0][self.goalpoint.1] {
+                return Err(MazeError::SolutionBroken(self.goalpoint));
+            }
+            let marks = || self.overlay.iter().flat_map(|overlay| overlay.cells());
+            if let Some(pos) = marks().find(|&(y, x)| !reached[y][x]) {
+                return Err(MazeError::SolutionBroken(pos));
+            }
+
+            // a simple path's ends are each next to one other marked cell, and the rest are each
+            // next to two
+            let mut simple = true;
+            for pos in marks() {
+                let expected = if self.entrypoint == self.goalpoint {
+                    0
+                } else if pos == self.entrypoint || pos == self.goalpoint {
+                    1
+                } else {
+                    2
+                };
+                simple &= marked_neighbors(pos).len() == expected;
+            }
+            Ok(SolutionCheck {
+                path_length,
+                simple,
+            })
+        }
+
+        /**
+         * Scores how hard this Maze is to solve, from the shortest path between the entrypoint
+         * and the goalpoint (found by breadth-first search, trying South, East, North, and West in
+         * turn, so the same Maze always gets the same score--whether or not it's solved). The
+         * score is 100 times the average of four measures, each from 0 to 1:
+         * - how many of the cells along the path are junctions: junctions / solution_length
+         * - how far wrong turns lead: branch_depth / (branch_depth + 5)
+         * - how many of the open cells are dead ends: min(1, 4 * dead_ends / open cells)
+         * - how far out of its way the path winds: 1 - (the Manhattan distance between the
+         *   entrypoint and goalpoint + 1) / solution_length
+         *
+         * A Maze with no way through scores 0 with nothing measured but its dead ends.
+         */
+        pub fn difficulty(&self) -> DifficultyScore {
+            let directions: [(isize, isize); 4] = [(1, 0), (0, 1), (-1, 0), (0, -1)];
+            let neighbors = |pos: (usize, usize)| {
+                directions.iter().filter_map(move |&(dy, dx)| {
+                    let (y, x) = (pos.0 as isize + dy, pos.1 as isize + dx);
+                    (y >= 0 && x >= 0 && self.is_passage((y as usize, x as usize)))
+                        .then_some((y as usize, x as usize))
+                })
+            };
+            let open_cells = self.passages().count();
+            let dead_ends = self.dead_ends().len();
+            let dead_end_share = (4.0 * dead_ends as f64 / open_cells.max(1) as f64).min(1.0);
+
+            // walk downhill from the entrypoint to find the shortest path
+            let to_goal = self.distance_grid(self.goalpoint);
+            let mut path: Vec<(usize, usize)> = vec![self.entrypoint];
+            if to_goal[self.entrypoint.0][self.entrypoint.1].is_none() {
+                return DifficultyScore {
+                    solution_length: 0,
+                    coverage: 0.0,
+                    junctions: 0,
+                    branches: 0,
+                    branch_depth: 0.0,
+                    dead_ends,
+                    score: 0.0,
+                };
+            }
+            while let Some(&pos) = path.last().filter(|&&pos| pos != self.goalpoint) {
+                let distance = to_goal[pos.0][pos.1].unwrap_or(0);
+                let next = neighbors(pos)
+                    .find(|&(y, x)| to_goal[y][x] == Some(distance.wrapping_sub(1)))
+                    .expect("every cell but the goalpoint has a neighbor closer to it");
+                path.push(next);
+            }
+            let on_path: HashSet<(usize, usize)> = path.iter().copied().collect();
+
+            let mut junctions = 0;
+            let mut branches = 0;
+            for &pos in &path {
+                let off_path = neighbors(pos)
+                    .filter(|next| !on_path.contains(next))
+                    .count();
+                branches += off_path;
+                if off_path > 0 {
+                    junctions += 1;
+                }
+            }
+
+            // how far every cell off the path is from the nearest cell on it
+            let mut from_path: Vec<Vec<Option<usize>>> =
+                vec![vec![None; self.dimensions.1]; self.dimensions.0];
+            let mut queue: VecDeque<(usize, usize)> = path.iter().copied().collect();
+            for &(y, x) in &path {
+                from_path[y][x] = Some(0);
+            }
+            let (mut total_depth, mut off_path_cells) = (0, 0);
+            while let Some(pos) = queue.pop_front() {
+                let distance = from_path[pos.0][pos.1].unwrap_or(0);
+                for (y, x) in neighbors(pos) {
+                    if from_path[y][x].is_none() {
+                        from_path[y][x] = Some(distance + 1);
+                        total_depth += distance + 1;
+                        off_path_cells += 1;
+                        queue.push_back((y, x));
+                    }
+                }
+            }
+            let branch_depth = total_depth as f64 / off_path_cells.max(1) as f64;
+
+            let coverage = path.len() as f64 / open_cells.max(1) as f64;
+            let measures = [
+                junctions as f64 / path.len() as f64,
+                branch_depth / (branch_depth + 5.0),
+                dead_end_share,
+                1.0 - (Heuristic::Manhattan.distance(self.entrypoint, self.goalpoint) + 1.0)
+                    / path.len() as f64,
+            ];
+            DifficultyScore {
+                solution_length: path.len(),
+                coverage,
+                junctions,
+                branches,
+                branch_depth,
+                dead_ends,
+                score: 100.0 * measures.iter().sum::<f64>() / measures.len() as f64,
+            }
+        }
+
+        /**
+         * Returns whether this Maze and the other one have the same walls and openings, whatever
+         * is marked on them--unlike ==, which also compares the marks, so a solved copy of a
+         * Maze isn't equal to an unsolved one.
+         */
+        pub fn structurally_equal(&self, other: &Maze) -> bool {
+            self.dimensions == other.dimensions
+                && self.entrypoint == other.entrypoint
+                && self.exits == other.exits
+                && self
+                    .cells
+                    .iter()
+                    .flatten()
+                    .zip(other.cells.iter().flatten())
+                    .all(|(cell, other)| cell.wall == other.wall)
+        }
+
+        /**
+         * Returns a hash of this Maze's walls and openings, whatever is marked on it, for
+         * bucketing Mazes before comparing them with structurally_equal: structurally equal Mazes
+         * always have the same fingerprint, on any platform and in any run. Two Mazes of the same
+         * size that differ by a single wall always have different ones, but otherwise different
+         * Mazes can collide, and the hash isn't cryptographic, so it's no use against anyone
+         * making collisions on purpose.
+         *
+         * ```
+         * use rust_maze::{CreationAlgorithm, Maze};
+         *
+         * let first = Maze::new_seeded((21, 21), CreationAlgorithm::Kruskal, 7);
+         * let mut second = Maze::new_seeded((21, 21), CreationAlgorithm::Kruskal, 7);
+         * assert_eq!(first.fingerprint(), second.fingerprint());
+         *
+         * // the corners between passages are always walls
+         * second.carve((2, 2)).unwrap();
+         * assert_ne!(first.fingerprint(), second.fingerprint());
+         * ```
+         */
+        pub fn fingerprint(&self) -> u64 {
+            // 64-bit FNV-1a, which has no keys to randomize. Each step is invertible, so inputs of
+            // the same length that differ in one byte can't end up with the same hash
+            let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+            let mut write = |bytes: &[u8]| {
+                for &byte in bytes {
+                    hash = (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3);
+                }
+            };
+            let mut write_cell = |(y, x): (usize, usize)| {
+                write(&(y as u64).to_le_bytes());
+                write(&(x as u64).to_le_bytes());
+            };
+            write_cell(self.dimensions);
+            write_cell(self.entrypoint);
+            write_cell((self.exits.len(), 0));
+            for &exit in &self.exits {
+                write_cell(exit);
+            }
+            for cell in self.cells.iter().flatten() {
+                write(&[cell.wall as u8]);
+            }
+            hash
+        }
+
+        /**
+         * Returns whether every passage in this Maze can be reached from the entrypoint (the
+         * goalpoint included), so nothing is cut off.
+         */
+        pub fn validate_connected(&self) -> bool {
+            let reached = Self::flood(&self.cells, self.entrypoint);
+            self.cells.iter().zip(&reached).all(|(row, reached)| {
+                row.iter()
+                    .zip(reached)
+                    .all(|(cell, &reached)| cell.wall || reached)
+            })
+        }
+
+        /**
+         * Checks that this Maze is put together the way a Maze should be: the entrance and every
+         * exit are passages inside the grid (with at least one exit, none of them the entrance),
+         * the outer wall is unbroken everywhere else, and every passage can be reached from the
+         * entrance. Returns every issue found, in that order, each with the cell it shows up at;
+         * a piece of the Maze that's cut off is reported once, at its first cell (reading top to
+         * bottom, left to right). Nothing here changes the Maze or needs it to be solved.
+         */
+        pub fn validate(&self) -> Result<(), Vec<ValidationIssue>> {
+            let (height, width) = self.dimensions;
+            let inside = |(y, x): (usize, usize)| y < height && x < width;
+            let mut issues: Vec<ValidationIssue> = vec![];
+
+            for &opening in std::iter::once(&self.entrypoint).chain(&self.exits) {
+                if !inside(opening) {
+                    issues.push(ValidationIssue::OutsideGrid(opening));
+                } else if self.cells[opening.0][opening.1].wall {
+                    issues.push(ValidationIssue::BlockedOpening(opening));
+                }
+            }
+            if self.exits.is_empty() {
+                issues.push(ValidationIssue::NoExit);
+            }
+            if self.exits.contains(&self.entrypoint) {
+                issues.push(ValidationIssue::EntranceIsExit(self.entrypoint));
+            }
+            for (pos, kind) in self.border_cells() {
+                if kind == CellKind::Passage || kind == CellKind::Solution {
+                    issues.push(ValidationIssue::BrokenBorder(pos));
+                }
+            }
+
+            // flood each piece that can't be reached, so it's only reported once
+            let mut reached = if inside(self.entrypoint) {
+                Self::flood(&self.cells, self.entrypoint)
+            } else {
+                vec![vec![false; width]; height]
+            };
+            for y in 0..height {
+                for x in 0..width {
+                    if !self.cells[y][x].wall && !reached[y][x] {
+                        issues.push(ValidationIssue::Disconnected((y, x)));
+                        let piece = Self::flood(&self.cells, (y, x));
+                        for (reached, piece) in reached.iter_mut().zip(&piece) {
+                            for (reached, &piece) in reached.iter_mut().zip(piece) {
+                                *reached |= piece;
+                            }
+                        }
+                    }
+                }
+            }
+
+            if issues.is_empty() {
+                Ok(())
+            } else {
+                Err(issues)
+            }
+        }
+
+        /**
+         * Returns whether any exit can be reached from the entrance, without solving this Maze
+         * or changing it at all.
+         */
+        pub fn is_solvable(&self) -> bool {
+            let (height, width) = self.dimensions;
+            let (y, x) = self.entrypoint;
+            if y >= height || x >= width || self.cells[y][x].wall {
+                return false;
+            }
+            let reached = Self::flood(&self.cells, self.entrypoint);
+            self.exits
+                .iter()
+                .any(|&(y, x)| y < height && x < width && reached[y][x])
+        }
+
+        /**
+         * Returns whether this Maze is perfect: every passage (the openings included) can be
+         * reached from every other by exactly one route, with no loops and nothing cut off. A
+         * perfect Maze is what dead-end filling narrows down to its solution on its own.
+         *
+         * ```
+         * use rust_maze::{CreationAlgorithm, Maze};
+         *
+         * let mut maze = Maze::new_from((21, 21), CreationAlgorithm::Kruskal);
+         * assert!(maze.is_perfect());
+         * maze.braid(1.0);
+         * assert!(!maze.is_perfect());
+         * ```
+         */
+        pub fn is_perfect(&self) -> bool {
+            let (height, width) = self.dimensions;
+            let open = |(y, x): (usize, usize)| !self.cells[y][x].wall;
+            // a tree joins its cells with one fewer connection than it has cells, and never joins
+            // two that are already connected
+            let mut sets = DisjointSet::new(height * width);
+            let mut passages = 0;
+            let mut connections = 0;
+            for y in 0..height {
+                for x in 0..width {
+                    if !open((y, x)) {
+                        continue;
+                    }
+                    passages += 1;
+                    for neighbor in [(y + 1, x), (y, x + 1)] {
+                        if neighbor.0 < height && neighbor.1 < width && open(neighbor) {
+                            if !sets.union(y * width + x, neighbor.0 * width + neighbor.1) {
+                                return false;
+                            }
+                            connections += 1;
+                        }
+                    }
+                }
+            }
+            passages > 0 && connections == passages - 1
+        }
+
+        /**
+         * Removes the solution to this Maze, along with anything else the last solver marked
+         * (like the dead ends a wall follower walked into), and returns whether there was
+         * anything to remove. The solvers only ever mark cells on the overlay, never change the
+         * walls, so this just drops the overlay, and afterwards the Maze is exactly as it was
+         * before it was solved, ready to be solved again with any algorithm.
+         *
+         * ```
+         * use rust_maze::{CreationAlgorithm, Heuristic, Maze, SolvingAlgorithm};
+         *
+         * let mut maze = Maze::new_seeded((21, 21), CreationAlgorithm::Kruskal, 5);
+         * maze.braid(0.5);
+         * let before = maze.to_grid();
+         * assert!(!maze.unsolve());
+         * for algorithm in [
+         *     SolvingAlgorithm::RecursiveBacktracking,
+         *     SolvingAlgorithm::IterativeBacktracking,
+         *     SolvingAlgorithm::DeadEndFilling,
+         *     SolvingAlgorithm::BreadthFirst,
+         *     SolvingAlgorithm::AStar(Heuristic::Manhattan),
+         *     SolvingAlgorithm::Dijkstra,
+         *     SolvingAlgorithm::WallFollowerLeft,
+         *     SolvingAlgorithm::WallFollowerRight,
+         *     SolvingAlgorithm::Tremaux,
+         *     SolvingAlgorithm::BidirectionalBfs,
+         *     SolvingAlgorithm::GreedyBestFirst,
+         *     SolvingAlgorithm::Pledge,
+         *     SolvingAlgorithm::RandomMouse { max_steps: 1_000_000 },
+         *     SolvingAlgorithm::FloodFill,
+         *     SolvingAlgorithm::JumpPoint,
+         *     SolvingAlgorithm::Lee,
+         *     SolvingAlgorithm::IdaStar,
+         *     SolvingAlgorithm::ParallelBidirectional,
+         * ] {
+         *     maze.solve_from(algorithm).unwrap();
+         *     assert!(maze.is_solved());
+         *     assert!(maze.unsolve());
+         *     assert!(!maze.is_solved());
+         *     assert_eq!(maze.to_grid(), before);
+         * }
+         * ```
+         */
+        pub fn unsolve(&mut self) -> bool {
+            self.overlay
+                .take()
+                .is_some_and(|overlay| !overlay.is_empty())
+        }
+
+        /**
+         * Returns the cells marked on this Maze by the last solve (or mark_path, or whatever
+         * else marked it), or None if nothing is marked.
+         */
+        pub fn overlay(&self) -> Option<&SolutionOverlay> {
+            self.overlay.as_ref().filter(|overlay| !overlay.is_empty())
+        }
+
+        /**
+         * Marks this Maze with the given overlay in place of whatever was marked, as though a
+         * solver had marked it, so is_solved, solution, printing, and the rest all go by it.
+         * Returns OverlayMismatch if the overlay was made for a Maze of other dimensions.
+         */
+        pub fn set_overlay(&mut self, overlay: SolutionOverlay) -> Result<(), MazeError> {
+            if overlay.dimensions() != self.dimensions {
+                return Err(MazeError::OverlayMismatch);
+            }
+            self.overlay = Some(overlay);
+            Ok(())
+        }
+
+        /**
+         * Solves this Maze like solve_from, but leaves it alone--unmarked, and with the record
+         * of how the last solve went untouched--and hands back the overlay the solve would have
+         * marked along with the report. Any number of these can be held at once, from
+         * different algorithms, and drawn with with_overlay. With more than one exit, the report
+         * says which one was reached.
+         *
+         * ```
+         * use rust_maze::{CreationAlgorithm, Maze, SolvingAlgorithm};
+         *
+         * let mut maze = Maze::new_seeded((21, 21), CreationAlgorithm::Kruskal, 2);
+         * maze.braid(0.5);
+         * let (_, wall_follower) = maze.solve_overlay(SolvingAlgorithm::WallFollowerLeft).unwrap();
+         * let (_, shortest) = maze.solve_overlay(SolvingAlgorithm::BreadthFirst).unwrap();
+         * assert!(!maze.is_solved());
+         * assert!(wall_follower.len() >= shortest.len());
+         *
+         * // side by side
+         * let left = maze.with_overlay(&wall_follower).to_string();
+         * let right = maze.with_overlay(&shortest).to_string();
+         * for (left, right) in left.lines().zip(right.lines()) {
+         *     println!("{}  {}", left, right);
+         * }
+         *
+         * maze.set_overlay(shortest).unwrap();
+         * assert!(maze.is_solved());
+         * ```
+         */
+        pub fn solve_overlay(
+            &self,
+            algorithm: SolvingAlgorithm,
+        ) -> Result<(SolveReport, SolutionOverlay), MazeError> {
+            let mut scratch = Maze {
+                dimensions: self.dimensions,
+                entrypoint: self.entrypoint,
+                goalpoint: self.goalpoint,
+                exits: self.exits.clone(),
+                algorithm: self.algorithm,
+                cells: self.cells.clone(),
+                stats: SolveStats::default(),
+                log: None,
+                overlay: None,
+            };
+            let report = scratch.solve_from(algorithm)?;
+            let overlay = scratch
+                .overlay
+                .unwrap_or_else(|| SolutionOverlay::new(self.dimensions));
+            Ok((report, overlay))
+        }
+
+        /**
+         * Returns this Maze to print like it usually does, but with the given overlay marked
+         * on it in place of its own.
+         */
+        pub fn with_overlay<'a>(&'a self, overlay: &'a SolutionOverlay) -> impl fmt::Display + 'a {
+            Overlaid {
+                maze: self,
+                overlay: Some(overlay),
+            }
+        }
+
+        /**
+         * Perturbs this Maze in place by moving the root of its spanning tree the specified number
+         * of steps, like the generation step of CreationAlgorithm::OriginShift. The Maze stays
+         * perfect, so this can be called repeatedly (reprinting in between) to watch it morph.
+         * Expects this Maze to be perfect to begin with--any loops are removed in the process. The
+         * solution is removed, since it probably isn't correct anymore.
+         */
+        pub fn shift_origin(&mut self, steps: usize) {
+            self.shift_origin_from_rng(steps, &mut thread_rng());
+        }
+
+        /**
+         * Shifts the origin of this Maze like shift_origin, making each of its random choices with
+         * rng.
+         */
+        fn shift_origin_from_rng(&mut self, steps: usize, rng: &mut dyn RngCore) {
+            self.unsolve();
+
+            // recover the tree as parent pointers by searching outward from an arbitrary origin
+            // cell
+            let origin: (usize, usize) = (1, 1);
+            let mut parents: Vec<Vec<Option<(usize, usize)>>> =
+                vec![vec![None; self.dimensions.1]; self.dimensions.0];
+            let mut queue: VecDeque<(usize, usize)> = VecDeque::from([origin]);
+            let mut seen = vec![vec![false; self.dimensions.1]; self.dimensions.0];
+            seen[origin.0][origin.1] = true;
+            while let Some(current) = queue.pop_front() {
+                for (dy, dx) in [(-2, 0), (2, 0), (0, -2), (0, 2)] {
+                    let two_neighbor: (isize, isize) =
+                        (current.0 as isize + dy, current.1 as isize + dx);
+                    if two_neighbor.0 > 0
+                        && two_neighbor.0 < self.dimensions.0 as isize - 1
+                        && two_neighbor.1 > 0
+                        && two_neighbor.1 < self.dimensions.1 as isize - 1
+                    {
+                        let two_neighbor = (two_neighbor.0 as usize, two_neighbor.1 as usize);
+                        // follow the passage between them, if there is one
+                        if !self.cells[(current.0 + two_neighbor.0) / 2]
+                            [(current.1 + two_neighbor.1) / 2]
+                            .wall
+                            && !seen[two_neighbor.0][two_neighbor.1]
+                        {
+                            seen[two_neighbor.0][two_neighbor.1] = true;
+                            parents[two_neighbor.0][two_neighbor.1] = Some(current);
+                            queue.push_back(two_neighbor);
+                        }
+                    }
+                }
+            }
+
+            let mut cells = Canvas {
+                cells: std::mem::take(&mut self.cells),
+                log: None,
+                progress: None,
+            };
+            Self::shift_tree(&mut cells, &mut parents, origin, steps, rng);
+            self.cells = cells.cells;
+        }
+
+        /**
+         * Regenerates the rectangle between top_left and bottom_right (inclusive, and rounded
+         * outward to the nearest walls) with the specified algorithm, leaving the rest of the Maze
+         * untouched. Passages are knocked through the sides of the rectangle wherever they're
+         * needed to keep everything connected, and each side that used to have a passage through it
+         * gets at least one back (which can leave a loop in a perfect Maze). The solution is
+         * removed, since it probably isn't correct anymore, and so are the tags and costs inside
+         * the rectangle's walls, since the cells they were attached to are gone.
+         * Returns an error if the rectangle doesn't fit inside the Maze, the algorithm can't make a
+         * Maze of its size (unicursal labyrinths need sides that are multiples of four), it keeps
+         * cutting part of the Maze off (as caves can), or one of its probabilities isn't a finite
+         * number; the Maze is left as it was, unsolved.
+         */
+        pub fn regenerate_region(
+            &mut self,
+            top_left: (usize, usize),
+            bottom_right: (usize, usize),
+            algorithm: CreationAlgorithm,
+        ) -> Result<(), MazeError> {
+            self.regenerate_region_from_rng(top_left, bottom_right, algorithm, &mut thread_rng())
+        }
+
+        /**
+         * Regenerates a rectangle of this Maze like regenerate_region, making each of its random
+         * choices with rng.
+         */
+        fn regenerate_region_from_rng(
+            &mut self,
+            top_left: (usize, usize),
+            bottom_right: (usize, usize),
+            algorithm: CreationAlgorithm,
+            rng: &mut dyn RngCore,
+        ) -> Result<(), MazeError> {
+            algorithm.check_parameters()?;
+            let top_left = (top_left.0 - top_left.0 % 2, top_left.1 - top_left.1 % 2);
+            let bottom_right = (
+                bottom_right.0 + bottom_right.0 % 2,
+                bottom_right.1 + bottom_right.1 % 2,
+            );
+            if bottom_right.0 >= self.dimensions.0
+                || bottom_right.1 >= self.dimensions.1
+                || top_left.0 >= bottom_right.0
+                || top_left.1 >= bottom_right.1
+            {
+                return Err(MazeError::InvalidRegion);
+            }
+            let size = (
+                bottom_right.0 - top_left.0 + 1,
+                bottom_right.1 - top_left.1 + 1,
+            );
+            self.unsolve();
+
+            // the spots on each side of the rectangle a passage could go through, as (side, spot),
+            // skipping the outer wall of the Maze itself
+            let mut crossings: Vec<(usize, (usize, usize))> = vec![];
+            for x in (top_left.1 + 1..bottom_right.1).step_by(2) {
+                if top_left.0 > 0 {
+                    crossings.push((0, (top_left.0, x)));
+                }
+                if bottom_right.0 < self.dimensions.0 - 1 {
+                    crossings.push((1, (bottom_right.0, x)));
+                }
+            }
+            for y in (top_left.0 + 1..bottom_right.0).step_by(2) {
+                if top_left.1 > 0 {
+                    crossings.push((2, (y, top_left.1)));
+                }
+                if bottom_right.1 < self.dimensions.1 - 1 {
+                    crossings.push((3, (y, bottom_right.1)));
+                }
+            }
+            // the cells just outside and inside the rectangle on either side of a spot
+            let across = |(side, (y, x)): (usize, (usize, usize))| match side {
+                0 => ((y - 1, x), (y + 1, x)),
+                1 => ((y + 1, x), (y - 1, x)),
+                2 => ((y, x - 1), (y, x + 1)),
+                _ => ((y, x + 1), (y, x - 1)),
+            };
+            crossings.shuffle(rng);
+            let previously_open: Vec<bool> = crossings
+                .iter()
+                .map(|&(_, (y, x))| !self.cells[y][x].wall)
+                .collect();
+
+            // algorithms with open areas can leave part of the Maze cut off, so they get a few
+            // tries
+            let original = self.cells.clone();
+            for _ in 0..10 {
+                let region = Self::try_new_from_rng(size, algorithm, rng)
+                    .map_err(|_| MazeError::InvalidRegion)?;
+                if region.dimensions != size {
+                    return Err(MazeError::InvalidRegion);
+                }
+                self.cells = original.clone();
+
+                // wall the rectangle off, and carve it anew
+                for &(_, (y, x)) in &crossings {
+                    self.cells[y][x].wall = true;
+                }
+                for y in top_left.0 + 1..bottom_right.0 {
+                    for x in top_left.1 + 1..bottom_right.1 {
+                        self.cells[y][x].wall = region.cells[y - top_left.0][x - top_left.1].wall;
+                        self.cells[y][x].tag = None;
+                        self.cells[y][x].cost = 1;
+                    }
+                }
+                // the entrypoint and goalpoint might be on the rectangle's edge
+                for (y, x) in [self.entrypoint, self.goalpoint] {
+                    let inside = (
+                        y.clamp(1, self.dimensions.0 - 2),
+                        x.clamp(1, self.dimensions.1 - 2),
+                    );
+                    if (top_left.0..=bottom_right.0).contains(&y)
+                        && (top_left.1..=bottom_right.1).contains(&x)
+                    {
+                        self.cells[inside.0][inside.1].wall = false;
+                    }
+                }
+
+                // figure out which pieces of the Maze are connected now
+                let index = |pos: (usize, usize)| pos.0 * self.dimensions.1 + pos.1;
+                let mut sets = DisjointSet::new(self.dimensions.0 * self.dimensions.1);
+                for y in 0..self.dimensions.0 {
+                    for x in 0..self.dimensions.1 {
+                        for (ny, nx) in [(y + 1, x), (y, x + 1)] {
+                            if ny < self.dimensions.0
+                                && nx < self.dimensions.1
+                                && !self.cells[y][x].wall
+                                && !self.cells[ny][nx].wall
+                            {
+                                sets.union(index((y, x)), index((ny, nx)));
+                            }
+                        }
+                    }
+                }
+
+                // reopen as many passages as it takes to join everything back together, trying
+                // the old ones first
+                let usable: Vec<usize> = (0..crossings.len())
+                    .filter(|&i| {
+                        let (outside, inside) = across(crossings[i]);
+                        !self.cells[outside.0][outside.1].wall
+                            && !self.cells[inside.0][inside.1].wall
+                    })
+                    .collect();
+                let mut order: Vec<usize> = usable
+                    .iter()
+                    .copied()
+                    .filter(|&i| previously_open[i])
+                    .collect();
+                order.extend(usable.iter().copied().filter(|&i| !previously_open[i]));
+                let mut sides_open = [false; 4];
+                for &i in &order {
+                    let (side, (y, x)) = crossings[i];
+                    let (outside, inside) = across(crossings[i]);
+                    if sets.union(index(outside), index(inside)) {
+                        self.cells[y][x].wall = false;
+                        sides_open[side] = true;
+                    }
+                }
+                // then make sure each side that had a passage still has one, even if that makes a
+                // loop
+                for &i in &order {
+                    let (side, (y, x)) = crossings[i];
+                    if previously_open[i] && !sides_open[side] {
+                        self.cells[y][x].wall = false;
+                        sides_open[side] = true;
+                    }
+                }
+
+                // make sure everything can still be reached
+                let reachable = Self::flood(&self.cells, self.entrypoint);
+                let connected = self.cells.iter().zip(&reachable).all(|(row, reached)| {
+                    row.iter()
+                        .zip(reached)
+                        .all(|(cell, &reached)| cell.wall || reached)
+                });
+                if connected {
+                    return Ok(());
+                }
+            }
+            self.cells = original;
+            Err(MazeError::RegionDisconnected)
+        }
+
+        /**
+         * Returns up to limit different paths from the entrypoint to the goalpoint that never
+         * visit the same cell twice, shortest first. They're found by a depth-first search that
+         * backs out of each path once it's been recorded, in the same order as recursive
+         * backtracking, so a perfect Maze gives just the one path it would find. The search
+         * stops once limit paths have been found, so they aren't necessarily the shortest ones.
+         * Mazes with open areas have an enormous number of paths, so keep limit small there.
+         */
+        pub fn all_solutions(&self, limit: usize) -> Vec<Path> {
+            let directions: [(isize, isize); 4] = [(1, 0), (0, 1), (-1, 0), (0, -1)];
+            let mut solutions: Vec<Path> = vec![];
+            let mut on_path = vec![vec![false; self.dimensions.1]; self.dimensions.0];
+            // the cells on the current path, and which way to try next from each
+            let mut stack: Vec<((usize, usize), usize)> = vec![];
+            if limit > 0 && !self.cells[self.entrypoint.0][self.entrypoint.1].wall {
+                on_path[self.entrypoint.0][self.entrypoint.1] = true;
+                stack.push((self.entrypoint, 0));
+            }
+
+            while let Some(&(pos, tried)) = stack.last() {
+                if pos == self.goalpoint || tried == directions.len() {
+                    if pos == self.goalpoint {
+                        solutions.push(Path {
+                            cells: stack.iter().map(|&(pos, _)| pos).collect(),
+                        });
+                        if solutions.len() == limit {
+                            break;
+                        }
+                    }
+                    // back out, so the cell can be part of other paths
+                    on_path[pos.0][pos.1] = false;
+                    stack.pop();
+                    continue;
+                }
+
+                if let Some(top) = stack.last_mut() {
+                    top.1 += 1;
+                }
+                let (dy, dx) = directions[tried];
+                let (y, x) = (pos.0 as isize + dy, pos.1 as isize + dx);
+                if y < 0
+                    || x < 0
+                    || y as usize >= self.dimensions.0
+                    || x as usize >= self.dimensions.1
+                {
+                    continue;
+                }
+                let (y, x) = (y as usize, x as usize);
+                if !self.cells[y][x].wall && !on_path[y][x] {
+                    on_path[y][x] = true;
+                    stack.push(((y, x), 0));
+                }
+            }
+
+            solutions.sort_by_key(|path| path.len());
+            solutions
+        }
+
+        /**
+         * Returns up to k different paths from the entrypoint to the goalpoint that never visit
+         * the same cell twice, shortest first, via Yen's algorithm: each path after the first
+         * follows one already found part of the way, then branches off it by the shortest route
+         * that avoids the ones found so far. A perfect Maze has just the one path. The searches
+         * give up after exploring a million cells between them, returning whatever paths were
+         * found by then.
+         */
+        pub fn k_shortest_paths(&self, k: usize) -> Vec<Path> {
+            let mut budget: usize = 1_000_000;
+            let mut blocked = vec![vec![false; self.dimensions.1]; self.dimensions.0];
+            let mut found: Vec<Vec<(usize, usize)>> = vec![];
+            if k == 0 {
+                return vec![];
+            }
+            match self.shortest_path_avoiding(self.entrypoint, &blocked, &[], &mut budget) {
+                Some(first) => found.push(first),
+                None => return vec![],
+            }
+
+            let mut candidates: Vec<Vec<(usize, usize)>> = vec![];
+            while found.len() < k && budget > 0 {
+                let last = found[found.len() - 1].clone();
+                for (i, &spur) in last.iter().enumerate().take(last.len() - 1) {
+                    // branching off at spur means not going the way any path that shares this
+                    // much of the last one went, nor back over the shared part itself
+                    let root = &last[..=i];
+                    let cut: Vec<(usize, usize)> = found
+                        .iter()
+                        .filter(|path| path.len() > i + 1 && path[..=i] == *root)
+                        .map(|path| path[i + 1])
+                        .collect();
+                    for &(y, x) in &root[..i] {
+                        blocked[y][x] = true;
+                    }
+                    let branch = self.shortest_path_avoiding(spur, &blocked, &cut, &mut budget);
+                    for &(y, x) in &root[..i] {
+                        blocked[y][x] = false;
+                    }
+
+                    if let Some(branch) = branch {
+                        let mut path = root[..i].to_vec();
+                        path.extend(branch);
+                        if !candidates.contains(&path) && !found.contains(&path) {
+                            candidates.push(path);
+                        }
+                    }
+                    if budget == 0 {
+                        break;
+                    }
+                }
+
+                // the shortest candidate is the next shortest path (ties go to the first found)
+                let Some(shortest) = (0..candidates.len()).min_by_key(|&i| candidates[i].len())
+                else {
+                    break;
+                };
+                found.push(candidates.remove(shortest));
+            }
+
+            found.into_iter().map(|cells| Path { cells }).collect()
+        }
+
+        /**
+         * Marks the given path in this Maze in place of any solution, such as one picked out of
+         * k_shortest_paths or all_solutions for display. Returns an error, leaving the Maze
+         * unsolved, if the path goes through a wall or outside the Maze.
+         */
+        pub fn mark_path(&mut self, path: &Path) -> Result<(), MazeError> {
+            self.unsolve();
+            for &pos in path.cells() {
+                self.check_coordinate(pos)?;
+                if !self.is_passage(pos) {
+                    return Err(MazeError::NotOpen(pos));
+                }
+            }
+            for &(y, x) in path.cells() {
+                self.mark((y, x));
+            }
+            Ok(())
+        }
+
+        /**
+         * Walks the given string of compass moves (N, E, S, and W, in either case, as made by
+         * Path::to_moves_string) from the start cell, and marks the path it takes in place of any
+         * solution. Returns the path, or an error if the start is outside the Maze
+         * (InvalidCoordinate) or a wall (NotOpen), or if the move at some index of the string
+         * isn't a compass letter (UnknownMove), would leave the Maze (MoveOffGrid), or would
+         * walk into a wall (MoveIntoWall), which leaves the Maze unsolved.
+         */
+        pub fn apply_moves(
+            &mut self,
+            start: (usize, usize),
+            moves: &str,
+        ) -> Result<Path, MazeError> {
+            self.unsolve();
+            self.check_coordinate(start)?;
+            if !self.is_passage(start) {
+                return Err(MazeError::NotOpen(start));
+            }
+
+            let mut cells: Vec<(usize, usize)> = vec![start];
+            let mut pos = start;
+            for (index, letter) in moves.chars().enumerate() {
+                let (dy, dx): (isize, isize) = match letter.to_ascii_uppercase() {
+                    'N' => (-1, 0),
+                    'E' => (0, 1),
+                    'S' => (1, 0),
+                    'W' => (0, -1),
+                    _ => return Err(MazeError::UnknownMove(index, letter)),
+                };
+                let (y, x) = (pos.0 as isize + dy, pos.1 as isize + dx);
+                if y < 0 || x < 0 || self.check_coordinate((y as usize, x as usize)).is_err() {
+                    return Err(MazeError::MoveOffGrid(index));
+                }
+                pos = (y as usize, x as usize);
+                if !self.is_passage(pos) {
+                    return Err(MazeError::MoveIntoWall(index));
+                }
+                cells.push(pos);
+            }
+
+            let path = Path { cells };
+            for &(y, x) in path.cells() {
+                self.mark((y, x));
+            }
+            Ok(path)
+        }
+
+        /**
+         * Returns a copy of this Maze turned a quarter turn clockwise, so its left edge becomes
+         * its top. Its dimensions are swapped, and its walls, openings, and any solution marked
+         * on it are all carried along; only the record of how it was solved is left behind.
+         *
+         * ```
+         * use rust_maze::{CreationAlgorithm, Maze};
+         *
+         * let mut maze = Maze::new_from((9, 15), CreationAlgorithm::Kruskal);
+         * maze.solve();
+         * let turned = maze.rotate_cw();
+         * assert_eq!(turned.dimensions(), (15, 9));
+         * assert!(turned.verify_solution().is_ok());
+         * assert_eq!(turned.rotate_cw().rotate_cw().rotate_cw(), maze);
+         * assert_eq!(turned.rotate_ccw(), maze);
+         * assert_eq!(maze.transpose().mirror_horizontal(), turned);
+         * ```
+         */
+        pub fn rotate_cw(&self) -> Maze {
+            let height = self.dimensions.0;
+            self.transformed((self.dimensions.1, height), |(y, x)| (x, height - 1 - y))
+        }
+
+        /**
+         * Returns a copy of this Maze turned a quarter turn counterclockwise, so its top edge
+         * becomes its left, carried along just like rotate_cw.
+         */
+        pub fn rotate_ccw(&self) -> Maze {
+            let width = self.dimensions.1;
+            self.transformed((width, self.dimensions.0), |(y, x)| (width - 1 - x, y))
+        }
+
+        /**
+         * Returns a copy of this Maze flipped left to right, as if seen in a mirror standing
+         * beside it, carried along just like rotate_cw.
+         */
+        pub fn mirror_horizontal(&self) -> Maze {
+            let width = self.dimensions.1;
+            self.transformed(self.dimensions, |(y, x)| (y, width - 1 - x))
+        }
+
+        /**
+         * Returns a copy of this Maze flipped top to bottom, as if seen in a mirror lying below
+         * it, carried along just like rotate_cw.
+         */
+        pub fn mirror_vertical(&self) -> Maze {
+            let height = self.dimensions.0;
+            self.transformed(self.dimensions, |(y, x)| (height - 1 - y, x))
+        }
+
+        /**
+         * Returns a copy of this Maze flipped across the diagonal from its top-left corner, so
+         * its rows become columns, carried along just like rotate_cw.
+         */
+        pub fn transpose(&self) -> Maze {
+            self.transformed((self.dimensions.1, self.dimensions.0), |(y, x)| (x, y))
+        }
+
+        /**
+         * Returns the rectangle of this Maze between the given (y, x) corners, inclusive, as a
+         * Maze of its own, walled in all the way around. The entrance and exit stay where they
+         * were if they're on the rectangle's edge; otherwise they're moved to where passages
+         * crossed the cut, the entrance to the first such spot (reading top to bottom, left to
+         * right) and the exit to the last, or straight in from the usual corners if there aren't
+         * any. Walling the rectangle in can leave some of its passages cut off from the rest, when
+         * the only way between them was outside it, so the fewest walls it takes to reach each
+         * cut-off piece are knocked down, and every passage can be reached, the exit included.
+         * The solution isn't kept, since it probably went outside the rectangle, but the tags
+         * inside it are.
+         * Returns InvalidCoordinate if a corner is outside this Maze, or InvalidRegion if the
+         * corners are the wrong way around or the rectangle is smaller than 3x3.
+         */
+        pub fn crop(
+            &self,
+            top_left: (usize, usize),
+            bottom_right: (usize, usize),
+        ) -> Result<Maze, MazeError> {
+            self.check_coordinate(top_left)?;
+            self.check_coordinate(bottom_right)?;
+            if bottom_right.0 < top_left.0 + 2 || bottom_right.1 < top_left.1 + 2 {
+                return Err(MazeError::InvalidRegion);
+            }
+            let (height, width) = (
+                bottom_right.0 - top_left.0 + 1,
+                bottom_right.1 - top_left.1 + 1,
+            );
+            let on_edge =
+                |(y, x): (usize, usize)| y == 0 || x == 0 || y == height - 1 || x == width - 1;
+            let corner =
+                |(y, x): (usize, usize)| (y == 0 || y == height - 1) && (x == 0 || x == width - 1);
+            let open = |(y, x): (usize, usize)| !self.cells[y + top_left.0][x + top_left.1].wall;
+            // where this Maze's openings land in the rectangle, if they're on its edge
+            let kept = |(y, x): (usize, usize)| {
+                (y >= top_left.0 && x >= top_left.1)
+                    .then(|| (y - top_left.0, x - top_left.1))
+                    .filter(|&pos| pos.0 < height && pos.1 < width && on_edge(pos) && !corner(pos))
+            };
+
+            // the spots on the edge where a passage ran out through the cut
+            let mut crossings: Vec<(usize, usize)> = vec![];
+            for y in 0..height {
+                for x in 0..width {
+                    if !on_edge((y, x)) || corner((y, x)) || !open((y, x)) {
+                        continue;
+                    }
+                    let inward = match (y, x) {
+                        (0, _) => (1, x),
+                        (_, 0) => (y, 1),
+                        (y, _) if y == height - 1 => (y - 1, x),
+                        _ => (y, x - 1),
+                    };
+                    if open(inward) {
+                        crossings.push((y, x));
+                    }
+                }
+            }
+            let entrypoint = kept(self.entrypoint)
+                .or(crossings.first().copied())
+                .unwrap_or((1, 0));
+            let goalpoint = kept(self.goalpoint)
+                .filter(|&pos| pos != entrypoint)
+                .or(crossings
+                    .iter()
+                    .rev()
+                    .copied()
+                    .find(|&pos| pos != entrypoint))
+                .unwrap_or(if entrypoint == (height - 2, width - 1) {
+                    (1, 0)
+                } else {
+                    (height - 2, width - 1)
+                });
+
+            let mut maze = Maze {
+                dimensions: (height, width),
+                entrypoint,
+                goalpoint,
+                exits: vec![goalpoint],
+                algorithm: None,
+                cells: self.cells[top_left.0..=bottom_right.0]
+                    .iter()
+                    .enumerate()
+                    .map(|(y, row)| {
+                        row[top_left.1..=bottom_right.1]
+                            .iter()
+                            .enumerate()
+                            .map(|(x, cell)| Cell {
+                                wall: cell.wall || on_edge((y, x)),
+                                visited: false,
+                                tag: cell.tag,
+                                cost: cell.cost,
+                            })
+                            .collect()
+                    })
+                    .collect(),
+                stats: SolveStats::default(),
+                log: None,
+                overlay: None,
+            };
+            maze.open_into_passages(entrypoint);
+            maze.open_into_passages(goalpoint);
+            maze.connect_passages();
+            Ok(maze)
+        }
+
+        /**
+         * Knocks down walls inside the outer wall until every passage can be reached from the
+         * entrypoint, each time breaking through the fewest walls it takes to reach another
+         * passage that's cut off.
+         */
+        fn connect_passages(&mut self) {
+            let (height, width) = self.dimensions;
+            loop {
+                let reached = Self::flood(&self.cells, self.entrypoint);
+                // search outward through the walls from everything that can be reached
+                let mut parents: Vec<Vec<Option<(usize, usize)>>> = vec![vec![None; width]; height];
+                let mut queue: VecDeque<(usize, usize)> = VecDeque::new();
+                for (y, row) in reached.iter().enumerate() {
+                    for (x, &reached) in row.iter().enumerate() {
+                        if reached {
+                            parents[y][x] = Some((y, x));
+                            queue.push_back((y, x));
+                        }
+                    }
+                }
+                let mut found: Option<(usize, usize)> = None;
+                'search: while let Some(current) = queue.pop_front() {
+                    for (dy, dx) in [(1, 0), (-1, 0), (0, 1), (0, -1)] {
+                        let (y, x) = (current.0 as isize + dy, current.1 as isize + dx);
+                        if y < 0 || x < 0 || y as usize >= height || x as usize >= width {
+                            continue;
+                        }
+                        let (y, x) = (y as usize, x as usize);
+                        if parents[y][x].is_some() {
+                            continue;
+                        }
+                        if !self.cells[y][x].wall {
+                            found = Some(current);
+                            break 'search;
+                        }
+                        // the outer wall stays up
+                        if y > 0 && x > 0 && y < height - 1 && x < width - 1 {
+                            parents[y][x] = Some(current);
+                            queue.push_back((y, x));
+                        }
+                    }
+                }
+                let Some(mut pos) = found else {
+                    break;
+                };
+                while self.cells[pos.0][pos.1].wall {
+                    self.cells[pos.0][pos.1].wall = false;
+                    pos = parents[pos.0][pos.1].expect("every wall searched has a parent");
+                }
+            }
+        }
+
+        /**
+         * Returns a Maze made of this one with the other stitched onto the given side of it, so
+         * the two share the wall along the seam. The seam is opened up in one spot, as close to
+         * its middle as possible, where both Mazes have a passage right beside it; if there's no
+         * such spot, the fewest walls it takes to get from one to the other are knocked down
+         * instead. The entrance is this Maze's and the exit is the other's, except that one that
+         * was on the seam is moved straight across its own Maze to the opposite edge. Any other
+         * openings are walled up, and neither solution is kept. Every passage in the result can
+         * be reached from the entrance.
+         * Returns EdgeMismatch if the two Mazes' edges along the seam aren't the same length, or
+         * DimensionsTooLarge if the result would be too big.
+         *
+         * ```
+         * use rust_maze::{CreationAlgorithm, Edge, Maze};
+         *
+         * let left = Maze::new_from((15, 21), CreationAlgorithm::Kruskal);
+         * let right = Maze::new_from((15, 31), CreationAlgorithm::RecursiveBacktracker);
+         * let mut joined = left.join(&right, Edge::Right).unwrap();
+         * assert_eq!(joined.dimensions(), (15, 51));
+         * assert!(joined.validate_connected());
+         * assert!(joined.solve());
+         * ```
+         */
+        pub fn join(&self, other: &Maze, side: Edge) -> Result<Maze, MazeError> {
+            let ((height, width), (other_height, other_width)) =
+                (self.dimensions, other.dimensions);
+            let (dimensions, mine, theirs) = match side {
+                Edge::Left | Edge::Right if height != other_height => {
+                    return Err(MazeError::EdgeMismatch)
+                }
+                Edge::Top | Edge::Bottom if width != other_width => {
+                    return Err(MazeError::EdgeMismatch)
+                }
+                Edge::Right => ((height, width + other_width - 1), (0, 0), (0, width - 1)),
+                Edge::Left => (
+                    (height, width + other_width - 1),
+                    (0, other_width - 1),
+                    (0, 0),
+                ),
+                Edge::Bottom => ((height + other_height - 1, width), (0, 0), (height - 1, 0)),
+                Edge::Top => (
+                    (height + other_height - 1, width),
+                    (other_height - 1, 0),
+                    (0, 0),
+                ),
+            };
+            Self::check_dimensions(dimensions)?;
+
+            // an opening on the seam is moved straight across to the opposite edge of its Maze
+            let across =
+                |(y, x): (usize, usize), (height, width): (usize, usize), edge: Edge| match edge {
+                    Edge::Left if x == 0 => (y, width - 1),
+                    Edge::Right if x == width - 1 => (y, 0),
+                    Edge::Top if y == 0 => (height - 1, x),
+                    Edge::Bottom if y == height - 1 => (0, x),
+                    _ => (y, x),
+                };
+            let opposite = match side {
+                Edge::Left => Edge::Right,
+                Edge::Right => Edge::Left,
+                Edge::Top => Edge::Bottom,
+                Edge::Bottom => Edge::Top,
+            };
+            let entrypoint = across(self.entrypoint, self.dimensions, side);
+            let entrypoint = (entrypoint.0 + mine.0, entrypoint.1 + mine.1);
+            let goalpoint = across(other.goalpoint, other.dimensions, opposite);
+            let goalpoint = (goalpoint.0 + theirs.0, goalpoint.1 + theirs.1);
+
+            let mut cells = vec![
+                vec![
+                    Cell {
+                        wall: true,
+                        visited: false,
+                        tag: None,
+                        cost: 1
+                    };
+                    dimensions.1
+                ];
+                dimensions.0
+            ];
+            for (maze, offset) in [(self, mine), (other, theirs)] {
+                for (y, row) in maze.cells.iter().enumerate() {
+                    for (x, cell) in row.iter().enumerate() {
+                        let joined = &mut cells[y + offset.0][x + offset.1];
+                        joined.wall &= cell.wall;
+                        // where the seam overlaps, this Maze's tags and costs win
+                        joined.tag = joined.tag.or(cell.tag);
+                        if joined.cost == 1 {
+                            joined.cost = cell.cost;
+                        }
+                    }
+                }
+            }
+            // wall up the seam and every old opening
+            let (seam, length) = match side {
+                Edge::Left | Edge::Right => ((0, mine.1.max(theirs.1)), height),
+                Edge::Top | Edge::Bottom => ((mine.0.max(theirs.0), 0), width),
+            };
+            let along = |offset: usize| match side {
+                Edge::Left | Edge::Right => (offset, seam.1),
+                Edge::Top | Edge::Bottom => (seam.0, offset),
+            };
+            for offset in 0..length {
+                let (y, x) = along(offset);
+                cells[y][x].wall = true;
+            }
+            for (y, row) in cells.iter_mut().enumerate() {
+                for (x, cell) in row.iter_mut().enumerate() {
+                    if y == 0 || x == 0 || y == dimensions.0 - 1 || x == dimensions.1 - 1 {
+                        cell.wall = true;
+                    }
+                }
+            }
+
+            let mut maze = Maze {
+                dimensions,
+                entrypoint,
+                goalpoint,
+                exits: vec![goalpoint],
+                algorithm: None,
+                cells,
+                stats: SolveStats::default(),
+                log: None,
+                overlay: None,
+            };
+            maze.open_into_passages(entrypoint);
+            maze.open_into_passages(goalpoint);
+            // open the seam where there's a passage on both sides of it, nearest the middle
+            let beside = |offset: usize| match side {
+                Edge::Left | Edge::Right => [(offset, seam.1 - 1), (offset, seam.1 + 1)],
+                Edge::Top | Edge::Bottom => [(seam.0 - 1, offset), (seam.0 + 1, offset)],
+            };
+            if let Some(offset) = (1..length - 1)
+                .filter(|&offset| beside(offset).iter().all(|&(y, x)| !maze.cells[y][x].wall))
+                .min_by_key(|&offset| offset.abs_diff(length / 2))
+            {
+                let (y, x) = along(offset);
+                maze.cells[y][x].wall = false;
+            }
+            maze.connect_passages();
+            Ok(maze)
+        }
+
+        /**
+         * Returns a copy of this Maze, with the given dimensions, where every cell has been moved
+         * to wherever the given function sends it.
+         */
+        fn transformed(
+            &self,
+            dimensions: (usize, usize),
+            map: impl Fn((usize, usize)) -> (usize, usize),
+        ) -> Maze {
+            let mut cells = vec![
+                vec![
+                    Cell {
+                        wall: true,
+                        visited: false,
+                        tag: None,
+                        cost: 1
+                    };
+                    dimensions.1
+                ];
+                dimensions.0
+            ];
+            for (y, row) in self.cells.iter().enumerate() {
+                for (x, cell) in row.iter().enumerate() {
+                    let (new_y, new_x) = map((y, x));
+                    cells[new_y][new_x] = cell.clone();
+                }
+            }
+            let overlay = self.overlay.as_ref().map(|overlay| {
+                let mut moved = SolutionOverlay::new(dimensions);
+                for pos in overlay.cells() {
+                    moved.set(map(pos), true);
+                }
+                moved
+            });
+            Maze {
+                dimensions,
+                entrypoint: map(self.entrypoint),
+                goalpoint: map(self.goalpoint),
+                exits: self.exits.iter().map(|&exit| map(exit)).collect(),
+                algorithm: self.algorithm,
+                cells,
+                stats: SolveStats::default(),
+                log: None,
+                overlay,
+            }
+        }
+
+        /**
+         * Copies what's at every cell of this Maze (as at reports it) into a grid of plain rows,
+         * top to bottom, each running left to right, which from_grid turns back into a Maze.
+         */
+        pub fn to_grid(&self) -> Vec<Vec<CellKind>> {
+            (0..self.dimensions.0)
+                .map(|row| {
+                    (0..self.dimensions.1)
+                        .map(|col| self.at(row, col).expect("the cell is inside the Maze"))
+                        .collect()
+                })
+                .collect()
+        }
+
+        /**
+         * Builds a Maze from a grid of cells like to_grid returns, row by row from the top. The
+         * grid needs one Entrance and at least one Exit; Solution cells are marked as the
+         * solution, and the exit next to it (or if nothing's marked, the first exit, reading top
+         * to bottom and left to right) becomes the goalpoint. Returns a ParseError if the rows
+         * aren't all the same length or the openings are missing or doubled, DimensionsTooSmall
+         * if the grid is smaller than 3x3, or Invalid if the Maze is broken some other way (as
+         * validate finds).
+         *
+         * ```
+         * use rust_maze::{CellKind, Maze};
+         *
+         * let (w, p) = (CellKind::Wall, CellKind::Passage);
+         * let grid = vec![
+         *     vec![w, w, w, w, w],
+         *     vec![CellKind::Entrance, p, p, p, w],
+         *     vec![w, w, w, p, w],
+         *     vec![w, p, p, p, CellKind::Exit],
+         *     vec![w, w, w, w, w],
+         * ];
+         * let mut maze = Maze::from_grid(grid.clone()).unwrap();
+         * assert_eq!(maze.openings(), ((1, 0), (3, 4)));
+         * assert_eq!(maze.to_grid(), grid);
+         * maze.solve();
+         * assert_eq!(maze.solution().unwrap().len(), 7);
+         *
+         * // every row has to be as wide as the first
+         * let mut ragged = grid.clone();
+         * ragged[2].pop();
+         * assert!(Maze::from_grid(ragged).is_err());
+         * ```
+         */
+        pub fn from_grid(grid: Vec<Vec<CellKind>>) -> Result<Self, MazeError> {
+            let width = grid.first().map_or(0, |row| row.len());
+            if let Some(y) = grid.iter().position(|row| row.len() != width) {
+                return Err(MazeError::ParseError(format!(
+                    "row {} is {} cells wide, not {}",
+                    y,
+                    grid[y].len(),
+                    width
+                )));
+            }
+            let dimensions = (grid.len(), width);
+            if dimensions.0 < 3 || dimensions.1 < 3 {
+                return Err(MazeError::DimensionsTooSmall);
+            }
+
+            let mut entrypoint: Option<(usize, usize)> = None;
+            let mut exits: Vec<(usize, usize)> = vec![];
+            let mut cells: Vec<Vec<Cell>> = Vec::with_capacity(dimensions.0);
+            let mut marks: Vec<Vec<bool>> = Vec::with_capacity(dimensions.0);
+            for (y, row) in grid.iter().enumerate() {
+                let mut cells_row: Vec<Cell> = Vec::with_capacity(width);
+                let mut marks_row: Vec<bool> = Vec::with_capacity(width);
+                for (x, &kind) in row.iter().enumerate() {
+                    match kind {
+                        CellKind::Entrance if entrypoint.is_some() => {
+                            return Err(MazeError::ParseError(format!(
+                                "there's a second entrance, at ({}, {})",
+                                y, x
+                            )))
+                        }
+                        CellKind::Entrance => entrypoint = Some((y, x)),
+                        CellKind::Exit => exits.push((y, x)),
+                        _ => {}
+                    }
+                    cells_row.push(Cell {
+                        wall: kind == CellKind::Wall,
+                        visited: false,
+                        tag: None,
+                        cost: 1,
+                    });
+                    marks_row.push(kind == CellKind::Solution);
+                }
+                cells.push(cells_row);
+                marks.push(marks_row);
+            }
+            let Some(entrypoint) = entrypoint else {
+                return Err(MazeError::ParseError(String::from("there's no entrance")));
+            };
+            if exits.is_empty() {
+                return Err(MazeError::ParseError(String::from("there's no exit")));
+            }
+
+            // the solution runs through both openings, though they're reported as themselves
+            let mut maze = Maze {
+                dimensions,
+                entrypoint,
+                goalpoint: exits[0],
+                exits,
+                algorithm: None,
+                cells,
+                stats: SolveStats::default(),
+                log: None,
+                overlay: SolutionOverlay::from_marks(marks),
+            };
+            if maze.overlay.is_some() {
+                let marked =
+                    |exit: (usize, usize)| maze.neighbors(exit).any(|pos| maze.is_marked(pos));
+                if let Some(&goalpoint) = maze.exits.iter().find(|&&exit| marked(exit)) {
+                    maze.goalpoint = goalpoint;
+                }
+                for pos in [maze.entrypoint, maze.goalpoint] {
+                    maze.mark(pos);
+                }
+            }
+            maze.validate().map_err(MazeError::Invalid)?;
+            Ok(maze)
+        }
+
+        /**
+         * Builds a Maze from a matrix of walls, row by row from the top, where true is a wall--
+         * like the 0/1 grids other maze tools write out. The walls are taken exactly as they
+         * are, without carving anything, so they don't have to make a perfect Maze, or even
+         * connect every passage. The entrance and exit have to be passages, and like with
+         * set_openings they can be anywhere but a corner of the outer wall, the middle of the
+         * Maze included. Returns InvalidWallGrid if the walls are smaller than 3x3 or their rows
+         * aren't all the same length, InvalidCoordinate if an opening is outside them,
+         * CornerOpening if it's a corner, NotOpen if it's a wall, SameOpenings if the entrance
+         * and exit are the same cell, or Unsolvable if there's no way from one to the other.
+         *
+         * ```
+         * use rust_maze::{Maze, MazeError, SolvingAlgorithm};
+         *
+         * let drawing = ["#####", "    #", "### #", "#    ", "#####"];
+         * let walls: Vec<Vec<bool>> = drawing
+         *     .iter()
+         *     .map(|row| row.chars().map(|c| c == '#').collect())
+         *     .collect();
+         * let mut maze = Maze::from_walls(walls.clone(), (1, 0), (3, 4)).unwrap();
+         * let report = maze.solve_from(SolvingAlgorithm::BreadthFirst).unwrap();
+         * assert_eq!(report.path_length, 7);
+         *
+         * // the exit has to be a way out
+         * assert!(matches!(
+         *     Maze::from_walls(walls.clone(), (1, 0), (3, 0)),
+         *     Err(MazeError::NotOpen((3, 0)))
+         * ));
+         * // and there has to be a way to it
+         * let mut blocked = walls;
+         * blocked[2][3] = true;
+         * assert!(matches!(
+         *     Maze::from_walls(blocked, (1, 0), (3, 4)),
+         *     Err(MazeError::Unsolvable)
+         * ));
+         * ```
+         */
+        pub fn from_walls(
+            walls: Vec<Vec<bool>>,
+            entrance: (usize, usize),
+            exit: (usize, usize),
+        ) -> Result<Self, MazeError> {
+            if walls.len() < 3
+                || walls[0].len() < 3
+                || walls.iter().any(|row| row.len() != walls[0].len())
+            {
+                return Err(MazeError::InvalidWallGrid);
+            }
+
+            let maze = Maze {
+                dimensions: (walls.len(), walls[0].len()),
+                entrypoint: entrance,
+                goalpoint: exit,
+                exits: vec![exit],
+                algorithm: None,
+                cells: walls
+                    .into_iter()
+                    .map(|row| {
+                        row.into_iter()
+                            .map(|wall| Cell {
+                                wall,
+                                visited: false,
+                                tag: None,
+                                cost: 1,
+                            })
+                            .collect()
+                    })
+                    .collect(),
+                stats: SolveStats::default(),
+                log: None,
+                overlay: None,
+            };
+            let (height, width) = maze.dimensions;
+            for opening in [entrance, exit] {
+                maze.check_coordinate(opening)?;
+                if (opening.0 == 0 || opening.0 == height - 1)
+                    && (opening.1 == 0 || opening.1 == width - 1)
+                {
+                    return Err(MazeError::CornerOpening(opening));
+                }
+                if maze.cells[opening.0][opening.1].wall {
+                    return Err(MazeError::NotOpen(opening));
+                }
+            }
+            if entrance == exit {
+                return Err(MazeError::SameOpenings);
+            }
+            if !Self::flood(&maze.cells, entrance)[exit.0][exit.1] {
+                return Err(MazeError::Unsolvable);
+            }
+            Ok(maze)
+        }
+
+        /**
+         * Packs this Maze into a compact binary form, which from_bytes reads back. It starts with a
+         * header--the magic bytes "MAZE", a version byte (1), the number of rows and columns, and a
+         * flags byte (1 if the solution is included, plus 2 if tags are, plus 4 if costs
+         * are)--followed by the entrypoint, the number of exits, which one is the goalpoint, and
+         * the exits themselves, with every number a little-endian u32 and every cell a row then a
+         * column. Then come the walls, one bit per cell (set for a wall) row by row, the first in
+         * the highest bit of each byte. If the Maze is solved, the solution path goes last: its
+         * number of moves, then two bits per move (0 North, 1 East, 2 South, 3 West), packed the
+         * same way. Only the path is kept, so a solver's other marks (like a wall follower's dead
+         * ends) are left out. If any cells are tagged, the number of them comes after that, then
+         * each one's row, column, and tag, and the cells that don't cost 1 (see set_cost) follow
+         * the same way, with their costs. A 101x101 Maze takes about 1.3 KB.
+         */
+        pub fn to_bytes(&self) -> Vec<u8> {
+            let solution = self.solution();
+            let tags: Vec<((usize, usize), u32)> = self.tagged().collect();
+            let costs: Vec<((usize, usize), u32)> = self.weighted_cells().collect();
+            let mut bytes: Vec<u8> = b"MAZE".to_vec();
+            bytes.push(BYTES_VERSION);
+            let push = |bytes: &mut Vec<u8>, number: usize| {
+                bytes.extend_from_slice(&(number as u32).to_le_bytes())
+            };
+            push(&mut bytes, self.dimensions.0);
+            push(&mut bytes, self.dimensions.1);
+            bytes.push(
+                solution.is_some() as u8
+                    | (!tags.is_empty() as u8) << 1
+                    | (!costs.is_empty() as u8) << 2,
+            );
+            push(&mut bytes, self.entrypoint.0);
+            push(&mut bytes, self.entrypoint.1);
+            push(&mut bytes, self.exits.len());
+            let goal = self.exits.iter().position(|&exit| exit == self.goalpoint);
+            push(&mut bytes, goal.unwrap_or(0));
+            for &(y, x) in &self.exits {
+                push(&mut bytes, y);
+                push(&mut bytes, x);
+            }
+
+            let mut packed = vec![0u8; (self.dimensions.0 * self.dimensions.1).div_ceil(8)];
+            for (index, cell) in self.cells.iter().flatten().enumerate() {
+                if cell.wall {
+                    packed[index / 8] |= 0x80 >> (index % 8);
+                }
+            }
+            bytes.extend(packed);
+
+            if let Some(cells) = solution {
+                let moves = Path { cells }.moves();
+                push(&mut bytes, moves.len());
+                let mut packed = vec![0u8; moves.len().div_ceil(4)];
+                for (index, direction) in moves.iter().enumerate() {
+                    let bits = match direction {
+                        Direction::North => 0,
+                        Direction::East => 1,
+                        Direction::South => 2,
+                        Direction::West => 3,
+                    };
+                    packed[index / 4] |= bits << (6 - 2 * (index % 4));
+                }
+                bytes.extend(packed);
+            }
+            if !tags.is_empty() {
+                push(&mut bytes, tags.len());
+                for ((y, x), tag) in tags {
+                    push(&mut bytes, y);
+                    push(&mut bytes, x);
+                    push(&mut bytes, tag as usize);
+                }
+            }
+            if !costs.is_empty() {
+                push(&mut bytes, costs.len());
+                for ((y, x), cost) in costs {
+                    push(&mut bytes, y);
+                    push(&mut bytes, x);
+                    push(&mut bytes, cost as usize);
+                }
+            }
+            bytes
+        }
+
+        /**
+         * Reads a Maze back from the binary form to_bytes packs it into. Everything is checked
+         * against the header before it's used, so truncated or made-up bytes can't make this
+         * panic (or allocate more than the bytes could describe): it returns a ParseError saying
+         * what's wrong if the bytes aren't a Maze, are a version this can't read, run out early
+         * or go on too long, or put the openings or solution somewhere they can't be,
+         * DimensionsTooSmall or DimensionsTooLarge if the header's size is, and Invalid if the
+         * Maze they describe is broken some other way (as validate finds).
+         *
+         * ```
+         * use rust_maze::{CreationAlgorithm, Maze};
+         *
+         * let mut maze = Maze::new_seeded((101, 101), CreationAlgorithm::Kruskal, 7);
+         * maze.solve();
+         * let bytes = maze.to_bytes();
+         * assert!(bytes.len() < 1500);
+         * let copy = Maze::from_bytes(&bytes).unwrap();
+         * assert_eq!(copy, maze);
+         * assert_eq!(copy.solution(), maze.solution());
+         *
+         * // anything cut short, or with the wrong magic bytes, is refused
+         * assert!(Maze::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+         * assert!(Maze::from_bytes(b"MAZE").is_err());
+         * let mut corrupt = bytes.clone();
+         * corrupt[0] = b'X';
+         * assert!(Maze::from_bytes(&corrupt).is_err());
+         * ```
+         */
+        pub fn from_bytes(bytes: &[u8]) -> Result<Self, MazeError> {
+            let mut reader = ByteReader { bytes, pos: 0 };
+            if reader.take(4)? != b"MAZE" {
+                return Err(MazeError::ParseError(String::from("not a maze")));
+            }
+            let version = reader.byte()?;
+            if version != BYTES_VERSION {
+                return Err(MazeError::ParseError(format!(
+                    "version {} isn't supported",
+                    version
+                )));
+            }
+            let dimensions = (reader.number()?, reader.number()?);
+            Self::check_dimensions(dimensions)?;
+            let flags = reader.byte()?;
+            if flags > 7 {
+                return Err(MazeError::ParseError(format!(
+                    "unknown flags {:#04x}",
+                    flags
+                )));
+            }
+            let cell = |reader: &mut ByteReader, what: &str| {
+                let pos = (reader.number()?, reader.number()?);
+                if pos.0 < dimensions.0 && pos.1 < dimensions.1 {
+                    Ok(pos)
+                } else {
+                    Err(MazeError::ParseError(format!(
+                        "the {} ({}, {}) is outside the maze",
+                        what, pos.0, pos.1
+                    )))
+                }
+            };
+            let entrypoint = cell(&mut reader, "entrance")?;
+            let count = reader.number()?;
+            let goal = reader.number()?;
+            // each exit takes eight bytes, so there can't be more than there are bytes left
+            if count > reader.remaining() / 8 {
+                return Err(MazeError::ParseError(String::from("the exits are cut off")));
+            }
+            if count > 0 && goal >= count {
+                return Err(MazeError::ParseError(format!(
+                    "goal {} isn't one of the {} exits",
+                    goal, count
+                )));
+            }
+            let mut exits: Vec<(usize, usize)> = Vec::with_capacity(count);
+            for _ in 0..count {
+                exits.push(cell(&mut reader, "exit")?);
+            }
+
+            let packed = reader.take((dimensions.0 * dimensions.1).div_ceil(8))?;
+            let mut cells: Vec<Vec<Cell>> = (0..dimensions.0)
+                .map(|y| {
+                    (0..dimensions.1)
+                        .map(|x| {
+                            let index = y * dimensions.1 + x;
+                            Cell {
+                                wall: packed[index / 8] & (0x80 >> (index % 8)) != 0,
+                                visited: false,
+                                tag: None,
+                                cost: 1,
+                            }
+                        })
+                        .collect()
+                })
+                .collect();
+            for (what, (y, x)) in std::iter::once(("entrance", entrypoint))
+                .chain(exits.iter().map(|&exit| ("exit", exit)))
+            {
+                if cells[y][x].wall {
+                    return Err(MazeError::ParseError(format!(
+                        "the {} ({}, {}) is a wall",
+                        what, y, x
+                    )));
+                }
+            }
+            let goalpoint = exits.get(goal).copied().unwrap_or(entrypoint);
+
+            let mut marks = vec![vec![false; dimensions.1]; dimensions.0];
+            if flags & 1 == 1 {
+                let moves = reader.number()?;
+                let packed = reader.take(moves.div_ceil(4))?;
+                let mut pos = entrypoint;
+                marks[pos.0][pos.1] = true;
+                for index in 0..moves {
+                    let (dy, dx): (isize, isize) =
+                        match packed[index / 4] >> (6 - 2 * (index % 4)) & 0b11 {
+                            0 => (-1, 0),
+                            1 => (0, 1),
+                            2 => (1, 0),
+                            _ => (0, -1),
+                        };
+                    let (y, x) = (pos.0 as isize + dy, pos.1 as isize + dx);
+                    if y < 0
+                        || x < 0
+                        || y as usize >= dimensions.0
+                        || x as usize >= dimensions.1
+                        || cells[y as usize][x as usize].wall
+                    {
+                        return Err(MazeError::ParseError(format!(
+                            "move {} of the solution runs into a wall",
+                            index
+                        )));
+                    }
+                    pos = (y as usize, x as usize);
+                    marks[pos.0][pos.1] = true;
+                }
+                if pos != goalpoint {
+                    return Err(MazeError::ParseError(String::from(
+                        "the solution doesn't reach the goal",
+                    )));
+                }
+            }
+            if flags & 2 == 2 {
+                let count = reader.number()?;
+                // each tag takes twelve bytes, so there can't be more than there are bytes left
+                if count > reader.remaining() / 12 {
+                    return Err(MazeError::ParseError(String::from("the tags are cut off")));
+                }
+                for _ in 0..count {
+                    let (y, x) = cell(&mut reader, "tagged cell")?;
+                    cells[y][x].tag = Some(reader.number()? as u32);
+                }
+            }
+            if flags & 4 == 4 {
+                let count = reader.number()?;
+                // like the tags, each cost takes twelve bytes
+                if count > reader.remaining() / 12 {
+                    return Err(MazeError::ParseError(String::from("the costs are cut off")));
+                }
+                for _ in 0..count {
+                    let (y, x) = cell(&mut reader, "weighted cell")?;
+                    cells[y][x].cost = reader.number()? as u32;
+                }
+            }
+            if reader.remaining() > 0 {
+                return Err(MazeError::ParseError(format!(
+                    "{} bytes left over at the end",
+                    reader.remaining()
+                )));
+            }
+
+            let maze = Maze {
+                dimensions,
+                entrypoint,
+                goalpoint,
+                exits,
+                algorithm: None,
+                cells,
+                stats: SolveStats::default(),
+                log: None,
+                overlay: SolutionOverlay::from_marks(marks),
+            };
+            maze.validate().map_err(MazeError::Invalid)?;
+            Ok(maze)
+        }
+
+        /**
+         * Writes this Maze as JSON, in a shape that's kept the same from version to version:
+         *
+         * {"rows":5,"cols":3,"entrance":[1,0],"exit":[3,2],"exits":[[3,2]],
+         *  "walls":[[true,true,true],...],"solution":[[1,0],[1,1],...]}
+         *
+         * Every cell is a [row, col] pair, with row 0 at the top and col 0 at the left. walls
+         * holds one array per row, top to bottom, of whether each cell in it (left to right) is
+         * a wall. exit is the goalpoint, and exits lists every exit (exit among them, unless
+         * they've all been removed). solution is the marked path from the entrance to the exit,
+         * or empty if the Maze isn't solved. If any cells are tagged, tags comes last, with a
+         * [row, col, tag] triple for each of them, row by row, and then costs, with a
+         * [row, col, cost] triple for each cell that doesn't cost 1. Nothing is added besides
+         * whitespace-free JSON, so the output can be compared byte for byte.
+         *
+         * ```
+         * use rust_maze::{CreationAlgorithm, Maze};
+         *
+         * // a 5x3 maze only has room for one passage, straight down the middle
+         * let mut maze = Maze::new_from((5, 3), CreationAlgorithm::Prim);
+         * maze.solve();
+         * assert_eq!(
+         *     maze.to_json(),
+         *     concat!(
+         *         r#"{"rows":5,"cols":3,"entrance":[1,0],"exit":[3,2],"exits":[[3,2]],"#,
+         *         r#""walls":[[true,true,true],[false,false,true],[true,false,true],"#,
+         *         r#"[true,false,false],[true,true,true]],"#,
+         *         r#""solution":[[1,0],[1,1],[2,1],[3,1],[3,2]]}"#
+         *     )
+         * );
+         * assert_eq!(Maze::from_json(&maze.to_json()).unwrap(), maze);
+         * ```
+         */
+        pub fn to_json(&self) -> String {
+            self.json(false)
+        }
+
+        /**
+         * Writes this Maze as JSON like to_json, but with the walls run-length encoded, which
+         * keeps big Mazes much smaller. In place of walls is walls_rle: the lengths of the runs
+         * of walls and passages, alternating, going through every cell row by row, top to
+         * bottom and left to right. The first run is always of walls (so it's 0 if the first
+         * cell isn't one), and the lengths add up to rows * cols.
+         */
+        pub fn to_json_compact(&self) -> String {
+            self.json(true)
+        }
+
+        /**
+         * The lengths of the runs of walls and passages, as to_json_compact writes them in
+         * walls_rle.
+         */
+        fn wall_runs(&self) -> Vec<usize> {
+            let mut runs: Vec<usize> = vec![0];
+            let mut wall = true;
+            for cell in self.cells.iter().flatten() {
+                if cell.wall != wall {
+                    runs.push(0);
+                    wall = cell.wall;
+                }
+                *runs.last_mut().expect("there's always a run") += 1;
+            }
+            runs
+        }
+
+        /**
+         * Writes this Maze as JSON, with the walls run-length encoded if rle is true.
+         */
+        fn json(&self, rle: bool) -> String {
+            let cell = |(y, x): (usize, usize)| format!("[{},{}]", y, x);
+            let cells = |cells: &[(usize, usize)]| {
+                cells
+                    .iter()
+                    .map(|&pos| cell(pos))
+                    .collect::<Vec<String>>()
+                    .join(",")
+            };
+            let walls = if rle {
+                let runs: Vec<String> =
+                    self.wall_runs().iter().map(|run| run.to_string()).collect();
+                format!("\"walls_rle\":[{}]", runs.join(","))
+            } else {
+                let rows: Vec<String> = self
+                    .cells
+                    .iter()
+                    .map(|row| {
+                        let row: Vec<&str> = row
+                            .iter()
+                            .map(|cell| if cell.wall { "true" } else { "false" })
+                            .collect();
+                        format!("[{}]", row.join(","))
+                    })
+                    .collect();
+                format!("\"walls\":[{}]", rows.join(","))
+            };
+            let tags: Vec<String> = self
+                .tagged()
+                .map(|((y, x), tag)| format!("[{},{},{}]", y, x, tag))
+                .collect();
+            let tags = match tags.is_empty() {
+                true => String::new(),
+                false => format!(",\"tags\":[{}]", tags.join(",")),
+            };
+            let costs: Vec<String> = self
+                .weighted_cells()
+                .map(|((y, x), cost)| format!("[{},{},{}]", y, x, cost))
+                .collect();
+            let costs = match costs.is_empty() {
+                true => String::new(),
+                false => format!(",\"costs\":[{}]", costs.join(",")),
+            };
+            format!(
+                "{{\"rows\":{},\"cols\":{},\"entrance\":{},\"exit\":{},\"exits\":[{}],{},\"solution\":[{}]{}{}}}",
+                self.dimensions.0,
+                self.dimensions.1,
+                cell(self.entrypoint),
+                cell(self.goalpoint),
+                cells(&self.exits),
+                walls,
+                cells(&self.solution().unwrap_or_default()),
+                tags,
+                costs
+            )
+        }
+
+        /**
+         * Reads a Maze back from the JSON to_json or to_json_compact writes: walls and walls_rle
+         * are both accepted (but not together), and exits can be left out, for just the one exit,
+         * and tags and costs for none. Other fields are ignored. Returns a ParseError saying what's
+         * wrong if it isn't JSON, a field is missing or the wrong type, the walls don't match the
+         * rows and cols (or their runs don't add up), there's too little JSON for that many cells
+         * (each value in it can stand for at most 65536 of them), the openings aren't passages
+         * inside the Maze, or the solution doesn't step from the entrance to the exit through
+         * passages; DimensionsTooSmall or DimensionsTooLarge if the rows and cols are; or Invalid
+         * if the Maze is broken some other way (as validate finds).
+         */
+        pub fn from_json(text: &str) -> Result<Self, MazeError> {
+            let json = crate::json::parse(text).map_err(MazeError::ParseError)?;
+            Self::from_json_value(&json)
+        }
+
+        /**
+         * Reads a Maze back from JSON that's already been parsed, as from_json does (and as
+         * deserializing one from a readable format does, with serde).
+         */
+        fn from_json_value(json: &crate::json::Value) -> Result<Self, MazeError> {
+            use crate::json::Value;
+            let error = |problem: String| MazeError::ParseError(problem);
+            if !matches!(json, Value::Object(_)) {
+                return Err(error(String::from("expected an object")));
+            }
+            let number = |value: &Value, what: &str| match value {
+                Value::Number(number)
+                    if number.fract() == 0.0 && (0.0..=u32::MAX as f64).contains(number) =>
+                {
+                    Ok(*number as usize)
+                }
+                _ => Err(error(format!("{} must be a whole number", what))),
+            };
+            let field = |name: &str| {
+                json.field(name)
+                    .ok_or_else(|| error(format!("{} is missing", name)))
+            };
+            fn array<'a>(value: &'a Value, what: &str) -> Result<&'a [Value], MazeError> {
+                match value {
+                    Value::Array(items) => Ok(items),
+                    _ => Err(MazeError::ParseError(format!("{} must be an array", what))),
+                }
+            }
+
+            let dimensions = (
+                number(field("rows")?, "rows")?,
+                number(field("cols")?, "cols")?,
+            );
+            Self::check_dimensions(dimensions)?;
+            // a handful of runs could otherwise ask for billions of cells before anything else
+            // is checked
+            if dimensions.0 * dimensions.1 > json.count().saturating_mul(CELLS_PER_JSON_VALUE) {
+                return Err(error(format!(
+                    "{} values of JSON are too few to describe {}x{} cells",
+                    json.count(),
+                    dimensions.0,
+                    dimensions.1
+                )));
+            }
+            let cell = |value: &Value, what: &str| {
+                let pair = array(value, what)?;
+                if pair.len() != 2 {
+                    return Err(error(format!("{} must be a [row, col] pair", what)));
+                }
+                let pos = (number(&pair[0], what)?, number(&pair[1], what)?);
+                if pos.0 >= dimensions.0 || pos.1 >= dimensions.1 {
+                    return Err(error(format!(
+                        "{} ({}, {}) is outside the maze",
+                        what, pos.0, pos.1
+                    )));
+                }
+                Ok(pos)
+            };
+
+            let walls: Vec<bool> = match (json.field("walls"), json.field("walls_rle")) {
+                (Some(walls), None) => {
+                    let rows = array(walls, "walls")?;
+                    if rows.len() != dimensions.0 {
+                        return Err(error(format!(
+                            "walls has {} rows, but rows is {}",
+                            rows.len(),
+                            dimensions.0
+                        )));
+                    }
+                    let mut walls: Vec<bool> = Vec::with_capacity(dimensions.0 * dimensions.1);
+                    for (y, row) in rows.iter().enumerate() {
+                        let row = array(row, "each row of walls")?;
+                        if row.len() != dimensions.1 {
+                            return Err(error(format!(
+                                "row {} of walls has {} cells, but cols is {}",
+                                y,
+                                row.len(),
+                                dimensions.1
+                            )));
+                        }
+                        for value in row {
+                            match value {
+                                Value::Bool(wall) => walls.push(*wall),
+                                _ => {
+                                    return Err(error(String::from("walls must be true or false")))
+                                }
+                            }
+                        }
+                    }
+                    walls
+                }
+                (None, Some(runs)) => {
+                    let runs = array(runs, "walls_rle")?;
+                    let mut lengths: Vec<usize> = Vec::with_capacity(runs.len());
+                    for run in runs {
+                        lengths.push(number(run, "each run of walls_rle")?);
+                    }
+                    // add the runs up before making room for them, so they can't ask for more
+                    let total = lengths
+                        .iter()
+                        .try_fold(0usize, |total, &run| total.checked_add(run));
+                    if total != Some(dimensions.0 * dimensions.1) {
+                        return Err(error(format!(
+                            "the runs of walls_rle don't add up to {} cells",
+                            dimensions.0 * dimensions.1
+                        )));
+                    }
+                    let mut walls: Vec<bool> = Vec::with_capacity(dimensions.0 * dimensions.1);
+                    for (index, &run) in lengths.iter().enumerate() {
+                        walls.extend(std::iter::repeat_n(index % 2 == 0, run));
+                    }
+                    walls
+                }
+                (Some(_), Some(_)) => {
+                    return Err(error(String::from(
+                        "only one of walls and walls_rle is allowed",
+                    )))
+                }
+                (None, None) => return Err(error(String::from("walls is missing"))),
+            };
+            let mut cells: Vec<Vec<Cell>> = walls
+                .chunks(dimensions.1)
+                .map(|row| {
+                    row.iter()
+                        .map(|&wall| Cell {
+                            wall,
+                            visited: false,
+                            tag: None,
+                            cost: 1,
+                        })
+                        .collect()
+                })
+                .collect();
+
+            let entrypoint = cell(field("entrance")?, "the entrance")?;
+            let goalpoint = cell(field("exit")?, "the exit")?;
+            let exits: Vec<(usize, usize)> = match json.field("exits") {
+                Some(exits) => array(exits, "exits")?
+                    .iter()
+                    .map(|exit| cell(exit, "each exit"))
+                    .collect::<Result<_, _>>()?,
+                None => vec![goalpoint],
+            };
+            for (what, (y, x)) in [("the entrance", entrypoint), ("the exit", goalpoint)]
+                .into_iter()
+                .chain(exits.iter().map(|&exit| ("an exit", exit)))
+            {
+                if cells[y][x].wall {
+                    return Err(error(format!("{} ({}, {}) is a wall", what, y, x)));
+                }
+            }
+
+            let solution: Vec<(usize, usize)> = array(field("solution")?, "solution")?
+                .iter()
+                .map(|pos| cell(pos, "each cell of the solution"))
+                .collect::<Result<_, _>>()?;
+            let mut marks = vec![vec![false; dimensions.1]; dimensions.0];
+            if !solution.is_empty() {
+                if solution.first() != Some(&entrypoint) || solution.last() != Some(&goalpoint) {
+                    return Err(error(String::from(
+                        "the solution must go from the entrance to the exit",
+                    )));
+                }
+                if let Err(MazeError::PathGap(index)) = Path::try_new(solution.clone()) {
+                    return Err(error(format!(
+                        "cell {} of the solution isn't next to the one before it",
+                        index
+                    )));
+                }
+                for &(y, x) in &solution {
+                    if cells[y][x].wall {
+                        return Err(error(format!(
+                            "the solution runs through the wall at ({}, {})",
+                            y, x
+                        )));
+                    }
+                    marks[y][x] = true;
+                }
+            }
+            if let Some(tags) = json.field("tags") {
+                for triple in array(tags, "tags")? {
+                    let triple = array(triple, "each tag")?;
+                    if triple.len() != 3 {
+                        return Err(error(String::from(
+                            "each tag must be a [row, col, tag] triple",
+                        )));
+                    }
+                    let (y, x) = (
+                        number(&triple[0], "each tagged cell")?,
+                        number(&triple[1], "each tagged cell")?,
+                    );
+                    if y >= dimensions.0 || x >= dimensions.1 {
+                        return Err(error(format!(
+                            "the tagged cell ({}, {}) is outside the maze",
+                            y, x
+                        )));
+                    }
+                    cells[y][x].tag = Some(number(&triple[2], "each tag")? as u32);
+                }
+            }
+            if let Some(costs) = json.field("costs") {
+                for triple in array(costs, "costs")? {
+                    let triple = array(triple, "each cost")?;
+                    if triple.len() != 3 {
+                        return Err(error(String::from(
+                            "each cost must be a [row, col, cost] triple",
+                        )));
+                    }
+                    let (y, x) = (
+                        number(&triple[0], "each weighted cell")?,
+                        number(&triple[1], "each weighted cell")?,
+                    );
+                    if y >= dimensions.0 || x >= dimensions.1 {
+                        return Err(error(format!(
+                            "the weighted cell ({}, {}) is outside the maze",
+                            y, x
+                        )));
+                    }
+                    cells[y][x].cost = number(&triple[2], "each cost")? as u32;
+                }
+            }
+
+            let maze = Maze {
+                dimensions,
+                entrypoint,
+                goalpoint,
+                exits,
+                algorithm: None,
+                cells,
+                stats: SolveStats::default(),
+                log: None,
+                overlay: SolutionOverlay::from_marks(marks),
+            };
+            maze.validate().map_err(MazeError::Invalid)?;
+            Ok(maze)
+        }
+
+        /**
+         * Saves this Maze to the file at the given path, in the given format, replacing whatever
+         * was there. Returns an Io error naming the path if it can't be written.
+         */
+        pub fn save(&self, path: &std::path::Path, format: FileFormat) -> Result<(), MazeError> {
+            let bytes = match format {
+                FileFormat::Text => format!("{:#}\n", self).into_bytes(),
+                FileFormat::Binary => self.to_bytes(),
+            };
+            std::fs::write(path, bytes).map_err(|error| MazeError::Io(path.to_path_buf(), error))
+        }
+
+        /**
+         * Loads a Maze from the file at the given path, saved in either format: it's read as
+         * Binary if it starts with the magic bytes to_bytes writes, and as Text otherwise. A Maze
+         * that was saved solved is loaded solved, and solving it again starts from scratch just
+         * the same. Returns an Io error naming the path if it can't be read, or whatever error
+         * from_bytes or from_str finds in its contents.
+         */
+        pub fn load(path: &std::path::Path) -> Result<Self, MazeError> {
+            let bytes =
+                std::fs::read(path).map_err(|error| MazeError::Io(path.to_path_buf(), error))?;
+            if bytes.starts_with(b"MAZE") {
+                return Self::from_bytes(&bytes);
+            }
+            match String::from_utf8(bytes) {
+                Ok(text) => text.parse(),
+                Err(_) => Err(MazeError::ParseError(String::from(
+                    "it's neither text nor a packed maze",
+                ))),
+            }
+        }
+
+        /**
+         * Removes dead ends from this Maze by knocking down one of the walls of a dead-end cell,
+         * preferring walls that lead into another dead end. fraction is the proportion of dead ends
+         * to remove, from 0.0 (leave the Maze alone) to 1.0 (a fully braided Maze with no dead
+         * ends). Braiding creates loops, so dead-end filling can no longer solve the Maze alone.
+         * The solution is removed, since there might be a shorter one now.
+         */
+        pub fn braid(&mut self, fraction: f64) {
+            self.braid_from_rng(fraction, &mut thread_rng());
+        }
+
+        /**
+         * Braids this Maze like braid, making each of its random choices with rng.
+         */
+        fn braid_from_rng(&mut self, fraction: f64, rng: &mut dyn RngCore) {
+            self.unsolve();
+            let two_offsets: [(isize, isize); 4] = [(-2, 0), (2, 0), (0, -2), (0, 2)];
+
+            let mut dead_ends = self.dead_end_cells();
+            dead_ends.shuffle(rng);
+            let count = (fraction.clamp(0.0, 1.0) * dead_ends.len() as f64).round() as usize;
+
+            for &dead_end in &dead_ends[..count] {
+                // knocking out an earlier dead end might've already taken care of this one
+                if self.degree(dead_end) != 1 {
+                    continue;
+                }
+
+                // find the walls between this dead end and the (odd, odd) cells around it
+                let mut walls: Vec<(usize, usize)> = vec![];
+                let mut walls_to_dead_ends: Vec<(usize, usize)> = vec![];
+                for &(dy, dx) in &two_offsets {
+                    let two_neighbor: (isize, isize) =
+                        (dead_end.0 as isize + dy, dead_end.1 as isize + dx);
+                    if two_neighbor.0 > 0
+                        && two_neighbor.0 < self.dimensions.0 as isize - 1
+                        && two_neighbor.1 > 0
+                        && two_neighbor.1 < self.dimensions.1 as isize - 1
+                    {
+                        let two_neighbor = (two_neighbor.0 as usize, two_neighbor.1 as usize);
+                        let wall = (
+                            (dead_end.0 + two_neighbor.0) / 2,
+                            (dead_end.1 + two_neighbor.1) / 2,
+                        );
+                        if self.cells[wall.0][wall.1].wall {
+                            walls.push(wall);
+                            if self.degree(two_neighbor) == 1 {
+                                walls_to_dead_ends.push(wall);
+                            }
+                        }
+                    }
+                }
+
+                // connecting two dead ends gets rid of both at once
+                if let Some(&(y, x)) = walls_to_dead_ends
+                    .choose(&mut *rng)
+                    .or_else(|| walls.choose(&mut *rng))
+                {
+                    self.cells[y][x].wall = false;
+                }
+            }
+        }
+
+        /**
+         * Returns the (odd, odd) cells of this Maze that are dead ends--that is, have exactly one
+         * open neighbor.
+         */
+        fn dead_end_cells(&self) -> Vec<(usize, usize)> {
+            let mut dead_ends: Vec<(usize, usize)> = vec![];
+            for y in (1..self.dimensions.0 - 1).step_by(2) {
+                for x in (1..self.dimensions.1 - 1).step_by(2) {
+                    if !self.cells[y][x].wall && self.degree((y, x)) == 1 {
+                        dead_ends.push((y, x));
+                    }
+                }
+            }
+            dead_ends
+        }
+
+        /**
+         * Returns the longest of the shortest paths between any two passages in this Maze (its
+         * diameter), from one end to the other, which mark_path can show. It's found with two
+         * breadth-first searches: the passage farthest from the entrypoint is at one end of the
+         * longest path in a perfect Maze, and the passage farthest from that is at the other.
+         * Loops can fool that, so in a Maze that isn't perfect the search is repeated from the
+         * far end of each path found until they stop getting longer, which finds the longest
+         * path (or one close to it) without searching from every passage. The path is empty if
+         * there are no passages at all.
+         *
+         * ```
+         * use rust_maze::Maze;
+         *
+         * let maze: Maze = concat!(
+         *     "██▒▒██████\n",
+         *     "██      ██\n",
+         *     "██  ██████\n",
+         *     "██      ░░\n",
+         *     "██████████",
+         * )
+         * .parse()
+         * .unwrap();
+         * let path = maze.longest_path();
+         * assert_eq!(path.len(), 8);
+         * assert_eq!(path.cells().first(), Some(&(3, 4)));
+         * assert_eq!(path.cells().last(), Some(&(1, 3)));
+         * ```
+         */
+        pub fn longest_path(&self) -> Path {
+            let (height, width) = self.dimensions;
+            let Some(start) = std::iter::once(self.entrypoint)
+                .chain(self.passages().map(|(pos, _)| pos))
+                .find(|&(y, x)| y < height && x < width && !self.cells[y][x].wall)
+            else {
+                return Path::new(vec![]);
+            };
+            // the farthest cell, and how far it is
+            let farthest = |distances: &Vec<Vec<Option<usize>>>| {
+                let mut farthest = (start, 0);
+                for (y, row) in distances.iter().enumerate() {
+                    for (x, &distance) in row.iter().enumerate() {
+                        if distance.is_some_and(|distance| distance > farthest.1) {
+                            farthest = ((y, x), distance.unwrap_or(0));
+                        }
+                    }
+                }
+                farthest
+            };
+
+            let (mut from, _) = farthest(&self.distance_grid(start));
+            let mut distances = self.distance_grid(from);
+            let (mut to, mut length) = farthest(&distances);
+            if !self.is_perfect() {
+                loop {
+                    let next = self.distance_grid(to);
+                    let (far, far_length) = farthest(&next);
+                    if far_length <= length {
+                        break;
+                    }
+                    (from, to, length, distances) = (to, far, far_length, next);
+                }
+            }
+
+            // walk back from the far end, one step closer each time
+            let mut cells: Vec<(usize, usize)> = vec![to];
+            let mut pos = to;
+            while pos != from {
+                let distance = distances[pos.0][pos.1].unwrap_or(0);
+                pos = [(1, 0), (-1, 0), (0, 1), (0, -1)]
+                    .iter()
+                    .map(|&(dy, dx)| {
+                        (
+                            (pos.0 as isize + dy) as usize,
+                            (pos.1 as isize + dx) as usize,
+                        )
+                    })
+                    .find(|&(y, x)| {
+                        y < height && x < width && distances[y][x] == Some(distance - 1)
+                    })
+                    .expect("every cell in the search is a step further than one next to it");
+                cells.push(pos);
+            }
+            cells.reverse();
+            Path::new(cells)
+        }
+
+        /**
+         * Moves the entrance and exit as far apart as they can be: to the ends of the longest
+         * path through the passages inside the outer wall, as found by longest_path. An end
+         * that isn't right beside the outer wall is moved to the spot in the wall closest to it
+         * (by walking, not as the crow flies) that opens onto a passage, so nothing new is
+         * carved. The old openings are walled up, like set_openings does, and any solution is
+         * removed. Nothing changes if the outer wall doesn't have two spots that open onto
+         * passages, or if moving the ends out to the wall would leave them no farther apart than
+         * the entrance and exit already are.
+         */
+        pub fn relocate_openings_to_longest_path(&mut self) {
+            let (height, width) = self.dimensions;
+            let on_border =
+                |(y, x): (usize, usize)| y == 0 || x == 0 || y == height - 1 || x == width - 1;
+            // the ends are found with the current openings (and any other exits) walled up
+            let mut sealed = self.clone();
+            for &(y, x) in std::iter::once(&self.entrypoint).chain(&self.exits) {
+                if on_border((y, x)) {
+                    sealed.cells[y][x].wall = true;
+                }
+            }
+            let path = sealed.longest_path();
+            let (Some(&first), Some(&last)) = (path.cells().first(), path.cells().last()) else {
+                return;
+            };
+
+            // every spot on the outer wall (apart from the corners) that opens onto a passage,
+            // along with the passage
+            let mut spots: Vec<((usize, usize), (usize, usize))> = vec![];
+            for x in 1..width - 1 {
+                spots.extend([((0, x), (1, x)), ((height - 1, x), (height - 2, x))]);
+            }
+            for y in 1..height - 1 {
+                spots.extend([((y, 0), (y, 1)), ((y, width - 1), (y, width - 2))]);
+            }
+            spots.retain(|&(_, (y, x))| !sealed.cells[y][x].wall);
+            let nearest = |end: (usize, usize), except: Option<(usize, usize)>| {
+                let distances = sealed.distance_grid(end);
+                spots
+                    .iter()
+                    .filter(|&&(spot, _)| Some(spot) != except)
+                    .filter_map(|&(spot, (y, x))| distances[y][x].map(|distance| (distance, spot)))
+                    .min_by_key(|&(distance, _)| distance)
+                    .map(|(_, spot)| spot)
+            };
+            let Some(entrance) = nearest(first, None) else {
+                return;
+            };
+            let Some(exit) = nearest(last, Some(entrance)) else {
+                return;
+            };
+            // projecting the ends onto the wall can bring them closer together than the
+            // openings already are, in which case they're better off where they were
+            let inside = |spot: (usize, usize)| {
+                spots
+                    .iter()
+                    .find(|&&(other, _)| other == spot)
+                    .map(|&(_, inside)| inside)
+                    .expect("the openings are chosen from the spots")
+            };
+            let apart = sealed.distance_grid(inside(entrance))[inside(exit).0][inside(exit).1];
+            let (y, x) = self.goalpoint;
+            if self.distance_grid(self.entrypoint)[y][x]
+                .is_some_and(|current| apart.is_none_or(|apart| apart + 2 <= current))
+            {
+                return;
+            }
+            // other exits stay where they are, unless the entrance is going where one is
+            let goalpoint = self.goalpoint;
+            self.exits
+                .retain(|&other| other == goalpoint || other != entrance);
+            self.set_openings(entrance, exit)
+                .expect("spots in the outer wall are never corners, and these two are different");
+        }
+
+        /**
+         * Moves the entrypoint and goalpoint to the two openings in the outer wall that are
+         * farthest apart, by searching for the farthest opening from the entrypoint and then the
+         * farthest from that (in a perfect Maze, that's as far apart as two openings can be).
+         * Returns the number of cells on the shortest path between them.
+         */
+        fn move_openings_apart(&mut self) -> usize {
+            let (height, width) = self.dimensions;
+            // the open cell just inside an opening
+            let inside = |pos: (usize, usize)| match pos {
+                (0, x) => (1, x),
+                (y, 0) => (y, 1),
+                (y, x) if y == height - 1 => (y - 1, x),
+                (y, x) => (y, x - 1),
+            };
+
+            // every spot on the outer wall (apart from the corners) that could be opened
+            let inner_entrypoint = inside(self.entrypoint);
+            self.cells[self.entrypoint.0][self.entrypoint.1].wall = true;
+            self.cells[self.goalpoint.0][self.goalpoint.1].wall = true;
+            let mut openings: Vec<(usize, usize)> = vec![];
+            for x in 1..width - 1 {
+                openings.extend([(0, x), (height - 1, x)]);
+            }
+            for y in 1..height - 1 {
+                openings.extend([(y, 0), (y, width - 1)]);
+            }
+            openings.retain(|&opening| {
+                let (y, x) = inside(opening);
+                !self.cells[y][x].wall
+            });
+
+            let farthest = |distances: &Vec<Vec<Option<usize>>>, except: Option<(usize, usize)>| {
+                openings
+                    .iter()
+                    .filter(|&&opening| Some(opening) != except)
+                    .filter_map(|&opening| {
+                        let (y, x) = inside(opening);
+                        distances[y][x].map(|distance| (distance, opening))
+                    })
+                    .max_by_key(|&(distance, _)| distance)
+            };
+            let start = farthest(&self.distance_grid(inner_entrypoint), None)
+                .map_or(self.entrypoint, |(_, opening)| opening);
+            let (distance, end) = farthest(&self.distance_grid(inside(start)), Some(start))
+                .unwrap_or((0, self.goalpoint));
+
+            self.entrypoint = start;
+            self.set_goalpoint(end);
+            self.cells[start.0][start.1].wall = false;
+            self.cells[end.0][end.1].wall = false;
+            // the cells between the two inner cells, plus the openings themselves
+            distance + 3
+        }
+
+        /**
+         * Returns how many steps it takes to get from the given (y, x) passage to every cell of
+         * this Maze, row by row, without passing through walls: None for walls, and for
+         * passages that can't be reached from it at all. The Maze (solution and all) is left as
+         * it was. Returns InvalidCoordinate if the cell is outside the Maze, or NotOpen if it's a
+         * wall.
+         *
+         * ```
+         * use rust_maze::{CreationAlgorithm, Maze};
+         *
+         * // everything to the right of column 8 is masked off
+         * let mask: Vec<Vec<bool>> = (0..11).map(|_| (0..21).map(|x| x < 9).collect()).collect();
+         * let maze = Maze::new_masked(&mask, CreationAlgorithm::Kruskal).unwrap();
+         * let (entrance, exit) = maze.openings();
+         * let distances = maze.distance_map(entrance).unwrap();
+         * assert_eq!(distances[entrance.0][entrance.1], Some(0));
+         * assert_eq!(distances[0][0], None);
+         * assert_eq!(distances[5][15], None);
+         * assert_eq!(maze.distance(entrance, exit), distances[exit.0][exit.1]);
+         * assert!(maze.distance_map((0, 0)).is_err());
+         * ```
+         */
+        pub fn distance_map(
+            &self,
+            from: (usize, usize),
+        ) -> Result<Vec<Vec<Option<usize>>>, MazeError> {
+            self.check_coordinate(from)?;
+            if self.cells[from.0][from.1].wall {
+                return Err(MazeError::NotOpen(from));
+            }
+            Ok(self.distance_grid(from))
+        }
+
+        /**
+         * Returns how many steps it takes to get between the two given (y, x) cells without
+         * passing through walls, or None if there's no way between them (or either one is a
+         * wall, or outside this Maze). Nothing on the Maze is marked or changed, and the search
+         * stops as soon as it gets to b, so it's cheap enough to ask often.
+         */
+        pub fn distance(&self, a: (usize, usize), b: (usize, usize)) -> Option<usize> {
+            self.distances_from(a, &[b])[0]
+        }
+
+        /**
+         * Returns how many steps it takes to get from the given (y, x) cell to each of the
+         * targets, in the same order, like distance but all from one search. Targets that are
+         * walls, outside this Maze, or that can't be reached get None--as do all of them, if
+         * the cell itself is a wall or outside the Maze.
+         */
+        pub fn distances_from(
+            &self,
+            a: (usize, usize),
+            targets: &[(usize, usize)],
+        ) -> Vec<Option<usize>> {
+            let (height, width) = self.dimensions;
+            let mut found: Vec<Option<usize>> = vec![None; targets.len()];
+            if !self.is_passage(a) {
+                return found;
+            }
+            // which cells are targets, and how many of those are passages that might turn up
+            let mut targeted = vec![false; height * width];
+            let mut remaining = 0;
+            for &pos in targets {
+                if self.is_passage(pos) && !targeted[pos.0 * width + pos.1] {
+                    targeted[pos.0 * width + pos.1] = true;
+                    remaining += 1;
+                }
+            }
+            let mut distances: Vec<Option<usize>> = vec![None; height * width];
+            let mut queue: VecDeque<(usize, usize)> = VecDeque::from([a]);
+            distances[a.0 * width + a.1] = Some(0);
+            while let Some(current) = queue.pop_front() {
+                if remaining == 0 {
+                    break;
+                }
+                let distance = distances[current.0 * width + current.1].unwrap_or(0);
+                if targeted[current.0 * width + current.1] {
+                    for (i, _) in targets
+                        .iter()
+                        .enumerate()
+                        .filter(|&(_, &pos)| pos == current)
+                    {
+                        found[i] = Some(distance);
+                    }
+                    remaining -= 1;
+                }
+                for (y, x) in self.open_neighbors(current) {
+                    if distances[y * width + x].is_none() {
+                        distances[y * width + x] = Some(distance + 1);
+                        queue.push_back((y, x));
+                    }
+                }
+            }
+            found
+        }
+
+        /**
+         * The same as distance, by the name it had first.
+         */
+        pub fn distance_between(&self, a: (usize, usize), b: (usize, usize)) -> Option<usize> {
+            self.distance(a, b)
+        }
+
+        /**
+         * Picks one of the cells that aren't walls, each as likely as any other--somewhere to put
+         * an item or an enemy. Every Maze has an open entrance, which is what's returned if
+         * somehow nothing is open at all.
+         */
+        pub fn random_passage(&self, rng: &mut impl Rng) -> (usize, usize) {
+            let open: Vec<(usize, usize)> = self.open_cells().collect();
+            open.choose(rng).copied().unwrap_or(self.entrypoint)
+        }
+
+        /**
+         * Picks two of the cells that aren't walls, each pair as likely as any other, that are at
+         * least min_distance steps apart along the passages (not as the crow flies). Pairs with
+         * no way between them don't count. If no such pair turns up after a fair number of
+         * tries--there may not be one--the farthest-apart pair that did is returned instead.
+         *
+         * ```
+         * use rust_maze::{CreationAlgorithm, Maze};
+         *
+         * let maze = Maze::new_seeded((21, 21), CreationAlgorithm::Prim, 7);
+         * let mut rng = rand::thread_rng();
+         * let (a, b) = maze.random_passage_pair(&mut rng, 10);
+         * assert!(maze.distance(a, b).unwrap() >= 10);
+         * ```
+         */
+        pub fn random_passage_pair(
+            &self,
+            rng: &mut impl Rng,
+            min_distance: usize,
+        ) -> ((usize, usize), (usize, usize)) {
+            // how many pairs to try before settling for the farthest one so far
+            const ATTEMPTS: usize = 64;
+            let open: Vec<(usize, usize)> = self.open_cells().collect();
+            let Some(&first) = open.first() else {
+                return (self.entrypoint, self.entrypoint);
+            };
+            let mut farthest = ((first, first), 0);
+            for _ in 0..ATTEMPTS {
+                let a = open[rng.gen_range(0..open.len())];
+                let b = open[rng.gen_range(0..open.len())];
+                let Some(distance) = self.distance(a, b) else {
+                    continue;
+                };
+                if distance >= min_distance {
+                    return (a, b);
+                }
+                if distance > farthest.1 {
+                    farthest = ((a, b), distance);
+                }
+            }
+            farthest.0
+        }
+
+        /**
+         * Iterates over the cells that aren't walls, by the walls alone (so unlike passages, a
+         * walled-up opening is left out).
+         */
+        fn open_cells(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+            self.cells()
+                .map(|(pos, _)| pos)
+                .filter(|&pos| self.is_passage(pos))
+        }
+
+        /**
+         * Returns the number of steps it takes to get from the given cell to every other cell,
+         * without passing through walls, or None for cells that can't be reached at all.
+         */
+        fn distance_grid(&self, from: (usize, usize)) -> Vec<Vec<Option<usize>>> {
+            let directions: [(isize, isize); 4] = [(1, 0), (-1, 0), (0, 1), (0, -1)];
+            let mut distances: Vec<Vec<Option<usize>>> =
+                vec![vec![None; self.dimensions.1]; self.dimensions.0];
+            let mut queue: VecDeque<(usize, usize)> = VecDeque::from([from]);
+            distances[from.0][from.1] = Some(0);
+            while let Some(current) = queue.pop_front() {
+                let distance = distances[current.0][current.1].unwrap_or(0);
+                for &(dy, dx) in &directions {
+                    let neighbor: (isize, isize) =
+                        (current.0 as isize + dy, current.1 as isize + dx);
+                    if neighbor.0 < 0
+                        || neighbor.0 >= self.dimensions.0 as isize
+                        || neighbor.1 < 0
+                        || neighbor.1 >= self.dimensions.1 as isize
+                    {
+                        continue;
+                    }
+                    let neighbor = (neighbor.0 as usize, neighbor.1 as usize);
+                    if !self.cells[neighbor.0][neighbor.1].wall
+                        && distances[neighbor.0][neighbor.1].is_none()
+                    {
+                        distances[neighbor.0][neighbor.1] = Some(distance + 1);
+                        queue.push_back(neighbor);
+                    }
+                }
+            }
+            distances
+        }
+
+        /**
+         * Returns the (y, x) cells directly north, east, south, and west of the given cell, in
+         * that order (the order of Direction), leaving out any that would be outside this Maze.
+         * A cell that's outside the Maze itself has no neighbors.
+         */
+        pub fn neighbors(&self, cell: (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+            let (height, width) = self.dimensions;
+            let inside = cell.0 < height && cell.1 < width;
+            [(-1, 0), (0, 1), (1, 0), (0, -1)]
+                .into_iter()
+                .filter(move |_| inside)
+                .filter_map(move |(dy, dx): (isize, isize)| {
+                    let (y, x) = (cell.0 as isize + dy, cell.1 as isize + dx);
+                    (y >= 0 && x >= 0 && (y as usize) < height && (x as usize) < width)
+                        .then_some((y as usize, x as usize))
+                })
+        }
+
+        /**
+         * Returns the neighbors of the given cell that aren't walls--the ones a step from it
+         * can go to--in the same order as neighbors.
+         */
+        pub fn open_neighbors(
+            &self,
+            cell: (usize, usize),
+        ) -> impl Iterator<Item = (usize, usize)> + '_ {
+            self.neighbors(cell)
+                .filter(move |&(y, x)| !self.cells[y][x].wall)
+        }
+
+        /**
+         * Returns how many of the given cell's neighbors aren't walls: 1 for a dead end, and 3
+         * or 4 for a junction (if the cell is a passage itself). A cell outside this Maze has
+         * none.
+         */
+        pub fn degree(&self, cell: (usize, usize)) -> usize {
+            self.open_neighbors(cell).count()
+        }
+
+        /**
+         * Returns the shortest path from the given cell to the goalpoint via breadth-first search,
+         * never stepping into a blocked cell, or straight from the first cell to one in cut. Each
+         * cell explored uses up one of the budget; once it runs out, the search gives up.
+         */
+        fn shortest_path_avoiding(
+            &self,
+            from: (usize, usize),
+            blocked: &[Vec<bool>],
+            cut: &[(usize, usize)],
+            budget: &mut usize,
+        ) -> Option<Vec<(usize, usize)>> {
+            let mut parents: Vec<Vec<Option<(usize, usize)>>> =
+                vec![vec![None; self.dimensions.1]; self.dimensions.0];
+            let mut queue: VecDeque<(usize, usize)> = VecDeque::from([from]);
+            parents[from.0][from.1] = Some(from);
+
+            while let Some(current) = queue.pop_front() {
+                if *budget == 0 {
+                    return None;
+                }
+                *budget -= 1;
+
+                if current == self.goalpoint {
+                    let mut path = vec![current];
+                    let mut pos = current;
+                    while let Some(parent) = parents[pos.0][pos.1].filter(|&parent| parent != pos) {
+                        path.push(parent);
+                        pos = parent;
+                    }
+                    path.reverse();
+                    return Some(path);
+                }
+
+                for (dy, dx) in [(1, 0), (0, 1), (-1, 0), (0, -1)] {
+                    let (y, x) = (current.0 as isize + dy, current.1 as isize + dx);
+                    if y < 0 || x < 0 {
+                        continue;
+                    }
+                    let next = (y as usize, x as usize);
+                    if self.is_passage(next)
+                        && !blocked[next.0][next.1]
+                        && parents[next.0][next.1].is_none()
+                        && !(current == from && cut.contains(&next))
+                    {
+                        parents[next.0][next.1] = Some(current);
+                        queue.push_back(next);
+                    }
+                }
+            }
+            None
+        }
+
+        /**
+         * Returns whether this Maze has a loop anywhere in it--more than one way between some pair
+         * of cells--which a perfect Maze never does.
+         */
+        fn has_loops(&self) -> bool {
+            // a passage with no loops has one fewer connection between cells than it has cells
+            let mut cells = 0;
+            let mut connections = 0;
+            for y in 0..self.dimensions.0 {
+                for x in 0..self.dimensions.1 {
+                    if self.is_passage((y, x)) {
+                        cells += 1;
+                        connections += self.is_passage((y + 1, x)) as usize;
+                        connections += self.is_passage((y, x + 1)) as usize;
+                    }
+                }
+            }
+            // and there's one fewer connection per separate piece of passage
+            let mut pieces = 0;
+            let mut reached = vec![vec![false; self.dimensions.1]; self.dimensions.0];
+            for y in 0..self.dimensions.0 {
+                for x in 0..self.dimensions.1 {
+                    if !self.is_passage((y, x)) || reached[y][x] {
+                        continue;
+                    }
+                    pieces += 1;
+                    reached[y][x] = true;
+                    let mut stack: Vec<(usize, usize)> = vec![(y, x)];
+                    while let Some(pos) = stack.pop() {
+                        for next in (MazeView { maze: self }).neighbors(pos) {
+                            if !reached[next.0][next.1] {
+                                reached[next.0][next.1] = true;
+                                stack.push(next);
+                            }
+                        }
+                    }
+                }
+            }
+            connections + pieces > cells
+        }
+
+        /**
+         * Returns InvalidCoordinate if the given (y, x) is outside this Maze.
+         */
+        fn check_coordinate(&self, pos: (usize, usize)) -> Result<(), MazeError> {
+            if pos.0 < self.dimensions.0 && pos.1 < self.dimensions.1 {
+                Ok(())
+            } else {
+                Err(MazeError::InvalidCoordinate(pos))
+            }
+        }
+
+        /**
+         * Returns whether the given (y, x) cell is marked on this Maze's overlay.
+         */
+        fn is_marked(&self, pos: (usize, usize)) -> bool {
+            self.overlay
+                .as_ref()
+                .is_some_and(|overlay| overlay.contains(pos))
+        }
+
+        /**
+         * Marks the given (y, x) cell on this Maze's overlay, starting one if there isn't one.
+         */
+        fn mark(&mut self, pos: (usize, usize)) {
+            let dimensions = self.dimensions;
+            self.overlay
+                .get_or_insert_with(|| SolutionOverlay::new(dimensions))
+                .set(pos, true);
+        }
+
+        /**
+         * Clears the given (y, x) cell on this Maze's overlay, if it's marked.
+         */
+        fn unmark(&mut self, pos: (usize, usize)) {
+            if let Some(overlay) = &mut self.overlay {
+                overlay.set(pos, false);
+            }
+        }
+
+        /**
+         * Returns whether the given (y, x) is inside this Maze, and not a wall.
+         */
+        fn is_passage(&self, pos: (usize, usize)) -> bool {
+            pos.0 < self.dimensions.0 && pos.1 < self.dimensions.1 && !self.cells[pos.0][pos.1].wall
+        }
+
+        /**
+         * Returns the shortest path from the entrypoint to the goalpoint through the marked cells
+         * of this Maze--which is the solution itself, for solvers that mark exactly the path, and
+         * leaves out the dead ends for ones that mark everywhere they walked.
+         */
+        fn marked_path(&self) -> Option<Path> {
+            self.path_through(self.overlay.as_ref(), self.goalpoint)
+        }
+
+        /**
+         * Returns the shortest path from the entrypoint to the given goalpoint through the cells
+         * marked on overlay, which needn't be this Maze's own.
+         */
+        fn path_through(
+            &self,
+            overlay: Option<&SolutionOverlay>,
+            goalpoint: (usize, usize),
+        ) -> Option<Path> {
+            let mut parents: Vec<Vec<Option<(usize, usize)>>> =
+                vec![vec![None; self.dimensions.1]; self.dimensions.0];
+            let mut queue: VecDeque<(usize, usize)> = VecDeque::from([self.entrypoint]);
+            parents[self.entrypoint.0][self.entrypoint.1] = Some(self.entrypoint);
+            while let Some(current) = queue.pop_front() {
+                if current == goalpoint {
+                    let mut cells: Vec<(usize, usize)> = vec![current];
+                    let mut pos = current;
+                    while pos != self.entrypoint {
+                        pos = parents[pos.0][pos.1].expect("every reached cell has a parent");
+                        cells.push(pos);
+                    }
+                    cells.reverse();
+                    return Some(Path { cells });
+                }
+                for (dy, dx) in [(1, 0), (0, 1), (-1, 0), (0, -1)] {
+                    let (y, x) = (
+                        (current.0 as isize + dy) as usize,
+                        (current.1 as isize + dx) as usize,
+                    );
+                    if self.is_passage((y, x))
+                        && overlay.is_some_and(|overlay| overlay.contains((y, x)))
+                        && parents[y][x].is_none()
+                    {
+                        parents[y][x] = Some(current);
+                        queue.push_back((y, x));
+                    }
+                }
+            }
+            None
+        }
+
+        /**
+         * Fits this Maze to a mask, given as a test for whether a position may be carved into. The
+         * cells that can't--and any passages leading to them--are walled off, then every (odd, odd)
+         * cell that can is opened up, along with the cells in open. That leaves the Maze in pieces,
+         * which are joined back together Kruskal-style by knocking down walls between them, picked
+         * in an order shuffled with rng.
+         * Returns false if some of the pieces can't be joined.
+         */
+        fn fit_to_mask_from_rng(
+            &mut self,
+            allowed: &dyn Fn((isize, isize)) -> bool,
+            open: &[(usize, usize)],
+            rng: &mut dyn RngCore,
+        ) -> bool {
+            let dimensions = self.dimensions;
+
+            for (y, row) in self.cells.iter_mut().enumerate() {
+                for (x, cell) in row.iter_mut().enumerate() {
+                    let (y, x) = (y as isize, x as isize);
+                    cell.wall |= !allowed((y, x))
+                        || match (y % 2, x % 2) {
+                            (0, 1) => !allowed((y - 1, x)) || !allowed((y + 1, x)),
+                            (1, 0) => !allowed((y, x - 1)) || !allowed((y, x + 1)),
+                            _ => false,
+                        };
+                    if y % 2 == 1 && x % 2 == 1 && allowed((y, x)) {
+                        cell.wall = false;
+                    }
+                }
+            }
+            for &(y, x) in open {
+                self.cells[y][x].wall = false;
+            }
+
+            let index = |pos: (usize, usize)| pos.0 * dimensions.1 + pos.1;
+            let neighbors = |(y, x): (usize, usize)| {
+                [(1, 0), (-1, 0), (0, 1), (0, -1)]
+                    .into_iter()
+                    .map(move |(dy, dx)| ((y as isize + dy) as usize, (x as isize + dx) as usize))
+            };
+            let mut sets = DisjointSet::new(dimensions.0 * dimensions.1);
+            for y in 1..dimensions.0 - 1 {
+                for x in 1..dimensions.1 - 1 {
+                    if !self.cells[y][x].wall {
+                        for (ny, nx) in neighbors((y, x)) {
+                            if !self.cells[ny][nx].wall {
+                                sets.union(index((y, x)), index((ny, nx)));
+                            }
+                        }
+                    }
+                }
+            }
+
+            // the walls that could be knocked down: passages between two (odd, odd) cells, which
+            // are only worth knocking down if they'd join two different pieces
+            let mut walls: Vec<(usize, usize)> = vec![];
+            for y in 1..dimensions.0 - 1 {
+                for x in 1..dimensions.1 - 1 {
+                    if (y + x) % 2 == 1
+                        && self.cells[y][x].wall
+                        && allowed((y as isize, x as isize))
+                    {
+                        walls.push((y, x));
+                    }
+                }
+            }
+            walls.shuffle(rng);
+            for (y, x) in walls {
+                let mut pieces: Vec<usize> = neighbors((y, x))
+                    .filter(|&(ny, nx)| !self.cells[ny][nx].wall)
+                    .map(|pos| sets.find(index(pos)))
+                    .collect();
+                pieces.sort_unstable();
+                pieces.dedup();
+                if pieces.len() >= 2 {
+                    self.cells[y][x].wall = false;
+                    for piece in pieces {
+                        sets.union(index((y, x)), piece);
+                    }
+                }
+            }
+
+            // everything open has to be reachable from everything else
+            let mut open_cells = (1..dimensions.0 - 1)
+                .flat_map(|y| (1..dimensions.1 - 1).map(move |x| (y, x)))
+                .filter(|&(y, x)| !self.cells[y][x].wall);
+            match open_cells.next() {
+                Some(first) => {
+                    let reached = Self::flood(&self.cells, first);
+                    open_cells.all(|(y, x)| reached[y][x])
+                }
+                None => true,
+            }
+        }
+
+        /**
+         * Finds where the entrypoint and goalpoint go in a Maze fitted to a mask: the first spot
+         * on the mask's boundary (reading top to bottom, left to right) where a passage can lead
+         * west or north out of it, and the last spot where one can lead east or south. There's
+         * always nothing north of the first (odd, odd) cell or south of the last, so both can be
+         * found as long as there's at least one.
+         */
+        fn boundary_openings(
+            &self,
+            allowed: &dyn Fn((isize, isize)) -> bool,
+        ) -> Option<((usize, usize), (usize, usize))> {
+            let rooms: Vec<(usize, usize)> = (1..self.dimensions.0 - 1)
+                .step_by(2)
+                .flat_map(|y| (1..self.dimensions.1 - 1).step_by(2).map(move |x| (y, x)))
+                .filter(|&(y, x)| allowed((y as isize, x as isize)))
+                .collect();
+            let outside = |room: (usize, usize), (dy, dx): (isize, isize)| {
+                (!allowed((room.0 as isize + 2 * dy, room.1 as isize + 2 * dx))).then_some((
+                    (room.0 as isize + dy) as usize,
+                    (room.1 as isize + dx) as usize,
+                ))
+            };
+            let entrypoint = rooms
+                .iter()
+                .find_map(|&room| outside(room, (0, -1)).or_else(|| outside(room, (-1, 0))))?;
+            let goalpoint = rooms
+                .iter()
+                .rev()
+                .find_map(|&room| outside(room, (0, 1)).or_else(|| outside(room, (1, 0))))?;
+            Some((entrypoint, goalpoint))
+        }
+
+        /**
+         * Generates a Maze using a random non-self-intersecting walk, beginning at a
+         * randomly-selected cell.
+         * Only good for small Mazes--causes stack overflows for Mazes with more than ~32,000 Cells
+         */
+        fn gen_from_walk(mut cells: Canvas, rng: &mut dyn RngCore) -> Self {
+            let dimensions: (usize, usize) = (cells.len(), cells[0].len());
+
+            let entrypoint: (usize, usize) = (1, 0);
+            cells.open(entrypoint);
+
+            let goalpoint: (usize, usize) = (dimensions.0 - 2, dimensions.1 - 1);
+            cells.open(goalpoint);
+
+            // random starting point
+            let mut starter: (usize, usize) = (0, 0);
+            while starter.0.is_multiple_of(2) || starter.1.is_multiple_of(2) {
+                starter = (
+                    gen_index(rng, 1..(dimensions.0 - 1)),
+                    gen_index(rng, 1..(dimensions.1 - 1)),
+                );
+            }
+
+            /**
+             * Recursively steps forward in a non-self-intersecting random walk and carves out the
+             * path in the Maze as it goes.
+             */
+            fn walk(
+                cells: &mut Canvas,
+                pos: (usize, usize),
+                dimensions: (usize, usize),
+                rng: &mut dyn RngCore,
+            ) {
+                if cells.cancelled() {
+                    return;
+                }
+                // remove wall at current cell and mark it as visited
+                cells.open(pos);
+                cells.visit(pos);
+
+                let mut two_offsets: [(isize, isize); 4] = [(-2, 0), (2, 0), (0, -2), (0, 2)];
+                two_offsets.shuffle(rng);
+
+                let mut walk_to = |two_offset: (isize, isize)| {
+                    let two_neighbor: (isize, isize) =
+                        (pos.0 as isize + two_offset.0, pos.1 as isize + two_offset.1);
+
+                    // if the cell specified by the offset is within the allowable area,
+                    if two_neighbor.0 > 0
+                        && two_neighbor.0 < dimensions.0 as isize - 1
+                        && two_neighbor.1 > 0
+                        && two_neighbor.1 < dimensions.1 as isize - 1
+                        // and hasn't yet been visited,
+                        && !cells[two_neighbor.0 as usize][two_neighbor.1 as usize].visited
+                    {
+                        // set the cell between them to not a wall (sneaky integer division)
+                        cells.open((
+                            (pos.0 as isize + two_offset.0 / 2) as usize,
+                            (pos.1 as isize + two_offset.1 / 2) as usize,
+                        ));
+                        // and walk from said cell specified by the offset
+                        walk(
+                            cells,
+                            (
+                                (pos.0 as isize + two_offset.0) as usize,
+                                (pos.1 as isize + two_offset.1) as usize,
+                            ),
+                            dimensions,
+                            rng,
+                        );
+                    }
+                };
+
+                for two_offset in two_offsets {
+                    walk_to(two_offset);
+                }
+            }
+
+            walk(&mut cells, starter, dimensions, rng);
+
+            // set each cell back to unvisited, so the Maze will print properly
+            cells.clear_visited();
+
+            Maze {
+                dimensions,
+                entrypoint,
+                goalpoint,
+                exits: vec![goalpoint],
+                algorithm: None,
+                cells: cells.cells,
+                stats: SolveStats::default(),
+                log: None,
+                overlay: None,
+            }
+        }
+
+        /**
+         * Generates a Maze by recursively adding walls at random positions that divide the
+         * remaining accessible area. Areas whose sides are both smaller than min_room_size are
+         * left open as rooms with probability room_chance, instead of being divided further; a
+         * room_chance of 0.0 divides everything, leaving a perfect Maze.
+         * Rooms leave more than one way across them, so dead-end filling can't narrow a Maze that
+         * has any down to one path on its own.
+         */
+        fn gen_from_divide(
+            mut cells: Canvas,
+            room_chance: f64,
+            min_room_size: usize,
+            rng: &mut dyn RngCore,
+        ) -> Self {
+            let dimensions: (usize, usize) = (cells.len(), cells[0].len());
+            let room_chance = probability(room_chance);
+
+            let entrypoint: (usize, usize) = (1, 0);
+
+            let goalpoint: (usize, usize) = (dimensions.0 - 2, dimensions.1 - 1);
+
+            // set walls on top and bottom
+            for i in 0..cells[0].len() {
+                cells.close((0, i));
+                cells.close((dimensions.0 - 1, i));
+            }
+
+            // set walls on left and right
+            for y in 0..dimensions.0 {
+                cells.close((y, 0));
+                cells.close((y, dimensions.1 - 1));
+            }
+
+            // make sure the entrypoint and goalpoint aren't walls
+            cells.open(entrypoint);
+            cells.open(goalpoint);
+
+            /**
+             * Recursively divide the section of the Maze uniquely defined by its specified top-left
+             * and bottom-right corners in half, leaving a hole in the wall to maintain access
+             * between the left/right or top/bottom halves. Small enough sections might be left
+             * undivided as rooms, according to room: (room_chance, min_room_size).
+             */
+            fn divide(
+                cells: &mut Canvas,
+                top_left: (usize, usize),
+                bottom_right: (usize, usize),
+                room: (f64, usize),
+                rng: &mut dyn RngCore,
+            ) {
+                if cells.cancelled() {
+                    return;
+                }
+                let height = bottom_right.0 - top_left.0;
+                let width = bottom_right.1 - top_left.1;
+
+                // we can't divide further if the area to divide is already 1 cell narrow
+                if height == 2 || width == 2 {
+                    return;
+                }
+                // leave the area open as a room, if it's small enough and we feel like it
+                let (room_chance, min_room_size) = room;
+                if room_chance > 0.0
+                    && height - 1 < min_room_size
+                    && width - 1 < min_room_size
+                    && rng.gen_bool(room_chance)
+                {
+                    return;
+                }
+                // if the area to divide is at least as tall as it is wide,
+                if height >= width {
+                    // pick an even y-index to draw a wall that divides the remaining area, and draw
+                    // it
+                    let mut wall_index = 1;
+                    while wall_index % 2 != 0 {
+                        wall_index = gen_index(rng, (top_left.0 + 1)..bottom_right.0);
+                    }
+                    for x in (top_left.1 + 1)..bottom_right.1 {
+                        cells.close((wall_index, x));
+                    }
+
+                    // pick an odd x-index to draw the hole on, and punch it out
+                    let mut hole_index = 0;
+                    while hole_index % 2 != 1 {
+                        hole_index = gen_index(rng, (top_left.1 + 1)..bottom_right.1);
+                    }
+                    cells.open((wall_index, hole_index));
+
+                    // recursively divide the remaining halves
+                    divide(cells, top_left, (wall_index, bottom_right.1), room, rng);
+                    divide(cells, (wall_index, top_left.1), bottom_right, room, rng);
+                }
+                // if the area to divide is wider than it is tall (same procedure as above)
+                else {
+                    let mut wall_index = gen_index(rng, (top_left.1 + 1)..bottom_right.1);
+                    while wall_index % 2 != 0 {
+                        wall_index = gen_index(rng, (top_left.1 + 1)..bottom_right.1);
+                    }
+                    for y in (top_left.0 + 1)..bottom_right.0 {
+                        cells.close((y, wall_index));
+                    }
+
+                    let mut hole_index = gen_index(rng, (top_left.0 + 1)..bottom_right.0);
+                    while hole_index % 2 != 1 {
+                        hole_index = gen_index(rng, (top_left.0 + 1)..bottom_right.0);
+                    }
+                    cells.open((hole_index, wall_index));
+
+                    divide(cells, top_left, (bottom_right.0, wall_index), room, rng);
+                    divide(cells, (top_left.0, wall_index), bottom_right, room, rng);
+                }
+            }
+
+            divide(
+                &mut cells,
+                (0, 0),
+                (dimensions.0 - 1, dimensions.1 - 1),
+                (room_chance.clamp(0.0, 1.0), min_room_size),
+                rng,
+            );
+
+            Maze {
+                dimensions,
+                goalpoint,
+                exits: vec![goalpoint],
+                algorithm: None,
+                entrypoint,
+                cells: cells.cells,
+                stats: SolveStats::default(),
+                log: None,
+                overlay: None,
+            }
+        }
+
+        /**
+         * Iteratively generates a Maze using iterative randomized Prim's algorithm. The starting
+         * point is randomly selected.
+         */
+        fn gen_from_prim(mut cells: Canvas, rng: &mut dyn RngCore) -> Self {
+            let dimensions: (usize, usize) = (cells.len(), cells[0].len());
+
+            let entrypoint: (usize, usize) = (1, 0);
+            cells.open(entrypoint);
+
+            let goalpoint: (usize, usize) = (dimensions.0 - 2, dimensions.1 - 1);
+            cells.open(goalpoint);
+
+            let two_offsets = [(-2, 0), (2, 0), (0, 2), (0, -2)];
+
+            // compute frontier cells of a random (odd, odd) cell and add them to a list
+            let mut frontiers: Vec<(usize, usize)> = vec![];
+            let mut starter: (usize, usize) = (0, 0);
+            while starter.0.is_multiple_of(2) || starter.1.is_multiple_of(2) {
+                starter = (
+                    gen_index(rng, 1..(dimensions.0 - 1)),
+                    gen_index(rng, 1..(dimensions.1 - 1)),
+                );
+            }
+            cells.open(starter);
+
+            let append_frontiers = |cells: &Vec<Vec<Cell>>,
+                                    frontiers: &mut Vec<(usize, usize)>,
+                                    pos: (usize, usize)| {
+                for &(dy, dx) in &two_offsets {
+                    let two_neighbor: (isize, isize) = (pos.0 as isize + dy, pos.1 as isize + dx);
+
+                    // as long as the two-neighbor is in bounds (and not on the edge),
+                    if two_neighbor.0 > 0
+                        && two_neighbor.0 < cells.len() as isize - 1
+                        && two_neighbor.1 > 0
+                        && two_neighbor.1 < cells[0].len() as isize - 1
+                        // not a wall,
+                        && cells[two_neighbor.0 as usize][two_neighbor.1 as usize].wall
+                        // and not already in the list,
+                        && !frontiers.contains(&(two_neighbor.0 as usize, two_neighbor.1 as usize))
+                    {
+                        // add it to the list of frontier cells
+                        frontiers.push((two_neighbor.0 as usize, two_neighbor.1 as usize));
+                    }
+                }
+            };
+
+            append_frontiers(&cells, &mut frontiers, starter);
+
+            // while the list of frontier cells is not empty:
+            while !frontiers.is_empty() {
+                if cells.cancelled() {
+                    break;
+                }
+                // pick a random frontier cell from the list, and mark it as not a wall
+                let rand_frontier_index: usize = gen_index(rng, 0..frontiers.len());
+                let current: (usize, usize) = frontiers[rand_frontier_index];
+                cells.open(current);
+
+                // compute its two-neighbors (that aren't walls)
+                let mut two_neighbors = vec![];
+                for &(dy, dx) in &two_offsets {
+                    let two_neighbor: (isize, isize) =
+                        (current.0 as isize + dy, current.1 as isize + dx);
+
+                    // if the two-neighbor is in the allowable area (not OOB and not on the very
+                    // edge),
+                    if two_neighbor.0 > 0
+                        && two_neighbor.0 < dimensions.0 as isize - 1
+                        && two_neighbor.1 > 0
+                        && two_neighbor.1 < dimensions.1 as isize - 1
+                        // and not a wall,
+                        && !cells[two_neighbor.0 as usize][two_neighbor.1 as usize].wall
+                    {
+                        // add it to the list of two-neighbors
+                        two_neighbors.push((two_neighbor.0 as usize, two_neighbor.1 as usize));
+                    }
+                }
+
+                // Pick a random two-neighbor and connect the randomly chosen frontier cell with it
+                // by setting the cell in-between to not a wall
+                if let Some((two_neighbor_y, two_neighbor_x)) = two_neighbors.choose(rng) {
+                    cells.open((
+                        (*two_neighbor_y + current.0) / 2,
+                        (*two_neighbor_x + current.1) / 2,
+                    ));
+                }
+
+                // compute the frontier cells of the randomly chosen frontier cell and add them to
+                // the list, if they aren't already in the list
+                append_frontiers(&cells, &mut frontiers, current);
+
+                // remove the (current) randomly chosen frontier cell from the list
+                frontiers.remove(rand_frontier_index);
+            }
+
+            Maze {
+                dimensions,
+                entrypoint,
+                goalpoint,
+                exits: vec![goalpoint],
+                algorithm: None,
+                cells: cells.cells,
+                stats: SolveStats::default(),
+                log: None,
+                overlay: None,
+            }
+        }
+
+        /**
+         * Generates a Maze using a weighted variant of Prim's algorithm, which grows from a
+         * frontier of passages rather than cells. With probability straightness, the corridor just
+         * carved is carried on--straight ahead if it can be, turning if it can't; otherwise a
+         * random passage is taken from the frontier, as in classic Prim. A straightness of 0.0
+         * gives the same short, bushy dead ends as gen_from_prim, while 1.0 gives long, winding
+         * corridors.
+         */
+        fn gen_from_weighted_prim(
+            mut cells: Canvas,
+            straightness: f64,
+            rng: &mut dyn RngCore,
+        ) -> Self {
+            let dimensions: (usize, usize) = (cells.len(), cells[0].len());
+
+            let entrypoint: (usize, usize) = (1, 0);
+            cells.open(entrypoint);
+
+            let goalpoint: (usize, usize) = (dimensions.0 - 2, dimensions.1 - 1);
+            cells.open(goalpoint);
+
+            let two_offsets: [(isize, isize); 4] = [(-2, 0), (2, 0), (0, -2), (0, 2)];
+            let in_area = |pos: (isize, isize)| {
+                pos.0 > 0
+                    && pos.0 < dimensions.0 as isize - 1
+                    && pos.1 > 0
+                    && pos.1 < dimensions.1 as isize - 1
+            };
+
+            // random starting point
+            let mut starter: (usize, usize) = (0, 0);
+            while starter.0.is_multiple_of(2) || starter.1.is_multiple_of(2) {
+                starter = (
+                    gen_index(rng, 1..(dimensions.0 - 1)),
+                    gen_index(rng, 1..(dimensions.1 - 1)),
+                );
+            }
+
+            // every passage (from, to) that could be carved out to an unvisited cell
+            let mut frontier: Vec<((usize, usize), (usize, usize))> = vec![];
+            let straightness = probability(straightness);
+            // the last passage carved, which is the one we'd like to keep going along
+            let mut last: ((usize, usize), (usize, usize)) = (starter, starter);
+            cells.open(starter);
+            cells.visit(starter);
+
+            loop {
+                if cells.cancelled() {
+                    break;
+                }
+                // add the passages out of the newly carved cell to the frontier
+                let current = last.1;
+                let mut onward: Vec<(usize, usize)> = vec![];
+                for &(dy, dx) in &two_offsets {
+                    let two_neighbor: (isize, isize) =
+                        (current.0 as isize + dy, current.1 as isize + dx);
+                    if in_area(two_neighbor)
+                        && !cells[two_neighbor.0 as usize][two_neighbor.1 as usize].visited
+                    {
+                        onward.push((two_neighbor.0 as usize, two_neighbor.1 as usize));
+                        frontier
+                            .push((current, (two_neighbor.0 as usize, two_neighbor.1 as usize)));
+                    }
+                }
+
+                // the passage that carries straight on from the last one, or failing that, one
+                // that turns off of it
+                let ahead: (usize, usize) = (
+                    (2 * current.0).wrapping_sub(last.0 .0),
+                    (2 * current.1).wrapping_sub(last.0 .1),
+                );
+                let carry_on = if onward.contains(&ahead) {
+                    Some((current, ahead))
+                } else {
+                    onward.choose(rng).map(|&next| (current, next))
+                };
+
+                let (from, to) = match carry_on {
+                    Some(passage) if rng.gen_bool(straightness) => passage,
+                    _ => {
+                        // skip any passages leading to cells that have since been carved out
+                        let mut passage = None;
+                        while !frontier.is_empty() {
+                            let candidate = frontier.swap_remove(gen_index(rng, 0..frontier.len()));
+                            if !cells[candidate.1 .0][candidate.1 .1].visited {
+                                passage = Some(candidate);
+                                break;
+                            }
+                        }
+                        match passage {
+                            Some(passage) => passage,
+                            None => break,
+                        }
+                    }
+                };
+
+                cells.open(((from.0 + to.0) / 2, (from.1 + to.1) / 2));
+                cells.open(to);
+                cells.visit(to);
+                last = (from, to);
+            }
+
+            // set each cell back to unvisited, so the Maze will print properly
+            cells.clear_visited();
+
+            Maze {
+                dimensions,
+                entrypoint,
+                goalpoint,
+                exits: vec![goalpoint],
+                algorithm: None,
+                cells: cells.cells,
+                stats: SolveStats::default(),
+                log: None,
+                overlay: None,
+            }
+        }
+
+        /**
+         * Generates a Maze using randomized Kruskal's algorithm: every wall separating two Cells is
+         * visited in a random order, and knocked down only if the Cells on either side of it aren't
+         * already connected.
+         */
+        fn gen_from_kruskal(mut cells: Canvas, rng: &mut dyn RngCore) -> Self {
+            let dimensions: (usize, usize) = (cells.len(), cells[0].len());
+
+            let entrypoint: (usize, usize) = (1, 0);
+            cells.open(entrypoint);
+
+            let goalpoint: (usize, usize) = (dimensions.0 - 2, dimensions.1 - 1);
+            cells.open(goalpoint);
+
+            // every (odd, odd) cell starts out as its own set, completely walled in
+            let mut walls: Vec<(usize, usize)> = vec![];
+            for y in 1..dimensions.0 - 1 {
+                for x in 1..dimensions.1 - 1 {
+                    match (y % 2, x % 2) {
+                        (1, 1) => cells.open((y, x)),
+                        // walls between two (odd, odd) cells have exactly one even coordinate
+                        (0, 1) | (1, 0) => walls.push((y, x)),
+                        _ => {}
+                    }
+                }
+            }
+
+            // (odd, odd) cells are numbered row by row for the disjoint-set
+            let set_index = |(y, x): (usize, usize)| (y / 2) * (dimensions.1 / 2) + x / 2;
+            let mut sets = DisjointSet::new((dimensions.0 / 2) * (dimensions.1 / 2));
+            walls.shuffle(rng);
+
+            for (y, x) in walls {
+                if cells.cancelled() {
+                    break;
+                }
+                // find the two cells this wall separates--above and below it if it's on an even
+                // row, otherwise to the left and right of it
+                let (first, second) = if y % 2 == 0 {
+                    ((y - 1, x), (y + 1, x))
+                } else {
+                    ((y, x - 1), (y, x + 1))
+                };
+
+                // only knock the wall down if it doesn't create a loop
+                if sets.union(set_index(first), set_index(second)) {
+                    cells.open((y, x));
+                }
+            }
+
+            Maze {
+                dimensions,
+                entrypoint,
+                goalpoint,
+                exits: vec![goalpoint],
+                algorithm: None,
+                cells: cells.cells,
+                stats: SolveStats::default(),
+                log: None,
+                overlay: None,
+            }
+        }
+
+        /**
+         * Generates a Maze one row at a time with Eller's algorithm. Only the set membership of the
+         * current row is kept around, so the bookkeeping takes O(cols) memory no matter how tall
+         * the Maze is.
+         */
+        fn gen_from_eller(mut cells: Canvas, rng: &mut dyn RngCore) -> Self {
+            let dimensions: (usize, usize) = (cells.len(), cells[0].len());
+
+            let entrypoint: (usize, usize) = (1, 0);
+            cells.open(entrypoint);
+
+            let goalpoint: (usize, usize) = (dimensions.0 - 2, dimensions.1 - 1);
+            cells.open(goalpoint);
+
+            // the (odd, odd) cells form a grid of this many rows and columns
+            let rows = dimensions.0 / 2;
+            let cols = dimensions.1 / 2;
+            let mut next_set: usize = 0;
+            // the set each column of the current row belongs to, or None if it hasn't been assigned
+            // one yet
+            let mut sets: Vec<Option<usize>> = vec![None; cols];
+
+            for row in 0..rows {
+                if cells.cancelled() {
+                    break;
+                }
+                let y = 2 * row + 1;
+                let last_row = row == rows - 1;
+
+                // every cell not carried down from the previous row gets a set of its own
+                let mut row_sets: Vec<usize> = sets
+                    .iter()
+                    .map(|set| {
+                        set.unwrap_or_else(|| {
+                            next_set += 1;
+                            next_set
+                        })
+                    })
+                    .collect();
+                for col in 0..cols {
+                    cells.open((y, 2 * col + 1));
+                }
+
+                // randomly join adjacent cells that aren't already connected--on the last row,
+                // every pair of disjoint neighbors must be joined so the Maze ends up fully
+                // connected
+                for col in 0..cols - 1 {
+                    if row_sets[col] != row_sets[col + 1] && (last_row || rng.gen_bool(0.5)) {
+                        cells.open((y, 2 * col + 2));
+                        let (kept, merged) = (row_sets[col], row_sets[col + 1]);
+                        for set in row_sets.iter_mut() {
+                            if *set == merged {
+                                *set = kept;
+                            }
+                        }
+                    }
+                }
+
+                if last_row {
+                    break;
+                }
+
+                // randomly carve downward passages, making sure each set gets at least one so that
+                // none of them get cut off from the rest of the Maze
+                let mut carve_down: Vec<bool> = (0..cols).map(|_| rng.gen_bool(0.5)).collect();
+                let mut by_set: Vec<(usize, usize)> = row_sets
+                    .iter()
+                    .enumerate()
+                    .map(|(col, &set)| (set, col))
+                    .collect();
+                by_set.sort_unstable();
+                for group in by_set.chunk_by(|first, second| first.0 == second.0) {
+                    if !group.iter().any(|&(_, col)| carve_down[col]) {
+                        let &(_, col) = group.choose(rng).unwrap();
+                        carve_down[col] = true;
+                    }
+                }
+
+                for col in 0..cols {
+                    sets[col] = if carve_down[col] {
+                        cells.open((y + 1, 2 * col + 1));
+                        Some(row_sets[col])
+                    } else {
+                        None
+                    };
+                }
+            }
+
+            Maze {
+                dimensions,
+                entrypoint,
+                goalpoint,
+                exits: vec![goalpoint],
+                algorithm: None,
+                cells: cells.cells,
+                stats: SolveStats::default(),
+                log: None,
+                overlay: None,
+            }
+        }
+
+        /**
+         * Generates a Maze with Wilson's algorithm, which samples uniformly from every possible
+         * perfect Maze of the given size. Starting from a tree of one random cell, loop-erased
+         * random walks are performed from cells outside the tree until they hit it, and each erased
+         * walk is carved out and added to the tree.
+         */
+        fn gen_from_wilson(mut cells: Canvas, rng: &mut dyn RngCore) -> Self {
+            let dimensions: (usize, usize) = (cells.len(), cells[0].len());
+
+            let entrypoint: (usize, usize) = (1, 0);
+            cells.open(entrypoint);
+
+            let goalpoint: (usize, usize) = (dimensions.0 - 2, dimensions.1 - 1);
+            cells.open(goalpoint);
+
+            let two_offsets: [(isize, isize); 4] = [(-2, 0), (2, 0), (0, -2), (0, 2)];
+
+            // every (odd, odd) cell, in the random order walks will be started from
+            let mut starters: Vec<(usize, usize)> = vec![];
+            for y in (1..dimensions.0 - 1).step_by(2) {
+                for x in (1..dimensions.1 - 1).step_by(2) {
+                    starters.push((y, x));
+                }
+            }
+            starters.shuffle(rng);
+
+            // the first cell is the initial tree; visited marks membership in the tree
+            let root = starters[0];
+            cells.open(root);
+            cells.visit(root);
+
+            // the index into two_offsets that the walk last left each cell by--overwriting it when
+            // a walk revisits a cell is what erases the loops, without ever storing the walk itself
+            let mut exits: Vec<Vec<usize>> = vec![vec![0; dimensions.1]; dimensions.0];
+
+            for &starter in &starters[1..] {
+                if cells.cancelled() {
+                    break;
+                }
+                if cells[starter.0][starter.1].visited {
+                    continue;
+                }
+
+                // randomly walk until we bump into the tree
+                let mut pos = starter;
+                while !cells[pos.0][pos.1].visited {
+                    let (direction, next) = loop {
+                        let direction = gen_index(rng, 0..two_offsets.len());
+                        let (dy, dx) = two_offsets[direction];
+                        let next = (pos.0 as isize + dy, pos.1 as isize + dx);
+
+                        // only step to cells within the allowable area
+                        if next.0 > 0
+                            && next.0 < dimensions.0 as isize - 1
+                            && next.1 > 0
+                            && next.1 < dimensions.1 as isize - 1
+                        {
+                            break (direction, (next.0 as usize, next.1 as usize));
+                        }
+                    };
+                    exits[pos.0][pos.1] = direction;
+                    pos = next;
+                }
+
+                // retrace the loop-erased walk from its start, carving it into the tree as we go
+                let mut pos = starter;
+                while !cells[pos.0][pos.1].visited {
+                    let (dy, dx) = two_offsets[exits[pos.0][pos.1]];
+                    cells.open(pos);
+                    cells.visit(pos);
+                    // (sneaky integer division, again)
+                    cells.open((
+                        (pos.0 as isize + dy / 2) as usize,
+                        (pos.1 as isize + dx / 2) as usize,
+                    ));
+                    pos = (
+                        (pos.0 as isize + dy) as usize,
+                        (pos.1 as isize + dx) as usize,
+                    );
+                }
+            }
+
+            // set each cell back to unvisited, so the Maze will print properly
+            cells.clear_visited();
+
+            Maze {
+                dimensions,
+                entrypoint,
+                goalpoint,
+                exits: vec![goalpoint],
+                algorithm: None,
+                cells: cells.cells,
+                stats: SolveStats::default(),
+                log: None,
+                overlay: None,
+            }
+        }
+
+        /**
+         * Generates a Maze with the Aldous-Broder algorithm: a simple random walk wanders the whole
+         * grid, carving a passage every time it steps into a cell it hasn't been to before, until
+         * every cell has been visited. Like Wilson's algorithm, this samples uniformly from all
+         * perfect Mazes, but it can take a long time to stumble into the last few cells.
+         */
+        fn gen_from_aldous_broder(mut cells: Canvas, rng: &mut dyn RngCore) -> Self {
+            let dimensions: (usize, usize) = (cells.len(), cells[0].len());
+
+            let entrypoint: (usize, usize) = (1, 0);
+            cells.open(entrypoint);
+
+            let goalpoint: (usize, usize) = (dimensions.0 - 2, dimensions.1 - 1);
+            cells.open(goalpoint);
+
+            let two_offsets: [(isize, isize); 4] = [(-2, 0), (2, 0), (0, -2), (0, 2)];
+
+            // random starting point
+            let mut pos: (usize, usize) = (0, 0);
+            while pos.0.is_multiple_of(2) || pos.1.is_multiple_of(2) {
+                pos = (
+                    gen_index(rng, 1..(dimensions.0 - 1)),
+                    gen_index(rng, 1..(dimensions.1 - 1)),
+                );
+            }
+            cells.open(pos);
+            cells.visit(pos);
+
+            let mut unvisited = (dimensions.0 / 2) * (dimensions.1 / 2) - 1;
+            while unvisited > 0 {
+                if cells.cancelled() {
+                    break;
+                }
+                let &(dy, dx) = two_offsets.choose(rng).unwrap();
+                let two_neighbor: (isize, isize) = (pos.0 as isize + dy, pos.1 as isize + dx);
+
+                // stay within the allowable area
+                if two_neighbor.0 <= 0
+                    || two_neighbor.0 >= dimensions.0 as isize - 1
+                    || two_neighbor.1 <= 0
+                    || two_neighbor.1 >= dimensions.1 as isize - 1
+                {
+                    continue;
+                }
+                let two_neighbor = (two_neighbor.0 as usize, two_neighbor.1 as usize);
+
+                // the first time we step into a cell, carve the passage we took to get there
+                if !cells[two_neighbor.0][two_neighbor.1].visited {
+                    cells.open(two_neighbor);
+                    cells.visit(two_neighbor);
+                    cells.open(((pos.0 + two_neighbor.0) / 2, (pos.1 + two_neighbor.1) / 2));
+                    unvisited -= 1;
+                }
+                pos = two_neighbor;
+            }
+
+            // set each cell back to unvisited, so the Maze will print properly
+            cells.clear_visited();
+
+            Maze {
+                dimensions,
+                entrypoint,
+                goalpoint,
+                exits: vec![goalpoint],
+                algorithm: None,
+                cells: cells.cells,
+                stats: SolveStats::default(),
+                log: None,
+                overlay: None,
+            }
+        }
+
+        /**
+         * Generates a Maze with the Hunt-and-Kill algorithm: a random walk carves through unvisited
+         * cells until it gets stuck, then the grid is scanned ("hunted") for an unvisited cell next
+         * to a visited one, which is connected to the Maze and becomes the start of the next walk.
+         * This tends to produce long, winding corridors with relatively few dead ends.
+         */
+        fn gen_from_hunt_and_kill(mut cells: Canvas, rng: &mut dyn RngCore) -> Self {
+            let dimensions: (usize, usize) = (cells.len(), cells[0].len());
+
+            let entrypoint: (usize, usize) = (1, 0);
+            cells.open(entrypoint);
+
+            let goalpoint: (usize, usize) = (dimensions.0 - 2, dimensions.1 - 1);
+            cells.open(goalpoint);
+
+            let two_offsets: [(isize, isize); 4] = [(-2, 0), (2, 0), (0, -2), (0, 2)];
+
+            // returns the two-neighbors of pos within the allowable area that have (or haven't)
+            // been visited yet
+            let two_neighbors = |cells: &Vec<Vec<Cell>>, pos: (usize, usize), visited: bool| {
+                let mut two_neighbors: Vec<(usize, usize)> = vec![];
+                for &(dy, dx) in &two_offsets {
+                    let two_neighbor: (isize, isize) = (pos.0 as isize + dy, pos.1 as isize + dx);
+                    if two_neighbor.0 > 0
+                        && two_neighbor.0 < dimensions.0 as isize - 1
+                        && two_neighbor.1 > 0
+                        && two_neighbor.1 < dimensions.1 as isize - 1
+                        && cells[two_neighbor.0 as usize][two_neighbor.1 as usize].visited
+                            == visited
+                    {
+                        two_neighbors.push((two_neighbor.0 as usize, two_neighbor.1 as usize));
+                    }
+                }
+                two_neighbors
+            };
+
+            // random starting point
+            let mut pos: (usize, usize) = (0, 0);
+            while pos.0.is_multiple_of(2) || pos.1.is_multiple_of(2) {
+                pos = (
+                    gen_index(rng, 1..(dimensions.0 - 1)),
+                    gen_index(rng, 1..(dimensions.1 - 1)),
+                );
+            }
+
+            // every row above this one has been completely visited, so hunting can start here
+            let mut hunt_row: usize = 1;
+
+            loop {
+                if cells.cancelled() {
+                    break;
+                }
+                // kill: walk randomly through unvisited cells until we hit a dead end
+                cells.open(pos);
+                cells.visit(pos);
+                while let Some(&next) = two_neighbors(&cells, pos, false).choose(rng) {
+                    cells.open(((pos.0 + next.0) / 2, (pos.1 + next.1) / 2));
+                    cells.open(next);
+                    cells.visit(next);
+                    pos = next;
+                }
+
+                // hunt: find an unvisited cell bordering the visited part of the Maze, and connect
+                // it to a random visited neighbor
+                let mut hunted: Option<(usize, usize)> = None;
+                let mut y = hunt_row;
+                let mut rows_finished = true;
+                while hunted.is_none() && y < dimensions.0 - 1 {
+                    for x in (1..dimensions.1 - 1).step_by(2) {
+                        if cells[y][x].visited {
+                            continue;
+                        }
+                        rows_finished = false;
+                        if let Some(&visited_neighbor) =
+                            two_neighbors(&cells, (y, x), true).choose(rng)
+                        {
+                            cells
+                                .open(((y + visited_neighbor.0) / 2, (x + visited_neighbor.1) / 2));
+                            hunted = Some((y, x));
+                            break;
+                        }
+                    }
+                    // rows with nothing left to visit never need to be scanned again
+                    if rows_finished {
+                        hunt_row = y + 2;
+                    }
+                    y += 2;
+                }
+
+                match hunted {
+                    Some(next) => pos = next,
+                    None => break,
+                }
+            }
+
+            // set each cell back to unvisited, so the Maze will print properly
+            cells.clear_visited();
+
+            Maze {
+                dimensions,
+                entrypoint,
+                goalpoint,
+                exits: vec![goalpoint],
+                algorithm: None,
+                cells: cells.cells,
+                stats: SolveStats::default(),
+                log: None,
+                overlay: None,
+            }
+        }
+
+        /**
+         * Generates a Maze with the Sidewinder algorithm, one row at a time. The top row is carved
+         * straight across, since there's nowhere north to go. On every other row, each cell is
+         * added to the current run and either carves east to extend it, or closes it out by carving
+         * north from a random cell in the run. The last cell of a row always closes out its run.
+         */
+        fn gen_from_sidewinder(mut cells: Canvas, rng: &mut dyn RngCore) -> Self {
+            let dimensions: (usize, usize) = (cells.len(), cells[0].len());
+
+            let entrypoint: (usize, usize) = (1, 0);
+            cells.open(entrypoint);
+
+            let goalpoint: (usize, usize) = (dimensions.0 - 2, dimensions.1 - 1);
+            cells.open(goalpoint);
+
+            // the top row is one long corridor
+            for x in 1..dimensions.1 - 1 {
+                cells.open((1, x));
+            }
+
+            for y in (3..dimensions.0 - 1).step_by(2) {
+                if cells.cancelled() {
+                    break;
+                }
+                let mut run_start: usize = 1;
+                for x in (1..dimensions.1 - 1).step_by(2) {
+                    cells.open((y, x));
+
+                    let at_east_edge = x == dimensions.1 - 2;
+                    if !at_east_edge && rng.gen_bool(0.5) {
+                        // extend the run eastward
+                        cells.open((y, x + 1));
+                    } else {
+                        // close out the run by carving north from a random (odd) cell in it
+                        let north_index = run_start + 2 * gen_index(rng, 0..=(x - run_start) / 2);
+                        cells.open((y - 1, north_index));
+                        run_start = x + 2;
+                    }
+                }
+            }
+
+            Maze {
+                dimensions,
+                entrypoint,
+                goalpoint,
+                exits: vec![goalpoint],
+                algorithm: None,
+                cells: cells.cells,
+                stats: SolveStats::default(),
+                log: None,
+                overlay: None,
+            }
+        }
+
+        /**
+         * Generates a Maze with the binary tree algorithm: every cell carves a passage toward one
+         * of the two sides of the biased corner, chosen at random, unless it's already up against
+         * that side. It's about as fast as generation gets, with no bookkeeping besides the grid
+         * itself.
+         */
+        fn gen_from_binary_tree(mut cells: Canvas, bias: Bias, rng: &mut dyn RngCore) -> Self {
+            use Bias::*;
+            let dimensions: (usize, usize) = (cells.len(), cells[0].len());
+
+            let entrypoint: (usize, usize) = (1, 0);
+            cells.open(entrypoint);
+
+            let goalpoint: (usize, usize) = (dimensions.0 - 2, dimensions.1 - 1);
+            cells.open(goalpoint);
+
+            // the vertical and horizontal directions cells are allowed to carve in
+            let (dy, dx): (isize, isize) = match bias {
+                NorthEast => (-1, 1),
+                NorthWest => (-1, -1),
+                SouthEast => (1, 1),
+                SouthWest => (1, -1),
+            };
+            for y in (1..dimensions.0 - 1).step_by(2) {
+                if cells.cancelled() {
+                    break;
+                }
+                for x in (1..dimensions.1 - 1).step_by(2) {
+                    cells.open((y, x));
+
+                    // can't carve through the outer walls
+                    let two_neighbor: (isize, isize) = (y as isize + 2 * dy, x as isize + 2 * dx);
+                    let can_carve_vertically =
+                        two_neighbor.0 > 0 && two_neighbor.0 < dimensions.0 as isize - 1;
+                    let can_carve_horizontally =
+                        two_neighbor.1 > 0 && two_neighbor.1 < dimensions.1 as isize - 1;
+
+                    let carve_vertically = match (can_carve_vertically, can_carve_horizontally) {
+                        (true, true) => rng.gen_bool(0.5),
+                        (true, false) => true,
+                        (false, true) => false,
+                        // the biased corner itself has nowhere to go
+                        (false, false) => continue,
+                    };
+
+                    if carve_vertically {
+                        cells.open(((y as isize + dy) as usize, x));
+                    } else {
+                        cells.open((y, (x as isize + dx) as usize));
+                    }
+                }
+            }
+
+            Maze {
+                dimensions,
+                entrypoint,
+                goalpoint,
+                exits: vec![goalpoint],
+                algorithm: None,
+                cells: cells.cells,
+                stats: SolveStats::default(),
+                log: None,
+                overlay: None,
+            }
+        }
+
+        /**
+         * Generates a Maze with the growing tree algorithm. A list of active cells starts out with
+         * one random cell; the strategy picks a cell from the list, which either carves into a
+         * random unvisited two-neighbor (adding it to the list) or, if it has none, is removed from
+         * the list. This repeats until the list is empty.
+         */
+        fn gen_from_growing_tree(
+            mut cells: Canvas,
+            strategy: GrowStrategy,
+            rng: &mut dyn RngCore,
+        ) -> Self {
+            use GrowStrategy::*;
+            let dimensions: (usize, usize) = (cells.len(), cells[0].len());
+
+            let entrypoint: (usize, usize) = (1, 0);
+            cells.open(entrypoint);
+
+            let goalpoint: (usize, usize) = (dimensions.0 - 2, dimensions.1 - 1);
+            cells.open(goalpoint);
+
+            let two_offsets: [(isize, isize); 4] = [(-2, 0), (2, 0), (0, -2), (0, 2)];
+
+            // random starting point
+            let mut starter: (usize, usize) = (0, 0);
+            while starter.0.is_multiple_of(2) || starter.1.is_multiple_of(2) {
+                starter = (
+                    gen_index(rng, 1..(dimensions.0 - 1)),
+                    gen_index(rng, 1..(dimensions.1 - 1)),
+                );
+            }
+            cells.open(starter);
+            cells.visit(starter);
+
+            // the active cells, from oldest to newest
+            let mut active: Vec<(usize, usize)> = vec![starter];
+
+            while !active.is_empty() {
+                if cells.cancelled() {
+                    break;
+                }
+                let index = match strategy {
+                    Newest => active.len() - 1,
+                    Oldest => 0,
+                    Random => gen_index(rng, 0..active.len()),
+                    Mix(percent_newest) => {
+                        if rng.gen_range(0..100) < percent_newest {
+                            active.len() - 1
+                        } else {
+                            gen_index(rng, 0..active.len())
+                        }
+                    }
+                };
+                let current = active[index];
+
+                // find the chosen cell's unvisited two-neighbors within the allowable area
+                let mut two_neighbors: Vec<(usize, usize)> = vec![];
+                for &(dy, dx) in &two_offsets {
+                    let two_neighbor: (isize, isize) =
+                        (current.0 as isize + dy, current.1 as isize + dx);
+                    if two_neighbor.0 > 0
+                        && two_neighbor.0 < dimensions.0 as isize - 1
+                        && two_neighbor.1 > 0
+                        && two_neighbor.1 < dimensions.1 as isize - 1
+                        && !cells[two_neighbor.0 as usize][two_neighbor.1 as usize].visited
+                    {
+                        two_neighbors.push((two_neighbor.0 as usize, two_neighbor.1 as usize));
+                    }
+                }
+
+                // grow into one of them, or retire the cell if it's surrounded
+                match two_neighbors.choose(rng) {
+                    Some(&next) => {
+                        cells.open(((current.0 + next.0) / 2, (current.1 + next.1) / 2));
+                        cells.open(next);
+                        cells.visit(next);
+                        active.push(next);
+                    }
+                    None => {
+                        active.remove(index);
+                    }
+                }
+            }
+
+            // set each cell back to unvisited, so the Maze will print properly
+            cells.clear_visited();
+
+            Maze {
+                dimensions,
+                entrypoint,
+                goalpoint,
+                exits: vec![goalpoint],
+                algorithm: None,
+                cells: cells.cells,
+                stats: SolveStats::default(),
+                log: None,
+                overlay: None,
+            }
+        }
+
+        /**
+         * Generates a Maze with the recursive backtracker: a depth-first search from a random cell
+         * that carves into a random unvisited two-neighbor, and backs up whenever it gets stuck.
+         * Unlike gen_from_walk, the recursion is done with an explicit stack, so it can handle
+         * Mazes far too large for the call stack.
+         */
+        fn gen_from_backtracker(mut cells: Canvas, rng: &mut dyn RngCore) -> Self {
+            let dimensions: (usize, usize) = (cells.len(), cells[0].len());
+
+            let entrypoint: (usize, usize) = (1, 0);
+            cells.open(entrypoint);
+
+            let goalpoint: (usize, usize) = (dimensions.0 - 2, dimensions.1 - 1);
+            cells.open(goalpoint);
+
+            let two_offsets: [(isize, isize); 4] = [(-2, 0), (2, 0), (0, -2), (0, 2)];
+
+            // random starting point
+            let mut starter: (usize, usize) = (0, 0);
+            while starter.0.is_multiple_of(2) || starter.1.is_multiple_of(2) {
+                starter = (
+                    gen_index(rng, 1..(dimensions.0 - 1)),
+                    gen_index(rng, 1..(dimensions.1 - 1)),
+                );
+            }
+            cells.open(starter);
+            cells.visit(starter);
+
+            let mut stack: Vec<(usize, usize)> = vec![starter];
+            while let Some(&current) = stack.last() {
+                if cells.cancelled() {
+                    break;
+                }
+                // find the current cell's unvisited two-neighbors within the allowable area
+                let mut two_neighbors: Vec<(usize, usize)> = vec![];
+                for &(dy, dx) in &two_offsets {
+                    let two_neighbor: (isize, isize) =
+                        (current.0 as isize + dy, current.1 as isize + dx);
+                    if two_neighbor.0 > 0
+                        && two_neighbor.0 < dimensions.0 as isize - 1
+                        && two_neighbor.1 > 0
+                        && two_neighbor.1 < dimensions.1 as isize - 1
+                        && !cells[two_neighbor.0 as usize][two_neighbor.1 as usize].visited
+                    {
+                        two_neighbors.push((two_neighbor.0 as usize, two_neighbor.1 as usize));
+                    }
+                }
+
+                // carve forward into one of them, or backtrack if there aren't any
+                match two_neighbors.choose(rng) {
+                    Some(&next) => {
+                        cells.open(((current.0 + next.0) / 2, (current.1 + next.1) / 2));
+                        cells.open(next);
+                        cells.visit(next);
+                        stack.push(next);
+                    }
+                    None => {
+                        stack.pop();
+                    }
+                }
+            }
+
+            // set each cell back to unvisited, so the Maze will print properly
+            cells.clear_visited();
+
+            Maze {
+                dimensions,
+                entrypoint,
+                goalpoint,
+                exits: vec![goalpoint],
+                algorithm: None,
+                cells: cells.cells,
+                stats: SolveStats::default(),
+                log: None,
+                overlay: None,
+            }
+        }
+
+        /**
+         * Generates a Maze with the Origin Shift algorithm. The Maze is kept as a spanning tree of
+         * parent pointers, starting from a trivial tree where every cell points east (or south,
+         * along the east wall) toward the origin in the bottom-right corner. Each iteration, the
+         * origin is pointed at a random neighbor, which becomes the new origin. About ten times as
+         * many iterations as there are cells gives a well-mixed Maze.
+         */
+        fn gen_from_origin_shift(
+            mut cells: Canvas,
+            iterations: usize,
+            rng: &mut dyn RngCore,
+        ) -> Self {
+            let dimensions: (usize, usize) = (cells.len(), cells[0].len());
+
+            let entrypoint: (usize, usize) = (1, 0);
+            cells.open(entrypoint);
+
+            let goalpoint: (usize, usize) = (dimensions.0 - 2, dimensions.1 - 1);
+            cells.open(goalpoint);
+
+            let origin: (usize, usize) = (dimensions.0 - 2, dimensions.1 - 2);
+            let mut parents: Vec<Vec<Option<(usize, usize)>>> =
+                vec![vec![None; dimensions.1]; dimensions.0];
+            for y in (1..dimensions.0 - 1).step_by(2) {
+                for x in (1..dimensions.1 - 1).step_by(2) {
+                    parents[y][x] = if x < origin.1 {
+                        Some((y, x + 2))
+                    } else if y < origin.0 {
+                        Some((y + 2, x))
+                    } else {
+                        None
+                    };
+                }
+            }
+
+            Self::shift_tree(&mut cells, &mut parents, origin, iterations, rng);
+
+            Maze {
+                dimensions,
+                entrypoint,
+                goalpoint,
+                exits: vec![goalpoint],
+                algorithm: None,
+                cells: cells.cells,
+                stats: SolveStats::default(),
+                log: None,
+                overlay: None,
+            }
+        }
+
+        /**
+         * Moves the origin of the spanning tree given by parents the specified number of random
+         * steps, then carves the resulting tree into cells: the passage between two (odd, odd)
+         * cells is open exactly when one of them is the other's parent. The cells on the outer
+         * walls (including the entrypoint and goalpoint) are left alone.
+         */
+        fn shift_tree(
+            cells: &mut Canvas,
+            parents: &mut [Vec<Option<(usize, usize)>>],
+            mut origin: (usize, usize),
+            steps: usize,
+            rng: &mut dyn RngCore,
+        ) {
+            let dimensions: (usize, usize) = (cells.len(), cells[0].len());
+            let two_offsets: [(isize, isize); 4] = [(-2, 0), (2, 0), (0, -2), (0, 2)];
+
+            // a Maze with a single (odd, odd) cell has nowhere to shift the origin to
+            let mut step = 0;
+            while step < steps && (dimensions.0 > 3 || dimensions.1 > 3) {
+                let &(dy, dx) = two_offsets.choose(rng).unwrap();
+                let two_neighbor: (isize, isize) = (origin.0 as isize + dy, origin.1 as isize + dx);
+
+                // only shift to cells within the allowable area
+                if two_neighbor.0 > 0
+                    && two_neighbor.0 < dimensions.0 as isize - 1
+                    && two_neighbor.1 > 0
+                    && two_neighbor.1 < dimensions.1 as isize - 1
+                {
+                    let two_neighbor = (two_neighbor.0 as usize, two_neighbor.1 as usize);
+                    // the old origin points at the new one, which doesn't point anywhere
+                    parents[origin.0][origin.1] = Some(two_neighbor);
+                    parents[two_neighbor.0][two_neighbor.1] = None;
+                    origin = two_neighbor;
+                    step += 1;
+                }
+            }
+
+            // wall off every passage between (odd, odd) cells, then open up the ones in the tree
+            for y in 1..dimensions.0 - 1 {
+                for x in 1..dimensions.1 - 1 {
+                    cells.set_wall((y, x), y % 2 == 0 || x % 2 == 0);
+                }
+            }
+            for y in (1..dimensions.0 - 1).step_by(2) {
+                for x in (1..dimensions.1 - 1).step_by(2) {
+                    if let Some(parent) = parents[y][x] {
+                        cells.open(((y + parent.0) / 2, (x + parent.1) / 2));
+                    }
+                }
+            }
+        }
+
+        /**
+         * Generates a dungeon: up to room_attempts rectangular rooms, each between room_sizes.0 and
+         * room_sizes.1 cells on a side, are placed wherever they don't overlap, and the space left
+         * over is filled with maze corridors. Every room and corridor is then connected to the rest
+         * through a randomly chosen door, and the corridors' dead ends are filled back in.
+         * The first two rooms are placed in the top-left and bottom-right corners (if they fit), so
+         * the entrypoint and goalpoint open into different rooms. Since rooms are open areas, the
+         * result isn't a perfect Maze.
+         */
+        fn gen_from_dungeon(
+            mut cells: Canvas,
+            room_attempts: usize,
+            room_sizes: (usize, usize),
+            rng: &mut dyn RngCore,
+        ) -> Self {
+            let dimensions: (usize, usize) = (cells.len(), cells[0].len());
+
+            let entrypoint: (usize, usize) = (1, 0);
+            cells.open(entrypoint);
+
+            let goalpoint: (usize, usize) = (dimensions.0 - 2, dimensions.1 - 1);
+            cells.open(goalpoint);
+
+            let two_offsets: [(isize, isize); 4] = [(-2, 0), (2, 0), (0, -2), (0, 2)];
+
+            // rooms span from one (odd, odd) cell to another, so their sides have odd lengths
+            let min_room = room_sizes.0.min(room_sizes.1).max(1);
+            let max_room = room_sizes.0.max(room_sizes.1).max(1);
+            let odd_side = |side: usize| side - if side.is_multiple_of(2) { 1 } else { 0 };
+
+            // place the rooms as (top-left, bottom-right) corners
+            let mut rooms: Vec<((usize, usize), (usize, usize))> = vec![];
+            for attempt in 0..room_attempts {
+                let height = odd_side(gen_index(rng, min_room..=max_room).min(dimensions.0 - 2));
+                let width = odd_side(gen_index(rng, min_room..=max_room).min(dimensions.1 - 2));
+                let top_left: (usize, usize) = match attempt {
+                    0 => (1, 1),
+                    1 => (dimensions.0 - 1 - height, dimensions.1 - 1 - width),
+                    _ => (
+                        1 + 2 * gen_index(rng, 0..=(dimensions.0 - 2 - height) / 2),
+                        1 + 2 * gen_index(rng, 0..=(dimensions.1 - 2 - width) / 2),
+                    ),
+                };
+                let bottom_right = (top_left.0 + height - 1, top_left.1 + width - 1);
+
+                // rooms need at least one wall between them
+                let overlaps = rooms.iter().any(|&(other_top_left, other_bottom_right)| {
+                    top_left.0 <= other_bottom_right.0 + 1
+                        && other_top_left.0 <= bottom_right.0 + 1
+                        && top_left.1 <= other_bottom_right.1 + 1
+                        && other_top_left.1 <= bottom_right.1 + 1
+                });
+                if !overlaps {
+                    rooms.push((top_left, bottom_right));
+                }
+            }
+
+            // every open cell belongs to a region: one for each room, and one for each corridor
+            let mut regions: Vec<Vec<usize>> = vec![vec![usize::MAX; dimensions.1]; dimensions.0];
+            for (region, &(top_left, bottom_right)) in rooms.iter().enumerate() {
+                for (y, row) in regions
+                    .iter_mut()
+                    .enumerate()
+                    .take(bottom_right.0 + 1)
+                    .skip(top_left.0)
+                {
+                    for x in top_left.1..=bottom_right.1 {
+                        cells.open((y, x));
+                    }
+                    row[top_left.1..=bottom_right.1].fill(region);
+                }
+            }
+            let in_room =
+                |regions: &Vec<Vec<usize>>, (y, x): (usize, usize)| regions[y][x] < rooms.len();
+
+            // fill the space between the rooms with corridors, carving with a depth-first search
+            // from every (odd, odd) cell that hasn't been reached yet
+            let mut region_count = rooms.len();
+            for y in (1..dimensions.0 - 1).step_by(2) {
+                if cells.cancelled() {
+                    break;
+                }
+                for x in (1..dimensions.1 - 1).step_by(2) {
+                    if regions[y][x] != usize::MAX {
+                        continue;
+                    }
+                    cells.open((y, x));
+                    regions[y][x] = region_count;
+
+                    let mut stack: Vec<(usize, usize)> = vec![(y, x)];
+                    while let Some(&current) = stack.last() {
+                        let mut two_neighbors: Vec<(usize, usize)> = vec![];
+                        for &(dy, dx) in &two_offsets {
+                            let two_neighbor: (isize, isize) =
+                                (current.0 as isize + dy, current.1 as isize + dx);
+                            if two_neighbor.0 > 0
+                                && two_neighbor.0 < dimensions.0 as isize - 1
+                                && two_neighbor.1 > 0
+                                && two_neighbor.1 < dimensions.1 as isize - 1
+                                && regions[two_neighbor.0 as usize][two_neighbor.1 as usize]
+                                    == usize::MAX
+                            {
+                                two_neighbors
+                                    .push((two_neighbor.0 as usize, two_neighbor.1 as usize));
+                            }
+                        }
+
+                        match two_neighbors.choose(rng) {
+                            Some(&next) => {
+                                let between = ((current.0 + next.0) / 2, (current.1 + next.1) / 2);
+                                for (cy, cx) in [between, next] {
+                                    cells.open((cy, cx));
+                                    regions[cy][cx] = region_count;
+                                }
+                                stack.push(next);
+                            }
+                            None => {
+                                stack.pop();
+                            }
+                        }
+                    }
+                    region_count += 1;
+                }
+            }
+
+            // knock down one door between every pair of regions needed to connect them all
+            let separated = |(y, x): (usize, usize)| match (y % 2, x % 2) {
+                (0, 1) => Some(((y - 1, x), (y + 1, x))),
+                (1, 0) => Some(((y, x - 1), (y, x + 1))),
+                _ => None,
+            };
+            let mut doors: Vec<(usize, usize)> = vec![];
+            for y in 1..dimensions.0 - 1 {
+                for x in 1..dimensions.1 - 1 {
+                    if let Some((first, second)) = separated((y, x)) {
+                        if cells[y][x].wall
+                            && !cells[first.0][first.1].wall
+                            && !cells[second.0][second.1].wall
+                            && regions[first.0][first.1] != regions[second.0][second.1]
+                        {
+                            doors.push((y, x));
+                        }
+                    }
+                }
+            }
+            doors.shuffle(rng);
+            let mut sets = DisjointSet::new(region_count);
+            for (y, x) in doors {
+                let (first, second) = separated((y, x)).unwrap();
+                if sets.union(regions[first.0][first.1], regions[second.0][second.1]) {
+                    cells.open((y, x));
+                }
+            }
+
+            // fill the corridors' dead ends back in, since they don't lead to any rooms--except for
+            // the cells next to the entrypoint and goalpoint, which would get cut off
+            let protected = [
+                (entrypoint.0, entrypoint.1 + 1),
+                (goalpoint.0, goalpoint.1 - 1),
+            ];
+            let degree = |cells: &Canvas, (y, x): (usize, usize)| {
+                [(y - 1, x), (y, x + 1), (y + 1, x), (y, x - 1)]
+                    .iter()
+                    .filter(|&&(y, x)| !cells[y][x].wall)
+                    .count()
+            };
+            let mut dead_ends: Vec<(usize, usize)> = vec![];
+            for y in 1..dimensions.0 - 1 {
+                for x in 1..dimensions.1 - 1 {
+                    dead_ends.push((y, x));
+                }
+            }
+            while let Some(pos) = dead_ends.pop() {
+                if cells.cancelled() {
+                    break;
+                }
+                if cells[pos.0][pos.1].wall
+                    || in_room(&regions, pos)
+                    || protected.contains(&pos)
+                    || degree(&cells, pos) != 1
+                {
+                    continue;
+                }
+                cells.close(pos);
+                // the cell it led to might be a dead end now
+                for (dy, dx) in [(1, 0), (-1, 0), (0, 1), (0, -1)] {
+                    dead_ends.push((
+                        (pos.0 as isize + dy) as usize,
+                        (pos.1 as isize + dx) as usize,
+                    ));
+                }
+            }
+
+            Maze {
+                dimensions,
+                entrypoint,
+                goalpoint,
+                exits: vec![goalpoint],
+                algorithm: None,
+                cells: cells.cells,
+                stats: SolveStats::default(),
+                log: None,
+                overlay: None,
+            }
+        }
+
+        /**
+         * Generates a unicursal labyrinth: a single winding corridor with no junctions at all. A
+         * perfect Maze is generated at half resolution, then doubled--each of its cells becomes a
+         * 2x2 block of cells, joined so that the corridor follows the left-hand wall of every
+         * passage there and back again. That visits every cell in one closed circuit, which is cut
+         * open between the entrypoint and the goalpoint, just below it on the west side.
+         * Expects both dimensions to be one more than a multiple of four.
+         */
+        fn gen_from_unicursal(mut cells: Canvas, rng: &mut dyn RngCore) -> Self {
+            let dimensions: (usize, usize) = (cells.len(), cells[0].len());
+            let blocks: (usize, usize) = ((dimensions.0 - 1) / 4, (dimensions.1 - 1) / 4);
+
+            let entrypoint: (usize, usize) = (1, 0);
+            cells.open(entrypoint);
+
+            let goalpoint: (usize, usize) = (3, 0);
+            cells.open(goalpoint);
+
+            // the half-resolution Maze that the circuit will wind its way around
+            let half = Self::gen_from_backtracker(
+                Canvas {
+                    cells: vec![
+                        vec![
+                            Cell {
+                                wall: true,
+                                visited: false,
+                                tag: None,
+                                cost: 1
+                            };
+                            2 * blocks.1 + 1
+                        ];
+                        2 * blocks.0 + 1
+                    ],
+                    log: None,
+                    progress: None,
+                },
+                rng,
+            );
+
+            for i in 0..blocks.0 {
+                if cells.cancelled() {
+                    break;
+                }
+                for j in 0..blocks.1 {
+                    // is there a passage from this block to the neighboring block in this
+                    // direction?
+                    let passage = |dy: isize, dx: isize| {
+                        let neighbor = (i as isize + dy, j as isize + dx);
+                        neighbor.0 >= 0
+                            && neighbor.0 < blocks.0 as isize
+                            && neighbor.1 >= 0
+                            && neighbor.1 < blocks.1 as isize
+                            && !half.cells[(2 * i as isize + 1 + dy) as usize]
+                                [(2 * j as isize + 1 + dx) as usize]
+                                .wall
+                    };
+                    let (y, x) = (4 * i, 4 * j);
+
+                    for (dy, dx) in [(1, 1), (1, 3), (3, 1), (3, 3)] {
+                        cells.open((y + dy, x + dx));
+                    }
+
+                    // each side of the block either runs alongside its wall, or follows a passage
+                    // out to the neighboring block and back
+                    let mut open: Vec<(usize, usize)> = vec![];
+                    if passage(-1, 0) {
+                        open.extend([(y, x + 1), (y, x + 3)]);
+                    } else {
+                        open.push((y + 1, x + 2));
+                    }
+                    if passage(1, 0) {
+                        open.extend([(y + 4, x + 1), (y + 4, x + 3)]);
+                    } else {
+                        open.push((y + 3, x + 2));
+                    }
+                    if passage(0, -1) {
+                        open.extend([(y + 1, x), (y + 3, x)]);
+                    } else {
+                        open.push((y + 2, x + 1));
+                    }
+                    if passage(0, 1) {
+                        open.extend([(y + 1, x + 4), (y + 3, x + 4)]);
+                    } else {
+                        open.push((y + 2, x + 3));
+                    }
+                    for (y, x) in open {
+                        cells.open((y, x));
+                    }
+                }
+            }
+
+            // cut the circuit open, between the entrypoint and the goalpoint
+            cells.close((2, 1));
+
+            Maze {
+                dimensions,
+                entrypoint,
+                goalpoint,
+                exits: vec![goalpoint],
+                algorithm: None,
+                cells: cells.cells,
+                stats: SolveStats::default(),
+                log: None,
+                overlay: None,
+            }
+        }
+
+        /**
+         * Generates a cave with a cellular automaton. Every inner cell starts out as a wall with
+         * fill_probability, then the grid is smoothed iterations times with the 4-5 rule: a wall
+         * stays a wall with at least four walls among its eight neighbors, and an open cell fills
+         * in with at least five. Smoothing leaves pockets cut off from the rest of the cave, so
+         * afterwards a tunnel is dug from the entrypoint's cave to the goalpoint if need be, and
+         * anything still unreachable is filled in.
+         * Unlike the other algorithms, caves have open areas and loops, so only recursive
+         * backtracking can solve them.
+         */
+        fn gen_from_cellular(
+            mut cells: Canvas,
+            fill_probability: f64,
+            iterations: usize,
+            rng: &mut dyn RngCore,
+        ) -> Self {
+            let dimensions: (usize, usize) = (cells.len(), cells[0].len());
+            let inner =
+                |y: usize, x: usize| y > 0 && y < dimensions.0 - 1 && x > 0 && x < dimensions.1 - 1;
+
+            let entrypoint: (usize, usize) = (1, 0);
+            let goalpoint: (usize, usize) = (dimensions.0 - 2, dimensions.1 - 1);
+            // the cells just inside the entrypoint and goalpoint
+            let start: (usize, usize) = (entrypoint.0, entrypoint.1 + 1);
+            let end: (usize, usize) = (goalpoint.0, goalpoint.1 - 1);
+
+            // seed the inner cells at random
+            let fill_probability = probability(fill_probability);
+            for y in 0..dimensions.0 {
+                for x in 0..dimensions.1 {
+                    cells.set_wall((y, x), !inner(y, x) || rng.gen_bool(fill_probability));
+                }
+            }
+
+            // smooth the noise into caves
+            for _ in 0..iterations {
+                if cells.cancelled() {
+                    break;
+                }
+                let mut smoothed = cells.clone();
+                for (y, row) in smoothed.iter_mut().enumerate() {
+                    for (x, cell) in row.iter_mut().enumerate() {
+                        if !inner(y, x) {
+                            continue;
+                        }
+                        let mut walls = 0;
+                        for row in &cells[y - 1..=y + 1] {
+                            for neighbor in &row[x - 1..=x + 1] {
+                                if neighbor.wall {
+                                    walls += 1;
+                                }
+                            }
+                        }
+                        // counting the cell itself, a wall with four wall neighbors and an open
+                        // cell with five both come to five
+                        cell.wall = walls >= 5;
+                    }
+                }
+                cells.replace(smoothed);
+            }
+            cells.open(start);
+            cells.open(end);
+
+            // dig a tunnel from the entrypoint's cave to the goalpoint if they aren't connected,
+            // taking the shortest route through whatever's in the way
+            let directions: [(isize, isize); 4] = [(1, 0), (-1, 0), (0, 1), (0, -1)];
+            let reachable = Self::flood(&cells, start);
+            if !reachable[end.0][end.1] {
+                let mut parents: Vec<Vec<Option<(usize, usize)>>> =
+                    vec![vec![None; dimensions.1]; dimensions.0];
+                let mut queue: VecDeque<(usize, usize)> = VecDeque::new();
+                for (y, row) in reachable.iter().enumerate() {
+                    for (x, &reached) in row.iter().enumerate() {
+                        if reached {
+                            parents[y][x] = Some((y, x));
+                            queue.push_back((y, x));
+                        }
+                    }
+                }
+                while let Some(current) = queue.pop_front() {
+                    if current == end {
+                        break;
+                    }
+                    for &(dy, dx) in &directions {
+                        let next = (
+                            (current.0 as isize + dy) as usize,
+                            (current.1 as isize + dx) as usize,
+                        );
+                        if inner(next.0, next.1) && parents[next.0][next.1].is_none() {
+                            parents[next.0][next.1] = Some(current);
+                            queue.push_back(next);
+                        }
+                    }
+                }
+                let mut current = end;
+                while let Some(parent) = parents[current.0][current.1] {
+                    cells.open(current);
+                    if parent == current {
+                        break;
+                    }
+                    current = parent;
+                }
+            }
+
+            // fill in whatever pockets are left over
+            let reachable = Self::flood(&cells, start);
+            for (y, row) in reachable.iter().enumerate() {
+                for (x, &reached) in row.iter().enumerate() {
+                    if !reached {
+                        cells.close((y, x));
+                    }
+                }
+            }
+
+            cells.open(entrypoint);
+            cells.open(goalpoint);
+
+            Maze {
+                dimensions,
+                entrypoint,
+                goalpoint,
+                exits: vec![goalpoint],
+                algorithm: None,
+                cells: cells.cells,
+                stats: SolveStats::default(),
+                log: None,
+                overlay: None,
+            }
+        }
+
+        /**
+         * Generates a Maze by tessellation. Starting from a single cell, the Maze built so far is
+         * copied into each quadrant of one twice its size, and three of the four walls between
+         * the copies get a hole knocked through them, which keeps the result perfect. Once one
+         * dimension is big enough, the other keeps doubling on its own, with the two copies
+         * joined through a single hole. Nearly all of the work is copying rows of cells, so it's
+         * by far the fastest way to make an enormous Maze--but the copies make it repetitive.
+         * Expects both dimensions to be one more than a power of two.
+         */
+        fn gen_from_fractal(mut cells: Canvas, rng: &mut dyn RngCore) -> Self {
+            let dimensions: (usize, usize) = (cells.len(), cells[0].len());
+
+            // the Maze built so far is size (odd, odd) cells across, in the top-left corner
+            let mut size: (usize, usize) = (1, 1);
+            cells.open((1, 1));
+            while 2 * size.0 + 1 < dimensions.0 || 2 * size.1 + 1 < dimensions.1 {
+                if cells.cancelled() {
+                    break;
+                }
+                let grow = (2 * size.0 + 1 < dimensions.0, 2 * size.1 + 1 < dimensions.1);
+                let (height, width) = (2 * size.0, 2 * size.1);
+
+                // copy the Maze to the right of itself, then copy both of them below
+                if grow.1 {
+                    for y in 0..height {
+                        for x in 0..width {
+                            cells.set_wall((y, width + x), cells[y][x].wall);
+                        }
+                    }
+                }
+                let copied = if grow.1 { 2 * width } else { width };
+                if grow.0 {
+                    for y in 0..height {
+                        for x in 0..copied {
+                            cells.set_wall((height + y, x), cells[y][x].wall);
+                        }
+                    }
+                }
+
+                // where the walls between the copies start: the seam row to the left and right
+                // of the center, and the seam column above and below it
+                let mut seams: Vec<((usize, usize), bool)> = vec![];
+                if grow.0 {
+                    seams.push(((height, 1), false));
+                    if grow.1 {
+                        seams.push(((height, width + 1), false));
+                    }
+                }
+                if grow.1 {
+                    seams.push(((1, width), true));
+                    if grow.0 {
+                        seams.push(((height + 1, width), true));
+                    }
+                }
+                // with four copies, leaving one seam whole keeps them from forming a loop
+                seams.shuffle(rng);
+                seams.truncate(seams.len().max(2) - 1);
+                for ((y, x), vertical) in seams {
+                    // holes go between (odd, odd) cells, so pick one of the odd spots along the
+                    // seam
+                    if vertical {
+                        cells.open((y + 2 * gen_index(rng, 0..size.0), x));
+                    } else {
+                        cells.open((y, x + 2 * gen_index(rng, 0..size.1)));
+                    }
+                }
+
+                if grow.0 {
+                    size.0 *= 2;
+                }
+                if grow.1 {
+                    size.1 *= 2;
+                }
+            }
+
+            // the openings go in last, so they aren't copied along with everything else
+            let entrypoint: (usize, usize) = (1, 0);
+            cells.open(entrypoint);
+
+            let goalpoint: (usize, usize) = (dimensions.0 - 2, dimensions.1 - 1);
+            cells.open(goalpoint);
+
+            Maze {
+                dimensions,
+                entrypoint,
+                goalpoint,
+                exits: vec![goalpoint],
+                algorithm: None,
+                cells: cells.cells,
+                stats: SolveStats::default(),
+                log: None,
+                overlay: None,
+            }
+        }
+
+        /**
+         * Returns which cells can be reached from the given position without passing through a
+         * wall.
+         */
+        fn flood(cells: &[Vec<Cell>], from: (usize, usize)) -> Vec<Vec<bool>> {
+            let mut reached = vec![vec![false; cells[0].len()]; cells.len()];
+            let mut stack: Vec<(usize, usize)> = vec![from];
+            reached[from.0][from.1] = true;
+            while let Some(current) = stack.pop() {
+                for (dy, dx) in [(1, 0), (-1, 0), (0, 1), (0, -1)] {
+                    let (y, x) = (current.0 as isize + dy, current.1 as isize + dx);
+                    if y < 0 || x < 0 || y as usize >= cells.len() || x as usize >= cells[0].len() {
+                        continue;
+                    }
+                    let (y, x) = (y as usize, x as usize);
+                    if !cells[y][x].wall && !reached[y][x] {
+                        reached[y][x] = true;
+                        stack.push((y, x));
+                    }
+                }
+            }
+            reached
+        }
+
+        /**
+         * Returns what stepping into each cell costs, as set_cost gave it.
+         */
+        fn step_costs(&self) -> Vec<Vec<usize>> {
+            self.cells
+                .iter()
+                .map(|row| row.iter().map(|cell| cell.cost as usize).collect())
+                .collect()
+        }
+
+        /**
+         * Returns what the cheapest step into a passage costs.
+         */
+        fn cheapest_step(&self) -> u32 {
+            self.cells
+                .iter()
+                .flatten()
+                .filter(|cell| !cell.wall)
+                .map(|cell| cell.cost)
+                .min()
+                .unwrap_or(1)
+        }
+    }
+
+    impl<'a> SolveRun<'a> {
+        /**
+         * Starts a solve of the given Maze toward its goalpoint, with nothing marked yet, counting
+         * into stats.
+         */
+        fn new(maze: &'a Maze, stats: SolveStats) -> Self {
+            SolveRun {
+                maze,
+                goalpoint: maze.goalpoint,
+                overlay: None,
+                stats,
+            }
+        }
+
+        /**
+         * Hands back the exit the solve ended up at, what it marked, and what it counted, for the
+         * Maze to keep.
+         */
+        fn finish(self) -> ((usize, usize), Option<SolutionOverlay>, SolveStats) {
+            (self.goalpoint, self.overlay, self.stats)
+        }
+
+        /**
+         * Returns whether the given (y, x) cell has been marked by this solve.
+         */
+        fn is_marked(&self, pos: (usize, usize)) -> bool {
+            self.overlay
+                .as_ref()
+                .is_some_and(|overlay| overlay.contains(pos))
+        }
+
+        /**
+         * Marks the given (y, x) cell, starting an overlay if there isn't one yet.
+         */
+        fn mark(&mut self, pos: (usize, usize)) {
+            let dimensions = self.maze.dimensions;
+            self.overlay
+                .get_or_insert_with(|| SolutionOverlay::new(dimensions))
+                .set(pos, true);
+        }
+
+        /**
+         * Returns the shortest path from the entrypoint to the goalpoint through the cells this
+         * solve has marked, like Maze::marked_path.
+         */
+        fn marked_path(&self) -> Option<Path> {
+            self.maze
+                .path_through(self.overlay.as_ref(), self.goalpoint)
+        }
+
+        /**
+         * Counts the given cell as looked at by the solve in progress, and records it if the solve
+         * is being traced.
+         */
+        fn explore(&mut self, pos: (usize, usize)) {
+            self.stats.explored += 1;
+            if let Some(trace) = &mut self.stats.trace {
+                trace.push(pos);
+            }
+            self.record(SolveEvent::Visited(pos));
+        }
+
+        /**
+         * Records what the solve in progress just did, if it's being watched by solve_stepwise.
+         */
+        fn record(&mut self, event: SolveEvent) {
+            if let Some(events) = &mut self.stats.events {
+                events.push(event);
+            }
+        }
+
+        /**
+         * Returns whether a solver walking through this Maze can stop at the given cell: the
+         * goalpoint, or any exit while solve_from lets walkers stop at the first one they reach.
+         */
+        fn at_exit(&self, pos: (usize, usize)) -> bool {
+            pos == self.goalpoint
+                || self
+                    .stats
+                    .exits
+                    .as_ref()
+                    .is_some_and(|exits| exits.contains(&pos))
+        }
+
+        /**
+         * Clears the given (y, x) cell, if this solve has marked it.
+         */
+        fn unmark(&mut self, pos: (usize, usize)) {
+            if let Some(overlay) = &mut self.overlay {
+                overlay.set(pos, false);
+            }
+        }
+
+        /**
+         * Returns whether the cells this solve has marked form a single, unbranching path from the
+         * entrypoint to the goalpoint.
+         */
+        fn marks_single_path(&self) -> bool {
+            let directions: [(isize, isize); 4] = [(1, 0), (-1, 0), (0, 1), (0, -1)];
+            let marked = |pos: (isize, isize)| {
+                pos.0 >= 0
+                    && pos.0 < self.maze.dimensions.0 as isize
+                    && pos.1 >= 0
+                    && pos.1 < self.maze.dimensions.1 as isize
+                    && !self.maze.cells[pos.0 as usize][pos.1 as usize].wall
+                    && self.is_marked((pos.0 as usize, pos.1 as usize))
+            };
+
+            // walk the path from the entrypoint, which must never fork
+            let mut previous: Option<(usize, usize)> = None;
+            let mut current = self.maze.entrypoint;
+            let mut length = 1;
+            while current != self.goalpoint {
+                let mut next_steps = directions.iter().filter_map(|&(dy, dx)| {
+                    let next = (current.0 as isize + dy, current.1 as isize + dx);
+                    let next_usize = (next.0 as usize, next.1 as usize);
+                    (marked(next) && Some(next_usize) != previous).then_some(next_usize)
+                });
+                match (next_steps.next(), next_steps.next()) {
+                    (Some(next), None) => {
+                        previous = Some(current);
+                        current = next;
+                        length += 1;
+                    }
+                    _ => return false,
+                }
+            }
+
+            // and nothing else can be marked besides the path
+            let marked_count = self
+                .overlay
+                .iter()
+                .flat_map(|overlay| overlay.cells())
+                .filter(|&(y, x)| !self.maze.cells[y][x].wall)
+                .count();
+            length == marked_count
+        }
+
+        /**
+         * Marks the trail left by a search, from the given cell back to where the search started,
+         * which is its own parent.
+         */
+        fn mark_trail(&mut self, parents: &[Vec<Option<(usize, usize)>>], from: (usize, usize)) {
+            let mut pos = from;
+            self.mark((pos.0, pos.1));
+            while let Some(parent) = parents[pos.0][pos.1].filter(|&parent| parent != pos) {
+                pos = parent;
+                self.mark((pos.0, pos.1));
+            }
+        }
+
+        /**
+         * Solves this Maze via recursive depth-first search. Returns true as long as the Maze has
+         * been successfully solved--false otherwise, though this should never occur.
+         * Explored remembers every Cell searched so far, even ones unmarked while backing out of a
+         * dead end; without it, open rooms and loops would be searched over and over again.
+         */
+        fn solve_from_backtracking(
+            &mut self,
+            pos: (usize, usize),
+            explored: &mut [Vec<bool>],
+        ) -> bool {
+            // base case: if we're on a wall, or a path we've been before, we can't be going towards
+            // the solution
+            if self.maze.cells[pos.0][pos.1].wall || explored[pos.0][pos.1] {
+                return false;
+            }
+
+            // mark current cell as visited
+            self.mark((pos.0, pos.1));
+            explored[pos.0][pos.1] = true;
+            self.explore(pos);
+
+            // base case: if we're at the goalpoint, no more work needs to be done
+            if self.at_exit(pos) {
+                self.goalpoint = pos;
+                return true;
+            }
+
+            // recursive case: try going each direction
+            // this function's return type--bool--stops future recursion branches as it unwinds; we
+            // take advantage of short-circuit boolean evaluation
+            ({
+                // South
+                pos.0 + 1 < self.maze.dimensions.0
+                    && self.solve_from_backtracking((pos.0 + 1, pos.1), explored)
+            }) || ({
+                // East
+                pos.1 + 1 < self.maze.dimensions.1
+                    && self.solve_from_backtracking((pos.0, pos.1 + 1), explored)
+            }) || ({
+                // North
+                pos.0 > 0 && self.solve_from_backtracking((pos.0 - 1, pos.1), explored)
+            }) || ({
+                // West
+                pos.1 > 0 && self.solve_from_backtracking((pos.0, pos.1 - 1), explored)
+            }) || ({
+                // This cell isn't on the path to the solution, since going each direction results
+                // in a dead end
+                self.unmark((pos.0, pos.1));
+                self.record(SolveEvent::Backtracked(pos));
+                false
+            })
+        }
+
+        /**
+         * Solves this Maze via the same depth-first search as recursive backtracking, trying South,
+         * East, North, and West in turn, so it marks exactly the same path. The search keeps its
+         * own stack of the cells on the current path (and which way to try next from each) rather
+         * than recursing, so it can't overflow the call stack on an enormous Maze.
+         */
+        fn solve_from_iterative_backtracking(&mut self) -> bool {
+            let directions: [(isize, isize); 4] = [(1, 0), (0, 1), (-1, 0), (0, -1)];
+            let mut explored = vec![vec![false; self.maze.dimensions.1]; self.maze.dimensions.0];
+            let mut stack: Vec<((usize, usize), usize)> = vec![];
+
+            let mut next = Some(self.maze.entrypoint);
+            loop {
+                // step into the next cell, unless it's a wall or a path we've been before
+                if let Some(pos) = next.take() {
+                    if !self.maze.cells[pos.0][pos.1].wall && !explored[pos.0][pos.1] {
+                        self.mark((pos.0, pos.1));
+                        explored[pos.0][pos.1] = true;
+                        self.explore(pos);
+                        if self.at_exit(pos) {
+                            self.goalpoint = pos;
+                            return true;
+                        }
+                        stack.push((pos, 0));
+                    }
+                }
+
+                let Some((pos, tried)) = stack.last_mut() else {
+                    return false;
+                };
+                if *tried == directions.len() {
+                    // This cell isn't on the path to the solution, since going each direction
+                    // results in a dead end
+                    self.unmark((pos.0, pos.1));
+                    self.record(SolveEvent::Backtracked(*pos));
+                    stack.pop();
+                    continue;
+                }
+                let (dy, dx) = directions[*tried];
+                *tried += 1;
+                let (y, x) = (pos.0 as isize + dy, pos.1 as isize + dx);
+                if y >= 0
+                    && x >= 0
+                    && (y as usize) < self.maze.dimensions.0
+                    && (x as usize) < self.maze.dimensions.1
+                {
+                    next = Some((y as usize, x as usize));
+                }
+            }
+        }
+
+        /**
+         * Solves this Maze iteratively by filling in the dead-ends, which leaves only the correct
+         * path. That isn't the case when the Maze has loops, so then the shortest path through the
+         * cells left unfilled is found by breadth-first search and marked instead.
+         */
+        fn solve_from_dead_end_filling(&mut self) -> bool {
+            self.mark((self.maze.entrypoint.0, self.maze.entrypoint.1));
+            self.mark((self.goalpoint.0, self.goalpoint.1));
+
+            let directions: [(isize, isize); 4] = [(1, 0), (-1, 0), (0, 1), (0, -1)];
+
+            // find the dead-ends
+            let mut dead_ends: Vec<(usize, usize)> = vec![];
+            let mut filled = 0;
+            // the edges are included, since the openings needn't be the only gaps in them
+            for i in 0..self.maze.cells.len() {
+                for j in 0..self.maze.cells[0].len() {
+                    // if the cell isn't a wall, check each neighboring cell to see if it's a dead
+                    // end--if it is, it's marked unvisited and added to the list; otherwise, it's
+                    // marked visited
+                    if !self.maze.cells[i][j].wall
+                        && (i, j) != self.maze.entrypoint
+                        && (i, j) != self.goalpoint
+                    {
+                        self.explore((i, j));
+                        if self.maze.degree((i, j)) != 1 {
+                            self.mark((i, j));
+                        }
+
+                        if !self.is_marked((i, j)) {
+                            dead_ends.push((i, j));
+                            filled += 1;
+                            self.record(SolveEvent::Filled((i, j)));
+                        }
+                    }
+                }
+            }
+            // pick a dead end out of the list, and find the cell in the Maze that connects to it
+            while let Some(dead_end) = dead_ends.pop() {
+                let connector = directions.iter().find_map(|&(dy, dx)| {
+                    let neighbor = (
+                        (dead_end.0 as isize + dy) as usize,
+                        (dead_end.1 as isize + dx) as usize,
+                    );
+
+                    if self.maze.is_passage(neighbor) && self.is_marked((neighbor.0, neighbor.1)) {
+                        Some(neighbor)
+                    } else {
+                        None
+                    }
+                });
+                // only a piece of the Maze cut off from everything else has no connector
+                let Some(connector) = connector else {
+                    continue;
+                };
+
+                // if there are two or more ways to go from connector (excluding dead_end), we've
+                // finished closing in a dead end; otherwise, we need to add connector as a new dead
+                // end
+                let mut paths_out_of_connector = 0;
+
+                for &(dy, dx) in &directions {
+                    let neighbor: (usize, usize) = (
+                        (connector.0 as isize + dx) as usize,
+                        (connector.1 as isize + dy) as usize,
+                    );
+
+                    if self.maze.is_passage(neighbor) && self.is_marked((neighbor.0, neighbor.1)) {
+                        paths_out_of_connector += 1;
+                    }
+                }
+
+                // if we're still at a dead end, make the connector a new dead end--unless it's
+                // one of the openings, which are never filled in
+                if paths_out_of_connector == 1
+                    && connector != self.maze.entrypoint
+                    && connector != self.goalpoint
+                {
+                    self.unmark((connector.0, connector.1));
+                    dead_ends.push(connector);
+                    filled += 1;
+                    self.record(SolveEvent::Filled(connector));
+                }
+            }
+
+            self.stats.filled = Some(filled);
+
+            // on a Maze with loops, filling in the dead ends leaves more than just the one path,
+            // so pick the shortest one out of what's left
+            if self.marks_single_path() {
+                return true;
+            }
+            let Some(path) = self.marked_path() else {
+                return false;
+            };
+            self.stats.completed_by_bfs = true;
+            self.overlay = None;
+            for &(y, x) in path.cells() {
+                self.mark((y, x));
+            }
+            true
+        }
+
+        /**
+         * Solves this Maze via breadth-first search, which spreads out from the entrypoint one step
+         * at a time, remembering where it reached each cell from. The first time it reaches the
+         * goalpoint is by a shortest path, which is traced back and marked--so unlike the other
+         * algorithms, it finds the shortest solution even in a Maze with loops.
+         */
+        fn solve_from_breadth_first(&mut self) -> bool {
+            let mut parents: Vec<Vec<Option<(usize, usize)>>> =
+                vec![vec![None; self.maze.dimensions.1]; self.maze.dimensions.0];
+            let mut queue: VecDeque<(usize, usize)> = VecDeque::from([self.maze.entrypoint]);
+            parents[self.maze.entrypoint.0][self.maze.entrypoint.1] = Some(self.maze.entrypoint);
+
+            while let Some(current) = queue.pop_front() {
+                self.explore(current);
+                if current == self.goalpoint {
+                    self.mark_trail(&parents, current);
+                    return true;
+                }
+
+                // South, East, North, and West, in the same order as recursive backtracking
+                for (dy, dx) in [(1, 0), (0, 1), (-1, 0), (0, -1)] {
+                    let (y, x) = (current.0 as isize + dy, current.1 as isize + dx);
+                    if y < 0
+                        || x < 0
+                        || y as usize >= self.maze.dimensions.0
+                        || x as usize >= self.maze.dimensions.1
+                    {
+                        continue;
+                    }
+                    let (y, x) = (y as usize, x as usize);
+                    if !self.maze.cells[y][x].wall && parents[y][x].is_none() {
+                        parents[y][x] = Some(current);
+                        queue.push_back((y, x));
+                    }
+                }
+            }
+            false
+        }
+
+        /**
+         * Solves this Maze via a best-first search, which always explores next whichever cell looks
+         * closest to the goalpoint. For A* search (when greedy is false), that's the cell with the
+         * cheapest known path to it (going by set_cost) plus the estimate of what's left to go. As
+         * long as that never overestimates, the path found is still a cheapest one, but far fewer
+         * cells get explored than by breadth-first search. Greedy best-first search only goes by
+         * the distance left, so it heads straight for the goalpoint--exploring fewer cells still,
+         * but sticking with the first path it finds to each cell, however long. Ties go to the cell
+         * closest to the goalpoint, then the topmost and leftmost, so the path is always the same.
+         */
+        fn solve_from_best_first(
+            &mut self,
+            greedy: bool,
+            distance_left: &dyn Fn((usize, usize)) -> f64,
+        ) -> bool {
+            // estimates are never negative, and the bits of non-negative floats sort the same way
+            // the floats do, so they can go in the open set as they are
+            let heuristic = |pos: (usize, usize)| distance_left(pos).to_bits();
+
+            let mut parents: Vec<Vec<Option<(usize, usize)>>> =
+                vec![vec![None; self.maze.dimensions.1]; self.maze.dimensions.0];
+            let mut costs: Vec<Vec<usize>> =
+                vec![vec![usize::MAX; self.maze.dimensions.1]; self.maze.dimensions.0];
+            let mut closed = vec![vec![false; self.maze.dimensions.1]; self.maze.dimensions.0];
+            // the open set, as (estimated total length, distance left, cell)
+            let estimate = |cost: usize, pos: (usize, usize)| {
+                if greedy {
+                    heuristic(pos)
+                } else {
+                    (cost as f64 + distance_left(pos)).to_bits()
+                }
+            };
+            let mut open = BinaryHeap::from([Reverse((
+                estimate(0, self.maze.entrypoint),
+                heuristic(self.maze.entrypoint),
+                self.maze.entrypoint,
+            ))]);
+            parents[self.maze.entrypoint.0][self.maze.entrypoint.1] = Some(self.maze.entrypoint);
+            costs[self.maze.entrypoint.0][self.maze.entrypoint.1] = 0;
+
+            while let Some(Reverse((_, _, current))) = open.pop() {
+                // a cell can be pushed again after a shorter path to it turns up
+                if closed[current.0][current.1] {
+                    continue;
+                }
+                closed[current.0][current.1] = true;
+                self.explore(current);
+
+                if current == self.goalpoint {
+                    self.mark_trail(&parents, current);
+                    return true;
+                }
+
+                for (dy, dx) in [(1, 0), (0, 1), (-1, 0), (0, -1)] {
+                    let (y, x) = (current.0 as isize + dy, current.1 as isize + dx);
+                    if y < 0
+                        || x < 0
+                        || y as usize >= self.maze.dimensions.0
+                        || x as usize >= self.maze.dimensions.1
+                    {
+                        continue;
+                    }
+                    let (y, x) = (y as usize, x as usize);
+                    let cost = costs[current.0][current.1]
+                        .saturating_add(self.maze.cells[y][x].cost as usize);
+                    let better = if greedy {
+                        costs[y][x] == usize::MAX
+                    } else {
+                        cost < costs[y][x]
+                    };
+                    if !self.maze.cells[y][x].wall && better {
+                        costs[y][x] = cost;
+                        parents[y][x] = Some(current);
+                        open.push(Reverse((estimate(cost, (y, x)), heuristic((y, x)), (y, x))));
+                    }
+                }
+            }
+            false
+        }
+
+        /**
+         * Solves this Maze via IDA* (iterative deepening A*) search, with the Manhattan distance
+         * as its estimate: a depth-first search that gives up on any cell whose estimated path
+         * length is over a threshold, starting over with the threshold raised to the smallest
+         * estimate it gave up on until the goalpoint is reached. Only the path being followed is
+         * remembered, rather than every cell searched, so it takes far less memory than A*,
+         * though cells are explored over and over again--and in a Maze with loops, every way
+         * around them is followed. The path is as short as A*'s, and how many passes it took is
+         * kept in stats.
+         */
+        fn solve_from_ida_star(&mut self) -> bool {
+            let goalpoint = self.goalpoint;
+            let estimate =
+                |pos: (usize, usize)| pos.0.abs_diff(goalpoint.0) + pos.1.abs_diff(goalpoint.1);
+            let mut threshold = estimate(self.maze.entrypoint);
+            let mut iterations = 0;
+
+            loop {
+                iterations += 1;
+                self.stats.iterations = Some(iterations);
+                // each cell on the path, with how many of its neighbors have been tried
+                let mut path: Vec<((usize, usize), usize)> = vec![(self.maze.entrypoint, 0)];
+                let mut on_path: HashSet<(usize, usize)> = HashSet::from([self.maze.entrypoint]);
+                self.explore(self.maze.entrypoint);
+                let mut next_threshold: Option<usize> = None;
+
+                while let Some(&(pos, tried)) = path.last() {
+                    if pos == goalpoint {
+                        for &((y, x), _) in &path {
+                            self.mark((y, x));
+                        }
+                        return true;
+                    }
+                    if tried == 4 {
+                        on_path.remove(&pos);
+                        path.pop();
+                        continue;
+                    }
+                    path.last_mut().expect("the path isn't empty").1 += 1;
+
+                    let (dy, dx) = [(1, 0), (0, 1), (-1, 0), (0, -1)][tried];
+                    let next = (
+                        (pos.0 as isize + dy) as usize,
+                        (pos.1 as isize + dx) as usize,
+                    );
+                    if !self.maze.is_passage(next) || on_path.contains(&next) {
+                        continue;
+                    }
+                    // the path to next is as long as the path so far
+                    let estimated = path.len() + estimate(next);
+                    if estimated > threshold {
+                        next_threshold =
+                            Some(next_threshold.map_or(estimated, |next| next.min(estimated)));
+                        continue;
+                    }
+                    self.explore(next);
+                    on_path.insert(next);
+                    path.push((next, 0));
+                }
+
+                match next_threshold {
+                    Some(next) => threshold = next,
+                    // nothing was given up on, so there's nowhere left to look
+                    None => return false,
+                }
+            }
+        }
+
+        /**
+         * Solves this Maze via jump point search, which is A* search that skips over the cells of
+         * a straight run instead of adding each one to the open set: from each cell it jumps ahead
+         * (and to either side) until something forces a turn, and only that cell is explored. The
+         * path is just as short as A*'s, but in open or braided Mazes far fewer cells are explored.
+         */
+        fn solve_from_jump_point(&mut self) -> bool {
+            let goalpoint = self.goalpoint;
+            let heuristic =
+                |(y, x): (usize, usize)| y.abs_diff(goalpoint.0) + x.abs_diff(goalpoint.1);
+
+            // each cell's parent is the jump point it was jumped to from, in a straight line
+            let mut parents: Vec<Vec<Option<(usize, usize)>>> =
+                vec![vec![None; self.maze.dimensions.1]; self.maze.dimensions.0];
+            let mut costs: Vec<Vec<usize>> =
+                vec![vec![usize::MAX; self.maze.dimensions.1]; self.maze.dimensions.0];
+            let mut closed = vec![vec![false; self.maze.dimensions.1]; self.maze.dimensions.0];
+            // the open set, as (estimated total length, distance left, cell)
+            let mut open = BinaryHeap::from([Reverse((
+                heuristic(self.maze.entrypoint),
+                heuristic(self.maze.entrypoint),
+                self.maze.entrypoint,
+            ))]);
+            parents[self.maze.entrypoint.0][self.maze.entrypoint.1] = Some(self.maze.entrypoint);
+            costs[self.maze.entrypoint.0][self.maze.entrypoint.1] = 0;
+
+            while let Some(Reverse((_, _, current))) = open.pop() {
+                if closed[current.0][current.1] {
+                    continue;
+                }
+                closed[current.0][current.1] = true;
+                self.explore(current);
+
+                if current == self.goalpoint {
+                    self.mark_jumps(&parents, current);
+                    return true;
+                }
+
+                // from the entrypoint, jump every way; otherwise keep going the way we came, or
+                // turn to either side--doubling back can't find anything shorter
+                let parent = parents[current.0][current.1].unwrap_or(current);
+                let directions: Vec<(isize, isize)> = if parent == current {
+                    vec![(1, 0), (0, 1), (-1, 0), (0, -1)]
+                } else {
+                    let (dy, dx) = (
+                        (current.0 as isize - parent.0 as isize).signum(),
+                        (current.1 as isize - parent.1 as isize).signum(),
+                    );
+                    vec![(dy, dx), (dx, dy), (-dx, -dy)]
+                };
+                for direction in directions {
+                    let Some(jump) = self.jump(current, direction) else {
+                        continue;
+                    };
+                    let cost = costs[current.0][current.1]
+                        + current.0.abs_diff(jump.0)
+                        + current.1.abs_diff(jump.1);
+                    if cost < costs[jump.0][jump.1] {
+                        costs[jump.0][jump.1] = cost;
+                        parents[jump.0][jump.1] = Some(current);
+                        open.push(Reverse((cost + heuristic(jump), heuristic(jump), jump)));
+                    }
+                }
+            }
+            false
+        }
+
+        /**
+         * Moves from pos in the given (dy, dx) direction, and returns the first cell worth
+         * stopping at for jump point search: the goalpoint, a cell with a side passage that
+         * couldn't have been reached from the row (or column) before it, or--when moving
+         * vertically--a cell that a horizontal jump from would find something. Returns None if it
+         * hits a wall first.
+         */
+        fn jump(&self, pos: (usize, usize), (dy, dx): (isize, isize)) -> Option<(usize, usize)> {
+            let open = |(y, x): (isize, isize)| {
+                y >= 0 && x >= 0 && self.maze.is_passage((y as usize, x as usize))
+            };
+
+            let (mut y, mut x) = (pos.0 as isize, pos.1 as isize);
+            loop {
+                (y, x) = (y + dy, x + dx);
+                if !open((y, x)) {
+                    return None;
+                }
+                let current = (y as usize, x as usize);
+                if current == self.goalpoint {
+                    return Some(current);
+                }
+
+                // a side passage with a wall behind it forces a turn here
+                for (sy, sx) in [(dx, dy), (-dx, -dy)] {
+                    if open((y + sy, x + sx)) && !open((y + sy - dy, x + sx - dx)) {
+                        return Some(current);
+                    }
+                }
+                if dy != 0
+                    && (self.jump(current, (0, 1)).is_some()
+                        || self.jump(current, (0, -1)).is_some())
+                {
+                    return Some(current);
+                }
+            }
+        }
+
+        /**
+         * Marks the path found by jump point search, filling in the straight runs between the
+         * jump points by following parents back from the given cell.
+         */
+        fn mark_jumps(&mut self, parents: &[Vec<Option<(usize, usize)>>], from: (usize, usize)) {
+            let mut current = from;
+            loop {
+                let parent = parents[current.0][current.1].unwrap_or(current);
+                let mut cell = current;
+                self.mark((cell.0, cell.1));
+                while cell != parent {
+                    cell = (
+                        (cell.0 as isize + (parent.0 as isize - cell.0 as isize).signum()) as usize,
+                        (cell.1 as isize + (parent.1 as isize - cell.1 as isize).signum()) as usize,
+                    );
+                    self.mark((cell.0, cell.1));
+                }
+                if parent == current {
+                    break;
+                }
+                current = parent;
+            }
+        }
+
+        /**
+         * Solves this Maze via Dijkstra's algorithm, which explores cells in order of the lowest
+         * total cost to reach them, where cost gives what stepping into each cell costs (stepping
+         * onto the entrypoint is free). The first time it reaches the goalpoint is by the
+         * cheapest path, which is traced back and marked.
+         */
+        fn solve_from_dijkstra(&mut self, cost: &dyn Fn((usize, usize)) -> usize) -> bool {
+            let mut parents: Vec<Vec<Option<(usize, usize)>>> =
+                vec![vec![None; self.maze.dimensions.1]; self.maze.dimensions.0];
+            let mut totals: Vec<Vec<usize>> =
+                vec![vec![usize::MAX; self.maze.dimensions.1]; self.maze.dimensions.0];
+            let mut closed = vec![vec![false; self.maze.dimensions.1]; self.maze.dimensions.0];
+            // the cells still to explore, as (total cost to reach, cell)
+            let mut open = BinaryHeap::from([Reverse((0, self.maze.entrypoint))]);
+            parents[self.maze.entrypoint.0][self.maze.entrypoint.1] = Some(self.maze.entrypoint);
+            totals[self.maze.entrypoint.0][self.maze.entrypoint.1] = 0;
+
+            while let Some(Reverse((total, current))) = open.pop() {
+                // a cell can be pushed again after a cheaper path to it turns up
+                if closed[current.0][current.1] {
+                    continue;
+                }
+                closed[current.0][current.1] = true;
+                self.explore(current);
+
+                if current == self.goalpoint {
+                    self.stats.cost = Some(total);
+                    self.mark_trail(&parents, current);
+                    return true;
+                }
+
+                for (dy, dx) in [(1, 0), (0, 1), (-1, 0), (0, -1)] {
+                    let (y, x) = (current.0 as isize + dy, current.1 as isize + dx);
+                    if y < 0
+                        || x < 0
+                        || y as usize >= self.maze.dimensions.0
+                        || x as usize >= self.maze.dimensions.1
+                    {
+                        continue;
+                    }
+                    let (y, x) = (y as usize, x as usize);
+                    if self.maze.cells[y][x].wall {
+                        continue;
+                    }
+                    let total = total.saturating_add(cost((y, x)));
+                    if total < totals[y][x] {
+                        totals[y][x] = total;
+                        parents[y][x] = Some(current);
+                        open.push(Reverse((total, (y, x))));
+                    }
+                }
+            }
+            false
+        }
+
+        /**
+         * Solves this Maze by walking through it with one hand on the wall. The walker starts at
+         * the entrypoint facing into the Maze, and at each step tries turning each way in turns
+         * (as quarter turns clockwise from where it's facing: 3 for left, 0 for straight on, 1 for
+         * right, and 2 for back), moving the first way that's open. Every cell it walks through is
+         * marked, dead ends and all, so the path is usually much longer than it needs to be.
+         * The walker can go around in circles forever if the goalpoint isn't on a wall connected
+         * to the one it started on, so it gives up if it's ever in the same cell facing the same
+         * way twice.
+         */
+        fn solve_from_wall_follower(&mut self, turns: [usize; 4]) -> bool {
+            // north, east, south, and west, in clockwise order
+            let directions: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+            let step = |pos: (usize, usize), facing: usize| {
+                let (y, x) = (
+                    pos.0 as isize + directions[facing].0,
+                    pos.1 as isize + directions[facing].1,
+                );
+                (y >= 0
+                    && x >= 0
+                    && (y as usize) < self.maze.dimensions.0
+                    && (x as usize) < self.maze.dimensions.1
+                    && !self.maze.cells[y as usize][x as usize].wall)
+                    .then_some((y as usize, x as usize))
+            };
+
+            let mut pos = self.maze.entrypoint;
+            let Some(mut facing) = (0..4).find(|&facing| step(pos, facing).is_some()) else {
+                return false;
+            };
+            let mut seen = vec![vec![[false; 4]; self.maze.dimensions.1]; self.maze.dimensions.0];
+            let mut path: Vec<(usize, usize)> = vec![pos];
+            let mut steps = 0;
+
+            while !self.at_exit(pos) {
+                if seen[pos.0][pos.1][facing] {
+                    return false;
+                }
+                seen[pos.0][pos.1][facing] = true;
+
+                // there's always a way to go, since the walker can turn back the way it came
+                let (next, turned) = turns
+                    .iter()
+                    .find_map(|&turn| {
+                        let turned = (facing + turn) % 4;
+                        step(pos, turned).map(|next| (next, turned))
+                    })
+                    .expect("the walker came from somewhere");
+                pos = next;
+                facing = turned;
+                steps += 1;
+                path.push(pos);
+            }
+            self.goalpoint = pos;
+
+            for (y, x) in path {
+                if !self.is_marked((y, x)) {
+                    self.mark((y, x));
+                    self.explore((y, x));
+                }
+            }
+            self.stats.steps = Some(steps);
+            true
+        }
+
+        /**
+         * Solves this Maze via the Pledge algorithm: the walker heads in one preferred direction
+         * (whichever way the goalpoint leads out of the Maze--east if it doesn't) until it runs
+         * into a wall, then follows the wall with its left hand, counting its turns (left turns
+         * up, right turns down). Once it's turned back around to the preferred direction, with a
+         * count of zero, it leaves the wall and heads off again. Since it doesn't just follow the
+         * same wall forever, it can get away from loops that trap a wall follower. Every cell it
+         * walks through is marked. The walker gives up if it hasn't made it after enough steps
+         * to walk every cell four ways twice over.
+         */
+        fn solve_from_pledge(&mut self) -> bool {
+            // north, east, south, and west, in clockwise order
+            let directions: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+            let step = |pos: (usize, usize), facing: usize| {
+                let (y, x) = (
+                    pos.0 as isize + directions[facing].0,
+                    pos.1 as isize + directions[facing].1,
+                );
+                (y >= 0
+                    && x >= 0
+                    && (y as usize) < self.maze.dimensions.0
+                    && (x as usize) < self.maze.dimensions.1
+                    && !self.maze.cells[y as usize][x as usize].wall)
+                    .then_some((y as usize, x as usize))
+            };
+            let preferred = if self.goalpoint.0 == 0 {
+                0
+            } else if self.goalpoint.0 == self.maze.dimensions.0 - 1 {
+                2
+            } else if self.goalpoint.1 == 0 {
+                3
+            } else {
+                1
+            };
+
+            let mut pos = self.maze.entrypoint;
+            let mut facing = preferred;
+            let mut turns: isize = 0;
+            let mut path: Vec<(usize, usize)> = vec![pos];
+            let mut steps = 0;
+            let limit = 8 * self.maze.dimensions.0 * self.maze.dimensions.1;
+
+            while !self.at_exit(pos) {
+                if steps == limit {
+                    return false;
+                }
+                if turns == 0 {
+                    // heading off in the preferred direction, until there's a wall in the way
+                    if let Some(next) = step(pos, facing) {
+                        pos = next;
+                        steps += 1;
+                        path.push(pos);
+                    } else {
+                        // turn right, to put the wall on the left
+                        facing = (facing + 1) % 4;
+                        turns -= 1;
+                    }
+                    continue;
+                }
+
+                // following the wall: left, straight on, right, or back, as quarter turns
+                // clockwise, along with what each does to the count
+                let Some((next, turn, counted)) = [(3, 1), (0, 0), (1, -1), (2, -2)]
+                    .iter()
+                    .find_map(|&(turn, counted)| {
+                        step(pos, (facing + turn) % 4).map(|next| (next, turn, counted))
+                    })
+                else {
+                    // walled in on every side, with nowhere to go
+                    return false;
+                };
+                facing = (facing + turn) % 4;
+                turns += counted;
+                pos = next;
+                steps += 1;
+                path.push(pos);
+            }
+            self.goalpoint = pos;
+
+            for (y, x) in path {
+                if !self.is_marked((y, x)) {
+                    self.mark((y, x));
+                    self.explore((y, x));
+                }
+            }
+            self.stats.steps = Some(steps);
+            true
+        }
+
+        /**
+         * Solves this Maze by sending a mouse wandering through it at random: at each step it picks
+         * a random way to go, other than back the way it came unless it's at a dead end. Only the
+         * mouse's path with its loops erased is marked--every time it comes back to a cell it's
+         * already been through, the path since then is cut out. Gives up (leaving the Maze
+         * unsolved) if the mouse hasn't made it to the goalpoint within max_steps steps.
+         */
+        fn solve_from_random_mouse(&mut self, max_steps: usize) -> bool {
+            let directions: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+            let mut rng = thread_rng();
+            let mut explored = vec![vec![false; self.maze.dimensions.1]; self.maze.dimensions.0];
+            // where each cell is on the loop-erased path, if it's on it
+            let mut on_path: Vec<Vec<Option<usize>>> =
+                vec![vec![None; self.maze.dimensions.1]; self.maze.dimensions.0];
+            let mut path: Vec<(usize, usize)> = vec![self.maze.entrypoint];
+            on_path[self.maze.entrypoint.0][self.maze.entrypoint.1] = Some(0);
+            explored[self.maze.entrypoint.0][self.maze.entrypoint.1] = true;
+            self.explore(self.maze.entrypoint);
+
+            let mut pos = self.maze.entrypoint;
+            let mut back: Option<(usize, usize)> = None;
+            let mut steps = 0;
+            while !self.at_exit(pos) {
+                if steps == max_steps {
+                    return false;
+                }
+                let exits: Vec<(usize, usize)> = directions
+                    .iter()
+                    .filter_map(|&(dy, dx)| {
+                        let (y, x) = (pos.0 as isize + dy, pos.1 as isize + dx);
+                        (y >= 0
+                            && x >= 0
+                            && (y as usize) < self.maze.dimensions.0
+                            && (x as usize) < self.maze.dimensions.1
+                            && !self.maze.cells[y as usize][x as usize].wall)
+                            .then_some((y as usize, x as usize))
+                    })
+                    .collect();
+                let forward: Vec<(usize, usize)> = exits
+                    .iter()
+                    .copied()
+                    .filter(|&exit| Some(exit) != back)
+                    .collect();
+                let Some(&next) = forward.choose(&mut rng).or(exits.first()) else {
+                    // nowhere to go at all
+                    return false;
+                };
+                back = Some(pos);
+                pos = next;
+                steps += 1;
+                if !explored[pos.0][pos.1] {
+                    explored[pos.0][pos.1] = true;
+                    self.explore(pos);
+                }
+
+                // erase the loop the mouse just closed, if it did
+                if let Some(index) = on_path[pos.0][pos.1] {
+                    for (y, x) in path.drain(index + 1..) {
+                        on_path[y][x] = None;
+                    }
+                } else {
+                    on_path[pos.0][pos.1] = Some(path.len());
+                    path.push(pos);
+                }
+            }
+            self.goalpoint = pos;
+
+            for (y, x) in path {
+                self.mark((y, x));
+            }
+            self.stats.steps = Some(steps);
+            true
+        }
+
+        /**
+         * Solves this Maze by flooding it from the goalpoint, finding how many steps every cell is
+         * from it, then walking downhill from the entrypoint: each step goes to a neighbor one
+         * step closer (trying South, East, North, and West in turn), which is a shortest path even
+         * in a Maze with loops. The distances are kept for last_distance_map.
+         */
+        fn solve_from_flood_fill(&mut self) -> bool {
+            let distances = self.maze.distance_grid(self.goalpoint);
+            // the flood reaches the cells nearest the goalpoint first
+            let mut reached: Vec<(usize, (usize, usize))> = vec![];
+            for (y, row) in distances.iter().enumerate() {
+                for (x, distance) in row.iter().enumerate() {
+                    if let Some(distance) = distance {
+                        reached.push((*distance, (y, x)));
+                    }
+                }
+            }
+            reached.sort();
+            for (_, pos) in reached {
+                self.explore(pos);
+            }
+
+            let mut path: Vec<(usize, usize)> = vec![];
+            let mut pos = self.maze.entrypoint;
+            let solved = distances[pos.0][pos.1].is_some();
+            if solved {
+                path.push(pos);
+                while let Some(distance) = distances[pos.0][pos.1].filter(|&distance| distance > 0)
+                {
+                    pos = [(1, 0), (0, 1), (-1, 0), (0, -1)]
+                        .iter()
+                        .map(|&(dy, dx)| {
+                            (
+                                (pos.0 as isize + dy) as usize,
+                                (pos.1 as isize + dx) as usize,
+                            )
+                        })
+                        .find(|&(y, x)| {
+                            distances
+                                .get(y)
+                                .and_then(|row| row.get(x))
+                                .copied()
+                                .flatten()
+                                == Some(distance - 1)
+                        })
+                        .expect("every reachable cell but the goalpoint has a closer neighbor");
+                    path.push(pos);
+                }
+            }
+
+            for (y, x) in path {
+                self.mark((y, x));
+            }
+            self.stats.distances = Some(distances);
+            solved
+        }
+
+        /**
+         * Solves this Maze via Lee's algorithm, as used for routing wires on circuit boards:
+         * numbered waves spread out from the entrypoint, each one made of the unnumbered neighbors
+         * of the last, until one reaches the goalpoint. Then the path is traced back from the
+         * goalpoint, each step going to a neighbor from the wave before (trying South, East,
+         * North, and West in turn), which is a shortest path even in a Maze with loops. The waves
+         * are kept for last_wave_grid.
+         */
+        fn solve_from_lee(&mut self) -> bool {
+            let mut waves: Vec<Vec<Option<usize>>> =
+                vec![vec![None; self.maze.dimensions.1]; self.maze.dimensions.0];
+            waves[self.maze.entrypoint.0][self.maze.entrypoint.1] = Some(0);
+            self.explore(self.maze.entrypoint);
+
+            let goalpoint = self.goalpoint;
+            let mut wave: Vec<(usize, usize)> = vec![self.maze.entrypoint];
+            let mut number = 0;
+            while !wave.is_empty() && waves[goalpoint.0][goalpoint.1].is_none() {
+                number += 1;
+                let mut next_wave: Vec<(usize, usize)> = vec![];
+                for pos in wave {
+                    for next in (MazeView { maze: self.maze }).neighbors(pos) {
+                        if waves[next.0][next.1].is_none() {
+                            waves[next.0][next.1] = Some(number);
+                            self.explore(next);
+                            next_wave.push(next);
+                        }
+                    }
+                }
+                wave = next_wave;
+            }
+
+            let solved = waves[goalpoint.0][goalpoint.1].is_some();
+            if solved {
+                let mut pos = goalpoint;
+                self.mark((pos.0, pos.1));
+                while let Some(number) = waves[pos.0][pos.1].filter(|&number| number > 0) {
+                    pos = (MazeView { maze: self.maze })
+                        .neighbors(pos)
+                        .into_iter()
+                        .find(|&(y, x)| waves[y][x] == Some(number - 1))
+                        .expect("every cell a wave reached was reached from the wave before");
+                    self.mark((pos.0, pos.1));
+                }
+            }
+            self.stats.waves = Some(waves);
+            solved
+        }
+
+        /**
+         * Solves this Maze via Trémaux's algorithm, walking through it and chalking a mark at
+         * both ends of each passage every time it's walked down. Arriving somewhere that's been
+         * visited before down a fresh passage means going around a loop, so the walker turns
+         * back; otherwise it takes the passage with the fewest marks, and never one with two.
+         * Once the goalpoint is reached, the passages marked exactly once make up the path back
+         * to the entrypoint. Unlike the wall followers, this can't go around in circles, so it
+         * works on Mazes with loops too.
+         */
+        fn solve_from_tremaux(&mut self) -> bool {
+            // north, east, south, and west, so that (direction + 2) % 4 is the way back
+            let directions: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+            let step = |maze: &Maze, pos: (usize, usize), direction: usize| {
+                let (y, x) = (
+                    pos.0 as isize + directions[direction].0,
+                    pos.1 as isize + directions[direction].1,
+                );
+                (y >= 0 && x >= 0 && maze.is_passage((y as usize, x as usize)))
+                    .then_some((y as usize, x as usize))
+            };
+
+            // the marks at the end of each passage leading out of each cell, by direction
+            let mut marks = vec![vec![[0u8; 4]; self.maze.dimensions.1]; self.maze.dimensions.0];
+            let mut explored = vec![vec![false; self.maze.dimensions.1]; self.maze.dimensions.0];
+            let mut pos = self.maze.entrypoint;
+            let mut back: Option<usize> = None;
+            let mut steps = 0;
+            explored[pos.0][pos.1] = true;
+            self.explore(pos);
+
+            while !self.at_exit(pos) {
+                let exits: Vec<usize> = (0..4)
+                    .filter(|&direction| {
+                        Some(direction) != back && step(self.maze, pos, direction).is_some()
+                    })
+                    .collect();
+                let here = marks[pos.0][pos.1];
+                let direction = match back {
+                    // been here before, but not down this passage: that's a loop, so turn back
+                    Some(back) if here[back] == 1 && exits.iter().any(|&exit| here[exit] > 0) => {
+                        back
+                    }
+                    _ => {
+                        let fewest = exits
+                            .iter()
+                            .copied()
+                            .min_by_key(|&exit| here[exit])
+                            .filter(|&exit| here[exit] < 2);
+                        // with nowhere new to go, head back the way we came
+                        match fewest.or(back.filter(|&back| here[back] < 2)) {
+                            Some(direction) => direction,
+                            None => return false,
+                        }
+                    }
+                };
+
+                let next = step(self.maze, pos, direction).expect("the passage leads somewhere");
+                marks[pos.0][pos.1][direction] += 1;
+                marks[next.0][next.1][(direction + 2) % 4] += 1;
+                pos = next;
+                back = Some((direction + 2) % 4);
+                steps += 1;
+                if !explored[pos.0][pos.1] {
+                    explored[pos.0][pos.1] = true;
+                    self.explore(pos);
+                }
+            }
+
+            self.goalpoint = pos;
+
+            // follow the passages marked once from the entrypoint to the goalpoint
+            let mut path: Vec<(usize, usize)> = vec![self.maze.entrypoint];
+            let mut back: Option<usize> = None;
+            while let Some(&pos) = path.last().filter(|&&pos| pos != self.goalpoint) {
+                let Some(direction) = (0..4).find(|&direction| {
+                    Some(direction) != back && marks[pos.0][pos.1][direction] == 1
+                }) else {
+                    return false;
+                };
+                path.push(step(self.maze, pos, direction).expect("the passage leads somewhere"));
+                back = Some((direction + 2) % 4);
+            }
+            for (y, x) in path {
+                self.mark((y, x));
+            }
+            self.stats.steps = Some(steps);
+            true
+        }
+
+        /**
+         * Solves this Maze via breadth-first search from both ends at once, a whole step at a time
+         * from whichever end has fewer cells to search next. The path is found once the two
+         * searches meet, after searching about half as far from each end, so far fewer cells get
+         * explored than searching from the entrypoint alone. Finishing the step where they first
+         * meet, and keeping the shortest way through, means the path is still a shortest one.
+         */
+        fn solve_from_bidirectional_bfs(&mut self) -> bool {
+            // the search from the entrypoint, then the one from the goalpoint
+            let mut parents =
+                [(); 2].map(|_| vec![vec![None; self.maze.dimensions.1]; self.maze.dimensions.0]);
+            let mut distances = [(); 2]
+                .map(|_| vec![vec![usize::MAX; self.maze.dimensions.1]; self.maze.dimensions.0]);
+            let mut frontiers: [Vec<(usize, usize)>; 2] =
+                [vec![self.maze.entrypoint], vec![self.goalpoint]];
+            for (side, start) in [self.maze.entrypoint, self.goalpoint]
+                .into_iter()
+                .enumerate()
+            {
+                parents[side][start.0][start.1] = Some(start);
+                distances[side][start.0][start.1] = 0;
+            }
+
+            // where the searches met, by the shortest way through
+            let mut meeting: Option<(usize, (usize, usize))> =
+                (self.maze.entrypoint == self.goalpoint).then_some((0, self.maze.entrypoint));
+            while meeting.is_none() && !frontiers[0].is_empty() && !frontiers[1].is_empty() {
+                let side = if frontiers[0].len() <= frontiers[1].len() {
+                    0
+                } else {
+                    1
+                };
+                let mut next: Vec<(usize, usize)> = vec![];
+                for current in std::mem::take(&mut frontiers[side]) {
+                    self.explore(current);
+                    let distance = distances[side][current.0][current.1] + 1;
+                    for (dy, dx) in [(1, 0), (0, 1), (-1, 0), (0, -1)] {
+                        let (y, x) = (current.0 as isize + dy, current.1 as isize + dx);
+                        if y < 0
+                            || x < 0
+                            || y as usize >= self.maze.dimensions.0
+                            || x as usize >= self.maze.dimensions.1
+                        {
+                            continue;
+                        }
+                        let (y, x) = (y as usize, x as usize);
+                        if self.maze.cells[y][x].wall || parents[side][y][x].is_some() {
+                            continue;
+                        }
+                        parents[side][y][x] = Some(current);
+                        distances[side][y][x] = distance;
+                        next.push((y, x));
+
+                        // the other search has been here too, so this is a way through
+                        let other = distances[1 - side][y][x];
+                        if other != usize::MAX
+                            && meeting.is_none_or(|(length, _)| distance + other < length)
+                        {
+                            meeting = Some((distance + other, (y, x)));
+                        }
+                    }
+                }
+                frontiers[side] = next;
+            }
+
+            let Some((_, meeting)) = meeting else {
+                return false;
+            };
+            // each half of the path ends at the meeting cell, which is marked by both
+            self.mark_trail(&parents[0], meeting);
+            self.mark_trail(&parents[1], meeting);
+            true
+        }
+
+        /**
+         * Solves this Maze via breadth-first search from both ends at once, like
+         * solve_from_bidirectional_bfs, but with the search from the goalpoint on a thread of its
+         * own. Each search numbers the cells it reaches by how far they are from its end, where
+         * the other can see them; whenever one reaches a cell the other already has, that's a way
+         * through. A search stops once it and the other have gone far enough between them that no
+         * shorter way through could turn up, or once either runs out of cells, so the path is
+         * still a shortest one. The path is traced back from the meeting cell to each end by
+         * stepping to cells numbered one lower. Since the searches race each other, which of
+         * several shortest paths is found (and how many cells get explored) can change from one
+         * solve to the next.
+         */
+        fn solve_from_parallel_bidirectional(&mut self) -> bool {
+            use std::sync::atomic::{AtomicBool, AtomicU32, Ordering::SeqCst};
+            use std::sync::Mutex;
+
+            let (height, width) = self.maze.dimensions;
+            let starts = [self.maze.entrypoint, self.goalpoint];
+            // the search from the entrypoint, then the one from the goalpoint
+            let distances: [Vec<AtomicU32>; 2] = [(); 2].map(|_| {
+                (0..height * width)
+                    .map(|_| AtomicU32::new(u32::MAX))
+                    .collect()
+            });
+            for (side, start) in starts.into_iter().enumerate() {
+                distances[side][start.0 * width + start.1].store(0, SeqCst);
+            }
+            // how far each search has numbered every cell out to
+            let reached = [AtomicU32::new(0), AtomicU32::new(0)];
+            let exhausted = AtomicBool::new(false);
+            // where the searches met, by the shortest way through
+            let meeting: Mutex<Option<(u32, (usize, usize))>> = Mutex::new(
+                (self.maze.entrypoint == self.goalpoint).then_some((0, self.maze.entrypoint)),
+            );
+
+            let maze = self.maze;
+            let search = |side: usize| -> Vec<(usize, usize)> {
+                let (mine, theirs) = (&distances[side], &distances[1 - side]);
+                let mut explored: Vec<(usize, usize)> = vec![];
+                let mut frontier: Vec<(usize, usize)> = vec![starts[side]];
+                let mut distance = 0;
+                loop {
+                    // the other search has numbered every cell out to this far, and has already
+                    // recorded any way through it found on the way, so nothing shorter than this
+                    // can turn up past here
+                    let theirs_reached = reached[1 - side].load(SeqCst);
+                    let shortest = meeting.lock().expect("neither search panics").map(|m| m.0);
+                    if exhausted.load(SeqCst)
+                        || shortest.is_some_and(|length| distance + theirs_reached >= length)
+                    {
+                        break;
+                    }
+
+                    let mut next: Vec<(usize, usize)> = vec![];
+                    for current in frontier {
+                        explored.push(current);
+                        for (dy, dx) in [(1, 0), (0, 1), (-1, 0), (0, -1)] {
+                            let (y, x) = (
+                                (current.0 as isize + dy) as usize,
+                                (current.1 as isize + dx) as usize,
+                            );
+                            if !maze.is_passage((y, x))
+                                || mine[y * width + x].load(SeqCst) != u32::MAX
+                            {
+                                continue;
+                            }
+                            mine[y * width + x].store(distance + 1, SeqCst);
+                            next.push((y, x));
+
+                            // the other search has been here too, so this is a way through
+                            let other = theirs[y * width + x].load(SeqCst);
+                            if other != u32::MAX {
+                                let mut meeting = meeting.lock().expect("neither search panics");
+                                if meeting.is_none_or(|(length, _)| distance + 1 + other < length) {
+                                    *meeting = Some((distance + 1 + other, (y, x)));
+                                }
+                            }
+                        }
+                    }
+                    // every cell this end can reach has been numbered, so either the way through
+                    // has been found, or there isn't one
+                    if next.is_empty() {
+                        exhausted.store(true, SeqCst);
+                        break;
+                    }
+                    frontier = next;
+                    distance += 1;
+                    reached[side].store(distance, SeqCst);
+                }
+                explored
+            };
+            let explored = std::thread::scope(|scope| {
+                let backward = scope.spawn(|| search(1));
+                let forward = search(0);
+                [
+                    forward,
+                    backward
+                        .join()
+                        .expect("the search from the goalpoint doesn't panic"),
+                ]
+            });
+
+            // the searches ran side by side, so take turns counting their cells
+            for i in 0..explored[0].len().max(explored[1].len()) {
+                for side_explored in &explored {
+                    if let Some(&pos) = side_explored.get(i) {
+                        self.explore(pos);
+                    }
+                }
+            }
+
+            let Some((_, meeting)) = meeting.into_inner().expect("neither search panics") else {
+                return false;
+            };
+            for side_distances in &distances {
+                let mut pos = meeting;
+                self.mark((pos.0, pos.1));
+                let mut distance = side_distances[pos.0 * width + pos.1].load(SeqCst);
+                while distance > 0 {
+                    pos = (MazeView { maze: self.maze })
+                        .neighbors(pos)
+                        .into_iter()
+                        .find(|&(y, x)| side_distances[y * width + x].load(SeqCst) == distance - 1)
+                        .expect("every numbered cell but the start has a neighbor numbered lower");
+                    self.mark((pos.0, pos.1));
+                    distance -= 1;
+                }
+            }
+            true
+        }
+    }
+
+    impl SolutionOverlay {
+        /**
+         * Creates an overlay with nothing marked, for a Maze of the given dimensions.
+         */
+        fn new((height, width): (usize, usize)) -> Self {
+            SolutionOverlay {
+                marked: vec![vec![false; width]; height],
+                count: 0,
+            }
+        }
+
+        /**
+         * Creates an overlay with the given cells marked, or None if none of them are.
+         */
+        fn from_marks(marked: Vec<Vec<bool>>) -> Option<Self> {
+            let count = marked.iter().flatten().filter(|&&marked| marked).count();
+            (count > 0).then_some(SolutionOverlay { marked, count })
+        }
+
+        /**
+         * Marks the (y, x) cell, or clears it.
+         */
+        fn set(&mut self, (y, x): (usize, usize), marked: bool) {
+            if self.marked[y][x] != marked {
+                self.marked[y][x] = marked;
+                if marked {
+                    self.count += 1;
+                } else {
+                    self.count -= 1;
+                }
+            }
+        }
+
+        /**
+         * Returns whether the given (y, x) cell is marked; cells outside the Maze never are.
+         */
+        pub fn contains(&self, (y, x): (usize, usize)) -> bool {
+            self.marked
+                .get(y)
+                .and_then(|row| row.get(x))
+                .copied()
+                .unwrap_or(false)
+        }
+
+        /**
+         * Iterates over the (y, x) of each marked cell, row by row.
+         */
+        pub fn cells(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+            self.marked.iter().enumerate().flat_map(|(y, row)| {
+                row.iter()
+                    .enumerate()
+                    .filter(|&(_, &marked)| marked)
+                    .map(move |(x, _)| (y, x))
+            })
+        }
+
+        /**
+         * Returns how many cells are marked.
+         */
+        pub fn len(&self) -> usize {
+            self.count
+        }
+
+        pub fn is_empty(&self) -> bool {
+            self.count == 0
+        }
+
+        /**
+         * Returns the (height, width) of the Maze this overlay was made for.
+         */
+        pub fn dimensions(&self) -> (usize, usize) {
+            (
+                self.marked.len(),
+                self.marked.first().map_or(0, |row| row.len()),
+            )
+        }
+    }
+
+    impl MazeGraph {
+        /**
+         * Returns the (y, x) cell of each node, which is numbered by where it comes in this.
+         */
+        pub fn nodes(&self) -> &[(usize, usize)] {
+            &self.nodes
+        }
+
+        pub fn edges(&self) -> &[GraphEdge] {
+            &self.edges
+        }
+
+        /**
+         * Returns the number of the node at the given (y, x) cell, or None if it isn't one.
+         */
+        pub fn node(&self, cell: (usize, usize)) -> Option<usize> {
+            self.nodes.binary_search(&cell).ok()
+        }
+
+        /**
+         * Returns each node the given one has an edge to, along with how long the edge is (once
+         * per edge, so nodes joined more than one way come up more than once).
+         */
+        pub fn neighbors(&self, node: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+            self.adjacency[node].iter().map(move |&edge| {
+                let edge = self.edges[edge];
+                match edge.from == node {
+                    true => (edge.to, edge.length),
+                    false => (edge.from, edge.length),
+                }
+            })
+        }
+    }
+
+    impl Path {
+        /**
+         * Returns a path through the given (y, x) cells, in order, without checking that each
+         * one is next to the one before it.
+         */
+        pub fn new(cells: Vec<(usize, usize)>) -> Self {
+            Path { cells }
+        }
+
+        /**
+         * Returns a path through the given (y, x) cells like new, but returns PathGap with the
+         * index of the first cell that isn't next to the one before it, if there is one.
+         */
+        pub fn try_new(cells: Vec<(usize, usize)>) -> Result<Self, MazeError> {
+            if let Some(index) = cells
+                .windows(2)
+                .position(|pair| Direction::between(pair[0], pair[1]).is_none())
+            {
+                return Err(MazeError::PathGap(index + 1));
+            }
+            Ok(Path { cells })
+        }
+
+        /**
+         * Returns the cells along this path, from start to end.
+         */
+        pub fn cells(&self) -> &[(usize, usize)] {
+            &self.cells
+        }
+
+        /**
+         * Returns the number of cells along this path, counting both ends.
+         */
+        pub fn len(&self) -> usize {
+            self.cells.len()
+        }
+
+        pub fn is_empty(&self) -> bool {
+            self.cells.is_empty()
+        }
+
+        /**
+         * Iterates over the cells along this path, from start to end.
+         */
+        pub fn iter(&self) -> std::slice::Iter<'_, (usize, usize)> {
+            self.cells.iter()
+        }
+
+        /**
+         * Returns whether this path goes through the given (y, x) cell.
+         */
+        pub fn contains(&self, cell: (usize, usize)) -> bool {
+            self.cells.contains(&cell)
+        }
+
+        /**
+         * Returns the direction of each step along this path, in order. A step between two
+         * cells that aren't next to each other (which only a path made by new can have) is left
+         * out.
+         */
+        pub fn moves(&self) -> Vec<Direction> {
+            self.cells
+                .windows(2)
+                .filter_map(|pair| Direction::between(pair[0], pair[1]))
+                .collect()
+        }
+
+        /**
+         * Returns how many times this path changes direction; turning back the way it came
+         * counts once.
+         */
+        pub fn turns(&self) -> usize {
+            self.moves()
+                .windows(2)
+                .filter(|pair| pair[0] != pair[1])
+                .count()
+        }
+
+        /**
+         * Returns how many steps long each straight stretch of this path is, in order: the
+         * number of moves made in one direction before each turn, then after the last one.
+         */
+        pub fn straight_runs(&self) -> Vec<usize> {
+            let mut runs: Vec<usize> = vec![];
+            let mut last: Option<Direction> = None;
+            for direction in self.moves() {
+                match runs.last_mut() {
+                    Some(run) if last == Some(direction) => *run += 1,
+                    _ => runs.push(1),
+                }
+                last = Some(direction);
+            }
+            runs
+        }
+
+        /**
+         * Returns the moves along this path as a string of compass letters, one per step--N, E,
+         * S, or W--like "NNEESSWN", which Maze::apply_moves can replay.
+         */
+        pub fn to_moves_string(&self) -> String {
+            self.moves()
+                .iter()
+                .map(|direction| match direction {
+                    Direction::North => 'N',
+                    Direction::East => 'E',
+                    Direction::South => 'S',
+                    Direction::West => 'W',
+                })
+                .collect()
+        }
+    }
+
+    impl<'a> IntoIterator for &'a Path {
+        type Item = &'a (usize, usize);
+        type IntoIter = std::slice::Iter<'a, (usize, usize)>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.cells.iter()
+        }
+    }
+
+    impl Direction {
+        /**
+         * Returns the direction to step from one (y, x) cell to the other, or None if they
+         * aren't next to each other.
+         */
+        fn between(from: (usize, usize), to: (usize, usize)) -> Option<Self> {
+            match (
+                to.0 as isize - from.0 as isize,
+                to.1 as isize - from.1 as isize,
+            ) {
+                (-1, 0) => Some(Direction::North),
+                (0, 1) => Some(Direction::East),
+                (1, 0) => Some(Direction::South),
+                (0, -1) => Some(Direction::West),
+                _ => None,
+            }
+        }
+    }
+
+    impl SolvingAlgorithm {
+        /**
+         * Returns what this algorithm is called, as it would be written in the middle of a
+         * sentence.
+         */
+        pub fn name(&self) -> &'static str {
+            use SolvingAlgorithm::*;
+            match self {
+                RecursiveBacktracking => "recursive backtracking",
+                IterativeBacktracking => "iterative backtracking",
+                DeadEndFilling => "dead-end filling",
+                BreadthFirst => "breadth-first search",
+                AStar(Heuristic::Manhattan) => "A* search",
+                AStar(Heuristic::Euclidean) => "A* search by Euclidean distance",
+                AStar(Heuristic::Chebyshev) => "A* search by Chebyshev distance",
+                AStar(Heuristic::Weighted(_)) => "weighted A* search",
+                Dijkstra => "Dijkstra's algorithm",
+                WallFollowerLeft => "following the left-hand wall",
+                WallFollowerRight => "following the right-hand wall",
+                Tremaux => "Trémaux's algorithm",
+                BidirectionalBfs => "breadth-first search from both ends",
+                GreedyBestFirst => "greedy best-first search",
+                Pledge => "the Pledge algorithm",
+                RandomMouse { .. } => "a random mouse",
+                FloodFill => "flood fill",
+                JumpPoint => "jump point search",
+                Lee => "Lee's algorithm",
+                IdaStar => "IDA* search",
+                ParallelBidirectional => "breadth-first search from both ends in parallel",
+            }
+        }
+    }
+
+    impl Heuristic {
+        /**
+         * Returns the estimated distance between two (y, x) cells.
+         */
+        fn distance(self, from: (usize, usize), to: (usize, usize)) -> f64 {
+            let (dy, dx) = (from.0.abs_diff(to.0) as f64, from.1.abs_diff(to.1) as f64);
+            match self {
+                Heuristic::Manhattan => dy + dx,
+                Heuristic::Euclidean => (dy * dy + dx * dx).sqrt(),
+                Heuristic::Chebyshev => dy.max(dx),
+                // a negative weight would have A* head away from the goalpoint
+                Heuristic::Weighted(weight) => weight.max(0.0) as f64 * (dy + dx),
+            }
+        }
+    }
+
+    impl GenerationSteps {
+        /**
+         * Returns the walls as they stand after the last step: true for a wall, and false for a
+         * passage, row by row. Before the first step, they're what the algorithm starts from,
+         * and once the Maze is finished, they're its walls.
+         */
+        pub fn grid(&self) -> &WallGrid {
+            &self.grid
+        }
+    }
+
+    impl GenerationSteps {
+        /**
+         * Turns the recorded steps into a log that replays to the given Maze, which they
+         * generated. The loops, braiding, and openings added after the algorithm finished go
+         * through the Maze itself rather than being recorded, so the walls they changed are
+         * logged row by row at the end.
+         */
+        fn into_log(self, maze: &Maze) -> Vec<CarveEvent> {
+            let mut grid = self.grid;
+            let mut log: Vec<CarveEvent> = vec![];
+            if grid.iter().flatten().all(|&wall| !wall) {
+                log.push(CarveEvent::Cleared);
+            }
+            for event in self.events {
+                match event {
+                    GenerationEvent::CellCarved((y, x)) => {
+                        grid[y][x] = false;
+                        log.push(CarveEvent::Carved((y, x)));
+                    }
+                    GenerationEvent::CellFilled((y, x)) => {
+                        grid[y][x] = true;
+                        log.push(CarveEvent::Filled((y, x)));
+                    }
+                    GenerationEvent::Finished(_) => {}
+                }
+            }
+            for (pos, _) in maze.cells() {
+                let wall = maze.cells[pos.0][pos.1].wall;
+                if grid[pos.0][pos.1] != wall {
+                    log.push(if wall {
+                        CarveEvent::Filled(pos)
+                    } else {
+                        CarveEvent::Carved(pos)
+                    });
+                }
+            }
+            log.push(CarveEvent::Openings {
+                entrance: maze.entrypoint,
+                exit: maze.goalpoint,
+            });
+            log
+        }
+    }
+
+    impl Iterator for GenerationSteps {
+        type Item = GenerationEvent;
+
+        fn next(&mut self) -> Option<GenerationEvent> {
+            let Some(event) = self.events.pop_front() else {
+                return self
+                    .maze
+                    .take()
+                    .map(|maze| GenerationEvent::Finished(Box::new(maze)));
+            };
+            match event {
+                GenerationEvent::CellCarved((y, x)) => self.grid[y][x] = false,
+                GenerationEvent::CellFilled((y, x)) => self.grid[y][x] = true,
+                GenerationEvent::Finished(_) => {}
+            }
+            Some(event)
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            let left = self.events.len() + self.maze.is_some() as usize;
+            (left, Some(left))
+        }
+    }
+
+    impl ExactSizeIterator for GenerationSteps {}
+
+    impl SolveSteps<'_> {
+        /**
+         * Returns the Maze being solved, marked as it stands after the last step.
+         */
+        pub fn maze(&self) -> &Maze {
+            self.maze
+        }
+    }
+
+    impl Iterator for SolveSteps<'_> {
+        type Item = SolveEvent;
+
+        fn next(&mut self) -> Option<SolveEvent> {
+            let Some(event) = self.events.pop_front() else {
+                if self.finished {
+                    return None;
+                }
+                self.finished = true;
+                self.maze.overlay = self.overlay.take();
+                return self.path.take().map(SolveEvent::Found);
+            };
+            match event {
+                SolveEvent::Visited(pos) => self.maze.mark(pos),
+                SolveEvent::Backtracked(pos) | SolveEvent::Filled(pos) => self.maze.unmark(pos),
+                SolveEvent::Found(_) => {}
+            }
+            Some(event)
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            let left = self.events.len() + self.path.is_some() as usize;
+            (left, Some(left))
+        }
+    }
+
+    impl ExactSizeIterator for SolveSteps<'_> {}
+
+    impl Drop for SolveSteps<'_> {
+        // a Maze half marked by a solve that was never finished would look solved
+        fn drop(&mut self) {
+            if !self.finished {
+                self.maze.unsolve();
+            }
+        }
+    }
+
+    impl SolveTrace {
+        /**
+         * Returns every cell looked at, in order. Some algorithms look at a cell more than once.
+         */
+        pub fn order(&self) -> &[(usize, usize)] {
+            &self.order
+        }
+
+        /**
+         * Returns when the given (y, x) cell was first looked at, counting from 0, or None if it
+         * never was.
+         */
+        pub fn visit_index(&self, pos: (usize, usize)) -> Option<usize> {
+            self.index.get(pos.0)?.get(pos.1).copied().flatten()
+        }
+
+        /**
+         * Returns whether the algorithm found a solution.
+         */
+        pub fn solved(&self) -> bool {
+            self.solved
+        }
+    }
+
+    impl MazeView<'_> {
+        /**
+         * Returns the height and width of the maze, walls included.
+         */
+        pub fn dimensions(&self) -> (usize, usize) {
+            self.maze.dimensions
+        }
+
+        pub fn entrance(&self) -> (usize, usize) {
+            self.maze.entrypoint
+        }
+
+        pub fn exit(&self) -> (usize, usize) {
+            self.maze.goalpoint
+        }
+
+        /**
+         * Returns whether the given (y, x) is inside the maze, and not a wall.
+         */
+        pub fn is_open(&self, cell: (usize, usize)) -> bool {
+            self.maze.is_passage(cell)
+        }
+
+        /**
+         * Returns what stepping into the given (y, x) cell costs (see Maze::set_cost), or None
+         * if it's outside the maze.
+         */
+        pub fn cost(&self, cell: (usize, usize)) -> Option<u32> {
+            self.maze.get_cost(cell)
+        }
+
+        /**
+         * Returns the open cells directly South, East, North, and West of the given cell, in that
+         * order.
+         */
+        pub fn neighbors(&self, cell: (usize, usize)) -> Vec<(usize, usize)> {
+            [(1, 0), (0, 1), (-1, 0), (0, -1)]
+                .iter()
+                .filter_map(|&(dy, dx)| {
+                    let (y, x) = (cell.0 as isize + dy, cell.1 as isize + dx);
+                    (y >= 0 && x >= 0 && self.is_open((y as usize, x as usize)))
+                        .then_some((y as usize, x as usize))
+                })
+                .collect()
+        }
+    }
+
+    /**
+     * The built-in algorithms run over the maze itself, by the same code solve_from uses, and hand
+     * back the path they found.
+     */
+    impl Solver for SolvingAlgorithm {
+        fn solve(&self, maze: &MazeView) -> Option<Path> {
+            maze.maze.run_solver(*self, SolveStats::default()).0
+        }
+    }
+
+    impl Generator for CreationAlgorithm {
+        fn generate(&self, dimensions: (usize, usize), rng: &mut dyn RngCore) -> WallGrid {
+            Maze::new_from_rng(dimensions, *self, rng, None, None)
+                .cells
+                .iter()
+                .map(|row| row.iter().map(|cell| cell.wall).collect())
+                .collect()
+        }
+    }
+
+    impl MazeBuilder {
+        /**
+         * Creates a builder with nothing set but the defaults.
+         */
+        pub fn new() -> Self {
+            MazeBuilder {
+                dimensions: (0, 0),
+                algorithm: CreationAlgorithm::Prim,
+                seed: None,
+                entrance: None,
+                exit: None,
+                openings: None,
+                loops: 0.0,
+                braid: 0.0,
+                terrain: vec![],
+                progress: None,
+                log: false,
+            }
+        }
+
+        /**
+         * Sets how many rows and columns the Maze has, which are rounded up like new_from does.
+         */
+        pub fn dimensions(mut self, rows: usize, columns: usize) -> Self {
+            self.dimensions = (rows, columns);
+            self
+        }
+
+        /**
+         * Sets the algorithm that carves out the Maze.
+         */
+        pub fn algorithm(mut self, algorithm: CreationAlgorithm) -> Self {
+            self.algorithm = algorithm;
+            self
+        }
+
+        /**
+         * Sets the seed for every random choice made while creating the Maze, so the same
+         * settings always build the same Maze.
+         */
+        pub fn seed(mut self, seed: u64) -> Self {
+            self.seed = Some(seed);
+            self
+        }
+
+        /**
+         * Puts the entrance on the given edge, leading into the offset-th (odd, odd) cell along
+         * it, counting from 0 at the top or left.
+         */
+        pub fn entrance(mut self, edge: Edge, offset: usize) -> Self {
+            self.entrance = Some((edge, offset));
+            self
+        }
+
+        /**
+         * Puts the exit on the given edge, leading out of the offset-th (odd, odd) cell along it,
+         * counting from 0 at the top or left.
+         */
+        pub fn exit(mut self, edge: Edge, offset: usize) -> Self {
+            self.exit = Some((edge, offset));
+            self
+        }
+
+        /**
+         * Puts the entrance and exit at exactly the given (y, x) cells, like set_openings,
+         * instead of wherever entrance and exit would put them.
+         */
+        pub fn openings(mut self, entrance: (usize, usize), exit: (usize, usize)) -> Self {
+            self.openings = Some((entrance, exit));
+            self
+        }
+
+        /**
+         * Sets the fraction (from 0 to 1) of the walls between (odd, odd) cells to knock down
+         * once the Maze is carved out, like new_with_loops.
+         */
+        pub fn loops(mut self, fraction: f64) -> Self {
+            self.loops = fraction;
+            self
+        }
+
+        /**
+         * Sets the fraction (from 0 to 1) of the dead ends to braid out once the Maze is carved
+         * out, like braid.
+         */
+        pub fn braid(mut self, fraction: f64) -> Self {
+            self.braid = fraction;
+            self
+        }
+
+        /**
+         * Covers the given fraction (from 0 to 1) of the passages in terrain that costs cost to
+         * step into, once the Maze is carved out and its openings are in place, like
+         * add_terrain. Each call adds another kind of terrain, laid over the ones before it.
+         */
+        pub fn terrain(mut self, fraction: f64, cost: u32) -> Self {
+            self.terrain.push((fraction, cost));
+            self
+        }
+
+        /**
+         * Sets whether the Maze keeps a log of every cell generating it carved or filled, in
+         * order, for generation_log to hand back and replay to rebuild it from. It's off unless
+         * this turns it on, since the log takes about as much room as the Maze itself.
+         */
+        pub fn log_generation(mut self, log: bool) -> Self {
+            self.log = log;
+            self
+        }
+
+        /**
+         * Has callback told how generating the Maze is going, every time about another 1% of the
+         * cells have been carved. Returning ControlFlow::Break stops generation, and build returns
+         * Cancelled instead of a Maze.
+         */
+        pub fn on_progress(
+            mut self,
+            callback: impl FnMut(GenerationProgress) -> ControlFlow<()> + 'static,
+        ) -> Self {
+            self.progress = Some(Rc::new(RefCell::new(callback)));
+            self
+        }
+
+        /**
+         * Creates the Maze. Returns an error if it would be smaller than 3x3 (DimensionsTooSmall)
+         * or too big to fit in memory (DimensionsTooLarge), if the entrance or exit is farther
+         * along its edge than the Maze has cells (OpeningOutOfRange), if they're the same cell
+         * (SameOpenings), if the exact openings are ones set_openings would reject, if one of the
+         * algorithm's probabilities isn't a finite number (InvalidParameter), or if the callback
+         * given to on_progress cancels it (Cancelled).
+         */
+        pub fn build(&self) -> Result<Maze, MazeError> {
+            self.algorithm.check_parameters()?;
+            self.build_clamped()
+        }
+
+        /**
+         * Creates the Maze like build, but clamps the algorithm's probabilities instead of
+         * rejecting them, for the constructors that can't return an error.
+         */
+        fn build_clamped(&self) -> Result<Maze, MazeError> {
+            Maze::check_dimensions(self.dimensions)?;
+            Maze::check_dimensions(Maze::rounded_dimensions(self.dimensions, self.algorithm))?;
+            match self.seed {
+                Some(seed) => self.build_from_rng(&mut StdRng::seed_from_u64(seed)),
+                None => self.build_from_rng(&mut thread_rng()),
+            }
+        }
+
+        fn build_from_rng(&self, rng: &mut dyn RngCore) -> Result<Maze, MazeError> {
+            let mut steps = self.log.then(|| GenerationSteps {
+                events: VecDeque::new(),
+                grid: vec![],
+                maze: None,
+            });
+            let mut maze = match &self.progress {
+                Some(callback) => {
+                    let mut callback = callback.borrow_mut();
+                    let mut progress = Progress {
+                        callback: &mut *callback,
+                        carved: 0,
+                        total: 0,
+                        next: 0,
+                        cancelled: false,
+                    };
+                    let maze = Maze::new_from_rng(
+                        self.dimensions,
+                        self.algorithm,
+                        rng,
+                        steps.as_mut(),
+                        Some(&mut progress),
+                    );
+                    if progress.cancelled {
+                        return Err(MazeError::Cancelled);
+                    }
+                    maze
+                }
+                None => {
+                    Maze::new_from_rng(self.dimensions, self.algorithm, rng, steps.as_mut(), None)
+                }
+            };
+            if self.loops > 0.0 {
+                maze.knock_down_walls(self.loops, rng);
+            }
+            if self.braid > 0.0 {
+                maze.braid_from_rng(self.braid, rng);
+            }
+
+            let (height, width) = maze.dimensions;
+            let place = |(edge, offset): (Edge, usize)| {
+                // counting (odd, odd) cells means every opening leads into one
+                let along = 2 * offset + 1;
+                let (length, cell) = match edge {
+                    Edge::Top => (width, (0, along)),
+                    Edge::Bottom => (width, (height - 1, along)),
+                    Edge::Left => (height, (along, 0)),
+                    Edge::Right => (height, (along, width - 1)),
+                };
+                if along < length - 1 {
+                    Ok(cell)
+                } else {
+                    Err(MazeError::OpeningOutOfRange(edge, offset))
+                }
+            };
+            let entrypoint = self.entrance.map(place).transpose()?;
+            let goalpoint = self.exit.map(place).transpose()?;
+            if entrypoint.is_some() || goalpoint.is_some() {
+                let entrypoint = entrypoint.unwrap_or(maze.entrypoint);
+                let goalpoint = goalpoint.unwrap_or(maze.goalpoint);
+                if entrypoint == goalpoint {
+                    return Err(MazeError::SameOpenings);
+                }
+                maze.set_openings(entrypoint, goalpoint)?;
+            }
+            if let Some((entrypoint, goalpoint)) = self.openings {
+                maze.set_openings(entrypoint, goalpoint)?;
+            }
+            for &(fraction, cost) in &self.terrain {
+                maze.add_terrain_from_rng(fraction, cost, rng);
+            }
+            if let Some(steps) = steps {
+                maze.log = Some(steps.into_log(&maze));
+            }
+            Ok(maze)
+        }
+    }
+
+    impl Default for MazeBuilder {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl MazeTemplate {
+        /**
+         * Creates an empty template, which leaves the whole Maze up to the algorithm.
+         */
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /**
+         * Carves out an open chamber from top_left to bottom_right (inclusive), which has to be
+         * inside the outer wall.
+         */
+        pub fn open_rect(&mut self, top_left: (usize, usize), bottom_right: (usize, usize)) {
+            self.open.push((top_left, bottom_right));
+        }
+
+        /**
+         * Makes a cell a wall that's never carved through, even inside an open chamber.
+         */
+        pub fn force_wall(&mut self, pos: (usize, usize)) {
+            self.walls.push(pos);
+        }
+
+        /**
+         * Puts the entrance at a spot on the outer wall (not in a corner).
+         */
+        pub fn set_entrance(&mut self, pos: (usize, usize)) {
+            self.entrance = Some(pos);
+        }
+
+        /**
+         * Puts the goal at a spot on the outer wall (not in a corner).
+         */
+        pub fn set_goal(&mut self, pos: (usize, usize)) {
+            self.goal = Some(pos);
+        }
+    }
+
+    impl CreationAlgorithm {
+        /**
+         * Returns InvalidParameter if any of this algorithm's probabilities isn't a finite number,
+         * which there's no sensible way to clamp.
+         */
+        fn check_parameters(&self) -> Result<(), MazeError> {
+            match *self {
+                CreationAlgorithm::RecursiveDivision { room_chance, .. }
+                    if !room_chance.is_finite() =>
+                {
+                    Err(MazeError::InvalidParameter("room_chance"))
+                }
+                CreationAlgorithm::Cellular {
+                    fill_probability, ..
+                } if !fill_probability.is_finite() => {
+                    Err(MazeError::InvalidParameter("fill_probability"))
+                }
+                CreationAlgorithm::WeightedPrim { straightness } if !straightness.is_finite() => {
+                    Err(MazeError::InvalidParameter("straightness"))
+                }
+                _ => Ok(()),
+            }
+        }
+    }
+
+    /**
+     * Counts the cells carved while a Maze is generated, and tells a callback how it's going each
+     * time another batch of them is done.
+     */
+    struct Progress<'a> {
+        callback: &'a mut dyn FnMut(GenerationProgress) -> ControlFlow<()>,
+        carved: usize,
+        total: usize,
+        next: usize, // how many cells will have been carved at the next report
+        cancelled: bool,
+    }
+
+    impl Progress<'_> {
+        /**
+         * Returns how many cells to carve between reports, so there's one about every 1%.
+         */
+        fn batch(&self) -> usize {
+            (self.total / 100).max(1)
+        }
+
+        fn carve(&mut self) {
+            self.carved += 1;
+            if self.carved < self.next || self.cancelled {
+                return;
+            }
+            self.next += self.batch();
+            let progress = GenerationProgress {
+                carved: self.carved,
+                total: self.total,
+            };
+            if (self.callback)(progress).is_break() {
+                self.cancelled = true;
+            }
+        }
+    }
+
+    /**
+     * The cells of a Maze while it's being generated. Its walls can only be changed through it,
+     * so every change can be recorded (and counted) as it's made.
+     */
+    struct Canvas<'a, 'b> {
+        cells: Vec<Vec<Cell>>,
+        // where the changes to the walls go, if they're being recorded
+        log: Option<&'a mut VecDeque<GenerationEvent>>,
+        progress: Option<&'a mut Progress<'b>>, // what counts the changes, if anything is
+    }
+
+    impl Canvas<'_, '_> {
+        /**
+         * Makes the (y, x) cell a wall, or a passage, recording it if that changes anything.
+         */
+        fn set_wall(&mut self, (y, x): (usize, usize), wall: bool) {
+            if self.cells[y][x].wall == wall {
+                return;
+            }
+            self.cells[y][x].wall = wall;
+            if let Some(log) = &mut self.log {
+                log.push_back(match wall {
+                    true => GenerationEvent::CellFilled((y, x)),
+                    false => GenerationEvent::CellCarved((y, x)),
+                });
+            }
+            if let Some(progress) = &mut self.progress {
+                progress.carve();
+            }
+        }
+
+        /**
+         * Returns whether progress has been cancelled, in which case the algorithm should stop
+         * where it is.
+         */
+        fn cancelled(&self) -> bool {
+            self.progress
+                .as_ref()
+                .is_some_and(|progress| progress.cancelled)
+        }
+
+        fn open(&mut self, cell: (usize, usize)) {
+            self.set_wall(cell, false);
+        }
+
+        fn close(&mut self, cell: (usize, usize)) {
+            self.set_wall(cell, true);
+        }
+
+        fn visit(&mut self, (y, x): (usize, usize)) {
+            self.cells[y][x].visited = true;
+        }
+
+        /**
+         * Sets each cell back to unvisited, so the Maze will print properly.
+         */
+        fn clear_visited(&mut self) {
+            for row in &mut self.cells {
+                for cell in row {
+                    cell.visited = false;
+                }
+            }
+        }
+
+        /**
+         * Replaces all of the cells at once, recording the walls that change row by row.
+         */
+        fn replace(&mut self, cells: Vec<Vec<Cell>>) {
+            for (y, row) in cells.iter().enumerate() {
+                for (x, cell) in row.iter().enumerate() {
+                    self.set_wall((y, x), cell.wall);
+                }
+            }
+            self.cells = cells;
+        }
+    }
+
+    impl std::ops::Deref for Canvas<'_, '_> {
+        type Target = Vec<Vec<Cell>>;
+
+        fn deref(&self) -> &Vec<Vec<Cell>> {
+            &self.cells
+        }
+    }
+
+    /**
+     * A disjoint-set (union-find) forest over the integers 0..size, with path compression and union
+     * by rank. Kruskal's algorithm uses it to keep track of which Cells are already connected.
+     */
+    struct DisjointSet {
+        parents: Vec<usize>,
+        ranks: Vec<usize>,
+    }
+
+    impl DisjointSet {
+        fn new(size: usize) -> Self {
+            DisjointSet {
+                parents: (0..size).collect(),
+                ranks: vec![0; size],
+            }
+        }
+
+        /**
+         * Returns the representative of the set containing element, flattening the path to it
+         * along the way.
+         */
+        fn find(&mut self, element: usize) -> usize {
+            let mut root = element;
+            while self.parents[root] != root {
+                root = self.parents[root];
+            }
+
+            let mut current = element;
+            while self.parents[current] != root {
+                let next = self.parents[current];
+                self.parents[current] = root;
+                current = next;
+            }
+            root
+        }
+
+        /**
+         * Merges the sets containing first and second. Returns false if they were already in the
+         * same set, true otherwise.
+         */
+        fn union(&mut self, first: usize, second: usize) -> bool {
+            let first_root = self.find(first);
+            let second_root = self.find(second);
+            if first_root == second_root {
+                return false;
+            }
+
+            // hang the shallower tree off of the deeper one
+            if self.ranks[first_root] < self.ranks[second_root] {
+                self.parents[first_root] = second_root;
+            } else if self.ranks[first_root] > self.ranks[second_root] {
+                self.parents[second_root] = first_root;
+            } else {
+                self.parents[second_root] = first_root;
+                self.ranks[first_root] += 1;
+            }
+            true
+        }
+    }
+
+    /**
+     * Clamps the given probability from 0 to 1, counting one that isn't a finite number as 0, so
+     * it's always safe to hand to gen_bool.
+     */
+    fn probability(chance: f64) -> f64 {
+        if chance.is_finite() {
+            chance.clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+
+    /**
+     * Picks a random number from the given range with rng. Sampling a usize directly can give
+     * different numbers on 32- and 64-bit platforms, so it's sampled as a u32 instead, which keeps
+     * a seeded Maze the same everywhere (no Maze comes anywhere near 4 billion cells across).
+     */
+    fn gen_index(rng: &mut dyn RngCore, range: impl std::ops::RangeBounds<usize>) -> usize {
+        use std::ops::Bound::*;
+        let start = match range.start_bound() {
+            Included(&start) => start,
+            Excluded(&start) => start + 1,
+            Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Included(&end) => end,
+            Excluded(&end) => end - 1,
+            Unbounded => u32::MAX as usize,
+        };
+        rng.gen_range(start as u32..=end as u32) as usize
+    }
+
+    /**
+     * Returns the letter for the given character in a 3x5 font, as five rows of three bits each
+     * (the highest is the leftmost pixel). Lowercase letters are drawn as uppercase ones.
+     */
+    fn glyph(character: char) -> Option<[u8; 5]> {
+        Some(match character.to_ascii_uppercase() {
+            'A' => [0b010, 0b101, 0b111, 0b101, 0b101],
+            'B' => [0b110, 0b101, 0b110, 0b101, 0b110],
+            'C' => [0b011, 0b100, 0b100, 0b100, 0b011],
+            'D' => [0b110, 0b101, 0b101, 0b101, 0b110],
+            'E' => [0b111, 0b100, 0b110, 0b100, 0b111],
+            'F' => [0b111, 0b100, 0b110, 0b100, 0b100],
+            'G' => [0b011, 0b100, 0b101, 0b101, 0b011],
+            'H' => [0b101, 0b101, 0b111, 0b101, 0b101],
+            'I' => [0b111, 0b010, 0b010, 0b010, 0b111],
+            'J' => [0b001, 0b001, 0b001, 0b101, 0b010],
+            'K' => [0b101, 0b101, 0b110, 0b101, 0b101],
+            'L' => [0b100, 0b100, 0b100, 0b100, 0b111],
+            'M' => [0b101, 0b111, 0b111, 0b101, 0b101],
+            'N' => [0b110, 0b101, 0b101, 0b101, 0b101],
+            'O' => [0b010, 0b101, 0b101, 0b101, 0b010],
+            'P' => [0b110, 0b101, 0b110, 0b100, 0b100],
+            'Q' => [0b010, 0b101, 0b101, 0b111, 0b011],
+            'R' => [0b110, 0b101, 0b110, 0b101, 0b101],
+            'S' => [0b011, 0b100, 0b010, 0b001, 0b110],
+            'T' => [0b111, 0b010, 0b010, 0b010, 0b010],
+            'U' => [0b101, 0b101, 0b101, 0b101, 0b111],
+            'V' => [0b101, 0b101, 0b101, 0b101, 0b010],
+            'W' => [0b101, 0b101, 0b111, 0b111, 0b101],
+            'X' => [0b101, 0b101, 0b010, 0b101, 0b101],
+            'Y' => [0b101, 0b101, 0b010, 0b010, 0b010],
+            'Z' => [0b111, 0b001, 0b010, 0b100, 0b111],
+            '0' => [0b111, 0b101, 0b101, 0b101, 0b111],
+            '1' => [0b010, 0b110, 0b010, 0b010, 0b111],
+            '2' => [0b110, 0b001, 0b010, 0b100, 0b111],
+            '3' => [0b110, 0b001, 0b010, 0b001, 0b110],
+            '4' => [0b101, 0b101, 0b111, 0b001, 0b001],
+            '5' => [0b111, 0b100, 0b110, 0b001, 0b110],
+            '6' => [0b011, 0b100, 0b111, 0b101, 0b111],
+            '7' => [0b111, 0b001, 0b010, 0b010, 0b010],
+            '8' => [0b111, 0b101, 0b111, 0b101, 0b111],
+            '9' => [0b111, 0b101, 0b111, 0b001, 0b110],
+            ' ' => [0b000, 0b000, 0b000, 0b000, 0b000],
+            '!' => [0b010, 0b010, 0b010, 0b000, 0b010],
+            '?' => [0b110, 0b001, 0b010, 0b000, 0b010],
+            '.' => [0b000, 0b000, 0b000, 0b000, 0b010],
+            ',' => [0b000, 0b000, 0b000, 0b010, 0b100],
+            '-' => [0b000, 0b000, 0b111, 0b000, 0b000],
+            '\'' => [0b010, 0b010, 0b000, 0b000, 0b000],
+            ':' => [0b000, 0b010, 0b000, 0b010, 0b000],
+            _ => return None,
+        })
+    }
+
+    // the most cells from_json lets each value in the JSON (each run of walls_rle, say) stand for
+    const CELLS_PER_JSON_VALUE: usize = 1 << 16;
+
+    // the version of the binary form that to_bytes writes, and the only one from_bytes reads
+    const BYTES_VERSION: u8 = 1;
+
+    /**
+     * Steps through the bytes of a Maze packed by to_bytes, without ever reading past the end.
+     */
+    struct ByteReader<'a> {
+        bytes: &'a [u8],
+        pos: usize,
+    }
+
+    impl<'a> ByteReader<'a> {
+        fn take(&mut self, count: usize) -> Result<&'a [u8], MazeError> {
+            if count > self.remaining() {
+                return Err(MazeError::ParseError(String::from(
+                    "the bytes end too soon",
+                )));
+            }
+            self.pos += count;
+            Ok(&self.bytes[self.pos - count..self.pos])
+        }
+
+        fn byte(&mut self) -> Result<u8, MazeError> {
+            Ok(self.take(1)?[0])
+        }
+
+        /**
+         * Reads a little-endian u32.
+         */
+        fn number(&mut self) -> Result<usize, MazeError> {
+            let bytes = self.take(4)?;
+            Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as usize)
+        }
+
+        fn remaining(&self) -> usize {
+            self.bytes.len() - self.pos
+        }
+    }
+
+    impl fmt::Display for MazeError {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            match self {
+                MazeError::OpeningOutOfRange(edge, offset) => write!(
+                    f,
+                    "there's no cell {} along the {:?} edge to open",
+                    offset, edge
+                ),
+                MazeError::SameOpenings => write!(f, "the entrance and exit can't be the same"),
+                MazeError::CornerOpening((y, x)) => {
+                    write!(f, "({}, {}) is a corner, so it can't be an opening", y, x)
+                }
+                MazeError::ParseError(reason) => write!(f, "couldn't make sense of it: {}", reason),
+                MazeError::InvalidCoordinate((y, x)) => {
+                    write!(f, "({}, {}) is outside the maze", y, x)
+                }
+                MazeError::NotSolved => write!(f, "the maze hasn't been solved"),
+                MazeError::UnknownMove(index, letter) => {
+                    write!(f, "move {} ({:?}) isn't N, E, S, or W", index, letter)
+                }
+                MazeError::MoveOffGrid(index) => write!(f, "move {} leaves the maze", index),
+                MazeError::MoveIntoWall(index) => write!(f, "move {} walks into a wall", index),
+                MazeError::PathGap(index) => write!(
+                    f,
+                    "cell {} of the path isn't next to the one before it",
+                    index
+                ),
+                MazeError::InvalidMask => write!(f, "the mask must be a non-empty rectangle"),
+                MazeError::MaskTooSmall => write!(f, "the mask is too small to hold a maze"),
+                MazeError::MaskDisconnected => write!(f, "the mask is split into separate pieces"),
+                MazeError::Io(path, error) => write!(f, "{}: {}", path.display(), error),
+                MazeError::ImageTooSmall => write!(f, "the image is too small to hold a maze"),
+                MazeError::DimensionsTooSmall => write!(f, "the maze must be at least 3x3"),
+                MazeError::DimensionsTooLarge => write!(f, "the maze is too big to fit in memory"),
+                MazeError::SolutionTooShort(best) => write!(
+                    f,
+                    "no maze had a long enough solution; the best went through {:.0}% of its cells",
+                    best * 100.0
+                ),
+                MazeError::InvalidRegion => {
+                    write!(f, "the region doesn't fit the maze at that size")
+                }
+                MazeError::RegionDisconnected => {
+                    write!(f, "regenerating the region cut part of the maze off")
+                }
+                MazeError::InvalidParameter(name) => write!(f, "{} must be a finite number", name),
+                MazeError::TextTooLarge => write!(f, "the text doesn't fit inside the maze"),
+                MazeError::UnsupportedCharacter(character) => {
+                    write!(f, "there's no letter for {:?}", character)
+                }
+                MazeError::InvalidTemplate => write!(f, "the template doesn't fit the maze"),
+                MazeError::TemplateDisconnected => {
+                    write!(f, "the template's walls cut part of the maze off")
+                }
+                MazeError::NotOpen((y, x)) => write!(f, "there's no passage at ({}, {})", y, x),
+                MazeError::NoPath => write!(f, "no path could be found"),
+                MazeError::NoExit => write!(f, "the maze has no exits left"),
+                MazeError::NotAnExit((y, x)) => write!(f, "there's no exit at ({}, {})", y, x),
+                MazeError::InvalidWallGrid => {
+                    write!(f, "the walls must be a rectangle at least 3x3")
+                }
+                MazeError::WallGridDisconnected => {
+                    write!(f, "the walls cut part of the maze off")
+                }
+                MazeError::OpeningBlocked((y, x)) => {
+                    write!(f, "the opening at ({}, {}) is blocked", y, x)
+                }
+                MazeError::Unsolvable => write!(f, "there's no way through the maze"),
+                MazeError::SolutionThroughWall((y, x)) => {
+                    write!(f, "the solution runs through the wall at ({}, {})", y, x)
+                }
+                MazeError::SolutionMissesEnd((y, x)) => {
+                    write!(f, "the solution doesn't reach ({}, {})", y, x)
+                }
+                MazeError::SolutionBroken((y, x)) => {
+                    write!(f, "the solution is cut off before ({}, {})", y, x)
+                }
+                MazeError::EdgeMismatch => {
+                    write!(f, "the edges being joined aren't the same length")
+                }
+                MazeError::OuterWall((y, x)) => {
+                    write!(f, "({}, {}) is part of the outer wall", y, x)
+                }
+                MazeError::WouldDisconnect((y, x)) => write!(
+                    f,
+                    "a wall at ({}, {}) would cut the entrance off from the exit",
+                    y, x
+                ),
+                MazeError::OverlayMismatch => {
+                    write!(f, "the overlay was made for a maze of other dimensions")
+                }
+                MazeError::Invalid(issues) => {
+                    let issues: Vec<String> =
+                        issues.iter().map(|issue| issue.to_string()).collect();
+                    write!(f, "the maze is broken: {}", issues.join("; "))
+                }
+                MazeError::Cancelled => write!(f, "generating the maze was cancelled"),
+            }
+        }
+    }
+
+    impl fmt::Display for ValidationIssue {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            match self {
+                ValidationIssue::OutsideGrid((y, x)) => {
+                    write!(f, "the opening at ({}, {}) is outside the maze", y, x)
+                }
+                ValidationIssue::BlockedOpening((y, x)) => {
+                    write!(f, "the opening at ({}, {}) is a wall", y, x)
+                }
+                ValidationIssue::NoExit => write!(f, "there's no exit"),
+                ValidationIssue::EntranceIsExit((y, x)) => {
+                    write!(f, "({}, {}) is both the entrance and an exit", y, x)
+                }
+                ValidationIssue::BrokenBorder((y, x)) => {
+                    write!(f, "the outer wall has a gap at ({}, {})", y, x)
+                }
+                ValidationIssue::Disconnected((y, x)) => {
+                    write!(
+                        f,
+                        "the passage at ({}, {}) is cut off from the entrance",
+                        y, x
+                    )
+                }
+            }
+        }
+    }
+
+    impl std::error::Error for MazeError {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            match self {
+                MazeError::Io(_, error) => Some(error),
+                _ => None,
+            }
+        }
+    }
+
+    /**
+     * Indexes a Maze by (row, col): the row counts down from 0 at the top, and the column across
+     * from 0 at the left, so maze[(maze.rows() - 1, 0)] is the bottom left corner. Panics if the
+     * cell is outside the Maze; get returns None instead. There's no way to change a cell by
+     * indexing, since a wall put in the wrong place could cut the Maze in two.
+     *
+     * ```
+     * use rust_maze::{CellKind, CreationAlgorithm, Maze};
+     *
+     * let maze = Maze::new_from((9, 13), CreationAlgorithm::Prim);
+     * assert_eq!(maze[(0, 0)], CellKind::Wall);
+     * assert_eq!(maze[(8, 12)], CellKind::Wall);
+     * assert_eq!(maze[(1, 0)], CellKind::Entrance);
+     * assert_eq!(maze[(7, 12)], CellKind::Exit);
+     * assert_eq!(maze.get((9, 0)), None);
+     * assert_eq!(maze.get((0, 13)), None);
+     * ```
+     */
+    impl Index<(usize, usize)> for Maze {
+        type Output = CellKind;
+
+        fn index(&self, pos: (usize, usize)) -> &CellKind {
+            self.get(pos).unwrap_or_else(|| {
+                panic!(
+                    "({}, {}) is outside the {}x{} Maze",
+                    pos.0, pos.1, self.dimensions.0, self.dimensions.1
+                )
+            })
+        }
+    }
+
+    /**
+     * Reads a Maze back from the way it's printed, colors and all (or, since the colors aren't
+     * needed to tell the cells apart, without them). Plain spaces work for passages as well as
+     * non-breaking ones, and spaces at the end of a line are ignored, since the outer wall is
+     * never a passage. Passages drawn as costing more (~~) are read as plain ones, since how much
+     * more isn't drawn. Each line has to be a row of whole cells as wide as the first, with one
+     * entrance and at least one exit among them; the exit next to the solution (or if nothing's
+     * marked, the first exit, reading top to bottom and left to right) becomes the goalpoint.
+     * Returns a ParseError saying which line and column (counting cells, from 1) don't fit,
+     * DimensionsTooSmall if the Maze would be smaller than 3x3, or Invalid if the Maze is broken
+     * some other way (as validate finds), like having a gap in its outer wall.
+     */
+    impl FromStr for Maze {
+        type Err = MazeError;
+
+        fn from_str(text: &str) -> Result<Self, MazeError> {
+            let error = |line: usize, column: usize, problem: &str| {
+                MazeError::ParseError(format!("line {}, column {}: {}", line, column, problem))
+            };
+            let mut cells: Vec<Vec<Cell>> = vec![];
+            let mut marks: Vec<Vec<bool>> = vec![];
+            let mut entrypoint: Option<(usize, usize)> = None;
+            let mut exits: Vec<(usize, usize)> = vec![];
+
+            for (y, line) in text.lines().enumerate() {
+                // leave out the colors, which are escape sequences like \x1b[41;31m
+                let mut glyphs: Vec<char> = vec![];
+                let mut chars = line.chars();
+                while let Some(c) = chars.next() {
+                    if c == '\x1b' {
+                        for c in chars.by_ref() {
+                            if c.is_ascii_alphabetic() {
+                                break;
+                            }
+                        }
+                    } else {
+                        glyphs.push(c);
+                    }
+                }
+                while glyphs.last().is_some_and(|c| c.is_whitespace()) {
+                    glyphs.pop();
+                }
+                // a blank line is only allowed at the very end
+                if glyphs.is_empty() && text.lines().skip(y + 1).all(|line| line.trim().is_empty())
+                {
+                    break;
+                }
+
+                let mut row: Vec<Cell> = vec![];
+                let mut marks_row: Vec<bool> = vec![];
+                for (x, pair) in glyphs.chunks(2).enumerate() {
+                    let (wall, marked) = match pair {
+                        ['\u{2588}', '\u{2588}'] => (true, false),
+                        ['\u{2593}', '\u{2593}'] => (false, true),
+                        [' ' | '\u{00a0}', ' ' | '\u{00a0}'] | ['~', '~'] => (false, false),
+                        ['\u{2592}', '\u{2592}'] => {
+                            if entrypoint.is_some() {
+                                return Err(error(y + 1, x + 1, "there's a second entrance"));
+                            }
+                            entrypoint = Some((y, x));
+                            (false, false)
+                        }
+                        ['\u{2591}', '\u{2591}'] => {
+                            exits.push((y, x));
+                            (false, false)
+                        }
+                        [_] => return Err(error(y + 1, x + 1, "the last cell is cut in half")),
+                        _ => {
+                            return Err(error(
+                                y + 1,
+                                x + 1,
+                                &format!("{:?} isn't a cell", pair.iter().collect::<String>()),
+                            ))
+                        }
+                    };
+                    row.push(Cell {
+                        wall,
+                        visited: false,
+                        tag: None,
+                        cost: 1,
+                    });
+                    marks_row.push(marked);
+                }
+                if let Some(first) = cells.first() {
+                    if row.len() != first.len() {
+                        return Err(error(
+                            y + 1,
+                            row.len().min(first.len()) + 1,
+                            &format!("the row is {} cells wide, not {}", row.len(), first.len()),
+                        ));
+                    }
+                }
+                cells.push(row);
+                marks.push(marks_row);
+            }
+
+            let dimensions = (cells.len(), cells.first().map_or(0, |row| row.len()));
+            if dimensions.0 < 3 || dimensions.1 < 3 {
+                return Err(MazeError::DimensionsTooSmall);
+            }
+            let Some(entrypoint) = entrypoint else {
+                return Err(MazeError::ParseError(String::from("there's no entrance")));
+            };
+            if exits.is_empty() {
+                return Err(MazeError::ParseError(String::from("there's no exit")));
+            }
+
+            // the solution runs through both openings, though they're drawn as themselves
+            let solved = marks.iter().flatten().any(|&marked| marked);
+            let marked = |(y, x): (usize, usize)| {
+                [(1, 0), (-1, 0), (0, 1), (0, -1)].iter().any(|&(dy, dx)| {
+                    marks
+                        .get((y as isize + dy) as usize)
+                        .and_then(|row| row.get((x as isize + dx) as usize))
+                        .is_some_and(|&marked| marked)
+                })
+            };
+            let goalpoint = exits
+                .iter()
+                .copied()
+                .find(|&exit| solved && marked(exit))
+                .unwrap_or(exits[0]);
+            if solved {
+                for (y, x) in [entrypoint, goalpoint] {
+                    marks[y][x] = true;
+                }
+            }
+
+            let maze = Maze {
+                dimensions,
+                entrypoint,
+                goalpoint,
+                exits,
+                algorithm: None,
+                cells,
+                stats: SolveStats::default(),
+                log: None,
+                overlay: SolutionOverlay::from_marks(marks),
+            };
+            maze.validate().map_err(MazeError::Invalid)?;
+            Ok(maze)
+        }
+    }
+
+    /**
+     * Mazes are equal when they have the same walls, openings, and marked solution. How they
+     * were created and how the last solve went don't count; see structurally_equal to leave
+     * out the solution too.
+     */
+    impl PartialEq for Maze {
+        fn eq(&self, other: &Self) -> bool {
+            self.structurally_equal(other)
+                && self.cells == other.cells
+                && self.overlay() == other.overlay()
+        }
+    }
+
+    impl Eq for Maze {}
+
+    impl Hash for Maze {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            self.dimensions.hash(state);
+            self.entrypoint.hash(state);
+            self.exits.hash(state);
+            self.cells.hash(state);
+            self.overlay().hash(state);
+        }
+    }
+
+    /**
+     * Serializes a Maze in the shapes it's already saved in: for formats meant to be read, like
+     * JSON, as the fields to_json_compact writes, and for the rest, like bincode, as the bytes
+     * to_bytes packs it into.
+     */
+    #[cfg(feature = "serde")]
+    impl Serialize for Maze {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            use serde::ser::SerializeStruct;
+            if !serializer.is_human_readable() {
+                return serializer.serialize_bytes(&self.to_bytes());
+            }
+            let triples = |cells: &mut dyn Iterator<Item = ((usize, usize), u32)>| {
+                cells
+                    .map(|((y, x), value)| (y, x, value))
+                    .collect::<Vec<(usize, usize, u32)>>()
+            };
+            let tags = triples(&mut self.tagged());
+            let costs = triples(&mut self.weighted_cells());
+            let mut state = serializer.serialize_struct("Maze", 9)?;
+            state.serialize_field("rows", &self.dimensions.0)?;
+            state.serialize_field("cols", &self.dimensions.1)?;
+            state.serialize_field("entrance", &self.entrypoint)?;
+            state.serialize_field("exit", &self.goalpoint)?;
+            state.serialize_field("exits", &self.exits)?;
+            state.serialize_field("walls_rle", &self.wall_runs())?;
+            state.serialize_field("solution", &self.solution().unwrap_or_default())?;
+            // like to_json, tags and costs are only written when there are any
+            for (name, triples) in [("tags", tags), ("costs", costs)] {
+                match triples.is_empty() {
+                    true => state.skip_field(name)?,
+                    false => state.serialize_field(name, &triples)?,
+                }
+            }
+            state.end()
+        }
+    }
+
+    /**
+     * Deserializes a Maze from either shape it's serialized in, checking it just as from_json or
+     * from_bytes would; whatever they'd refuse comes back as the format's error, with the
+     * MazeError's message.
+     */
+    #[cfg(feature = "serde")]
+    impl<'de> Deserialize<'de> for Maze {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            use serde::de::Error;
+            if deserializer.is_human_readable() {
+                let json = crate::json::Value::deserialize(deserializer)?;
+                return Maze::from_json_value(&json).map_err(D::Error::custom);
+            }
+            deserializer.deserialize_bytes(PackedMaze)
+        }
+    }
+
+    /**
+     * Reads a Maze back from the bytes to_bytes packed it into, for formats that aren't meant to
+     * be read.
+     */
+    #[cfg(feature = "serde")]
+    struct PackedMaze;
+
+    #[cfg(feature = "serde")]
+    impl<'de> serde::de::Visitor<'de> for PackedMaze {
+        type Value = Maze;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("the bytes of a Maze, as to_bytes packs them")
+        }
+
+        fn visit_bytes<E: serde::de::Error>(self, bytes: &[u8]) -> Result<Maze, E> {
+            Maze::from_bytes(bytes).map_err(E::custom)
+        }
+
+        // some formats hand bytes over one at a time, like any other sequence
+        fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Maze, A::Error> {
+            let mut bytes: Vec<u8> = Vec::new();
+            while let Some(byte) = seq.next_element()? {
+                bytes.push(byte);
+            }
+            self.visit_bytes(&bytes)
+        }
+    }
+
+    /**
+     * Sums a Maze up for debugging, without the whole grid: its size, the algorithm that created
+     * it, where its openings are, and whether it's solved.
+     */
+    impl fmt::Debug for Maze {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.debug_struct("Maze")
+                .field("dimensions", &self.dimensions)
+                .field("algorithm", &self.algorithm)
+                .field("entrance", &self.entrypoint)
+                .field("exit", &self.goalpoint)
+                .field("exits", &self.exits)
+                .field("solved", &self.is_solved())
+                .finish()
+        }
+    }
+
+    /**
+     * Formats a Maze to be pretty-printable with the println!() macro. Each cell is two
+     * characters wide: \u{2588}\u{2588} for a wall, \u{2592}\u{2592} for the entrance (in red),
+     * \u{2591}\u{2591} for an exit (in green), \u{2593}\u{2593} for the solution (in blue), ~~ for
+     * a passage that costs more than 1 to step into (in yellow), and two non-breaking spaces for
+     * any other passage, with one more at the end of each row. The alternate
+     * form, {:#}, leaves out the colors.
+     */
+    impl fmt::Display for Maze {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            Overlaid {
+                maze: self,
+                overlay: self.overlay.as_ref(),
+            }
+            .fmt(f)
+        }
+    }
+
+    /**
+     * A Maze and an overlay to draw on top of it, which prints just like the Maze would if it
+     * were marked with the overlay. Returned by Maze::with_overlay.
+     */
+    struct Overlaid<'a> {
+        maze: &'a Maze,
+        overlay: Option<&'a SolutionOverlay>,
+    }
+
+    impl fmt::Display for Overlaid<'_> {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            let maze = self.maze;
+            for (y, row) in maze.cells.iter().enumerate() {
+                for (x, cell) in row.iter().enumerate() {
+                    let (glyph, color) = if (y, x) == maze.entrypoint {
+                        ("\u{2592}\u{2592}", Some(Color::Red))
+                    } else if maze.exits.contains(&(y, x)) {
+                        ("\u{2591}\u{2591}", Some(Color::Green))
+                    } else if cell.wall {
+                        ("\u{2588}\u{2588}", Some(Color::White))
+                    } else if self.overlay.is_some_and(|overlay| overlay.contains((y, x))) {
+                        ("\u{2593}\u{2593}", Some(Color::Blue))
+                    } else if cell.cost > 1 {
+                        ("~~", Some(Color::Yellow))
+                    } else {
+                        ("\u{00a0}\u{00a0}", None)
+                    };
+                    match color {
+                        Some(color) if !f.alternate() => {
+                            write!(f, "{}", glyph.color(color).on_color(color))?
+                        }
+                        _ => write!(f, "{}", glyph)?,
+                    }
+                }
+                write!(f, "{}", "\u{00a0}".clear())?;
+                if y != maze.dimensions.0 - 1 {
+                    writeln!(f)?;
+                }
+            }
+            Ok(())
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use std::collections::VecDeque;
+
+        fn degree(maze: &Maze, (y, x): (usize, usize)) -> usize {
+            let (height, width) = maze.dimensions;
+            let open = |y: usize, x: usize| y < height && x < width && !maze.cells[y][x].wall;
+            [
+                open(y + 1, x),
+                open(y, x + 1),
+                y > 0 && open(y - 1, x),
+                x > 0 && open(y, x - 1),
+            ]
+            .iter()
+            .filter(|&&neighbor| neighbor)
+            .count()
+        }
+
+        // counts the open cells, besides the openings, whose number of open neighbors passes keep
+        fn count_by_degree(maze: &Maze, keep: impl Fn(usize) -> bool) -> usize {
+            let (height, width) = maze.dimensions;
+            (0..height)
+                .flat_map(|y| (0..width).map(move |x| (y, x)))
+                .filter(|&(y, x)| {
+                    !maze.cells[y][x].wall && (y, x) != maze.entrypoint && (y, x) != maze.goalpoint
+                })
+                .filter(|&pos| keep(degree(maze, pos)))
+                .count()
+        }
+
+        // every open cell can be reached, each exactly one way: a tree has one fewer join
+        // between open cells than it has open cells
+        fn is_perfect(maze: &Maze) -> bool {
+            let reached = Maze::flood(&maze.cells, maze.entrypoint);
+            let (height, width) = maze.dimensions;
+            let open: Vec<(usize, usize)> = (0..height)
+                .flat_map(|y| (0..width).map(move |x| (y, x)))
+                .filter(|&(y, x)| !maze.cells[y][x].wall)
+                .collect();
+            let joins: usize = open.iter().map(|&pos| degree(maze, pos)).sum();
+            open.iter().all(|&(y, x)| reached[y][x]) && joins / 2 + 1 == open.len()
+        }
+
+        fn dead_end_count(maze: &Maze) -> usize {
+            count_by_degree(maze, |degree| degree == 1)
+        }
+
+        fn junction_count(maze: &Maze) -> usize {
+            count_by_degree(maze, |degree| degree >= 3)
+        }
+
+        // builds a Maze from rows of '#' walls and ' ' passages, with 'S' and 'G' for the
+        // entrypoint and goalpoint
+        fn from_rows(rows: &[&str]) -> Maze {
+            let find = |mark: char| -> (usize, usize) {
+                rows.iter()
+                    .enumerate()
+                    .find_map(|(y, row)| row.chars().position(|c| c == mark).map(|x| (y, x)))
+                    .expect("every drawing has both openings")
+            };
+            Maze {
+                dimensions: (rows.len(), rows[0].len()),
+                entrypoint: find('S'),
+                goalpoint: find('G'),
+                exits: vec![find('G')],
+                algorithm: None,
+                cells: rows
+                    .iter()
+                    .map(|row| {
+                        row.chars()
+                            .map(|c| Cell {
+                                wall: c == '#',
+                                visited: false,
+                                tag: None,
+                                cost: 1,
+                            })
+                            .collect()
+                    })
+                    .collect(),
+                stats: SolveStats::default(),
+                log: None,
+                overlay: None,
+            }
+        }
+
+        #[test]
+        fn hunt_and_kill_is_perfect_and_solvable_by_dead_end_filling() {
+            for _ in 0..8 {
+                let mut maze = Maze::new_from((31, 41), CreationAlgorithm::HuntAndKill);
+                let (height, width) = maze.dimensions;
+                let open: Vec<(usize, usize)> = (0..height)
+                    .flat_map(|y| (0..width).map(move |x| (y, x)))
+                    .filter(|&(y, x)| !maze.cells[y][x].wall)
+                    .collect();
+
+                // every open cell can be reached from the entrypoint, each exactly one way, so a
+                // perfect Maze has one fewer join between open cells than it has open cells
+                let mut distance = vec![vec![None; width]; height];
+                distance[maze.entrypoint.0][maze.entrypoint.1] = Some(0);
+                let mut queue = VecDeque::from([maze.entrypoint]);
+                let mut joins = 0;
+                while let Some((y, x)) = queue.pop_front() {
+                    for (ny, nx) in [
+                        (y + 1, x),
+                        (y, x + 1),
+                        (y.wrapping_sub(1), x),
+                        (y, x.wrapping_sub(1)),
+                    ] {
+                        if ny < height && nx < width && !maze.cells[ny][nx].wall {
+                            joins += 1;
+                            if distance[ny][nx].is_none() {
+                                distance[ny][nx] = Some(distance[y][x].unwrap() + 1);
+                                queue.push_back((ny, nx));
+                            }
+                        }
+                    }
+                }
+                assert!(open.iter().all(|&(y, x)| distance[y][x].is_some()));
+                assert_eq!(joins / 2 + 1, open.len());
+
+                // and filling in its dead ends leaves just the one path
+                maze.solve_from(SolvingAlgorithm::DeadEndFilling).unwrap();
+                assert!(maze.is_solved());
+                let marked = open.iter().filter(|&&pos| maze.is_marked(pos)).count();
+                let goal = maze.goalpoint;
+                assert_eq!(marked, distance[goal.0][goal.1].unwrap() + 1);
+            }
+        }
+
+        #[test]
+        fn growing_tree_newest_leaves_fewer_dead_ends_than_random() {
+            let dead_ends = |algorithm: CreationAlgorithm| -> usize {
+                (0..6)
+                    .map(|_| dead_end_count(&Maze::new_from((41, 41), algorithm)))
+                    .sum()
+            };
+            let newest = dead_ends(CreationAlgorithm::GrowingTree(GrowStrategy::Newest));
+            let random = dead_ends(CreationAlgorithm::GrowingTree(GrowStrategy::Random));
+            let prim = dead_ends(CreationAlgorithm::Prim);
+            // always growing from the newest cell backtracks like a depth-first search, leaving
+            // long corridors
+            assert!(newest * 2 < random, "{} vs {}", newest, random);
+            // growing from a random cell is much like Prim's algorithm
+            assert!(
+                random * 10 > prim * 7 && random * 10 < prim * 13,
+                "{} vs {}",
+                random,
+                prim
+            );
+        }
+
+        #[test]
+        fn full_braid_leaves_no_dead_ends_and_backtracking_still_solves() {
+            for _ in 0..6 {
+                let mut maze = Maze::new_from((31, 31), CreationAlgorithm::Kruskal);
+                assert!(dead_end_count(&maze) > 0);
+                maze.braid(1.0);
+                assert_eq!(dead_end_count(&maze), 0);
+                assert!(maze
+                    .solve_from(SolvingAlgorithm::RecursiveBacktracking)
+                    .is_ok());
+                assert!(maze.is_solved());
+            }
+        }
+
+        #[test]
+        fn partial_braid_removes_only_some_dead_ends() {
+            let mut maze = Maze::new_from((41, 41), CreationAlgorithm::Prim);
+            let before = dead_end_count(&maze);
+            maze.braid(0.5);
+            let after = dead_end_count(&maze);
+            assert!(after > 0 && after < before, "{} -> {}", before, after);
+        }
+
+        #[test]
+        fn dead_end_filling_finishes_a_braided_maze_by_bfs() {
+            let mut maze = Maze::new_from((31, 31), CreationAlgorithm::Prim);
+            maze.braid(1.0);
+            let mut bfs = maze.clone();
+            assert!(bfs.solve_from(SolvingAlgorithm::BreadthFirst).is_ok());
+            // with no dead ends to fill, every cell is left over, which isn't a single path
+            assert!(maze.solve_from(SolvingAlgorithm::DeadEndFilling).is_ok());
+            assert_eq!(maze.last_cells_filled(), Some(0));
+            assert!(maze.last_completed_by_bfs());
+            assert!(maze.verify_solution().unwrap().simple);
+            assert_eq!(maze.solution_length(), bfs.solution_length());
+        }
+
+        #[test]
+        fn unicursal_labyrinth_has_no_junctions_or_dead_ends() {
+            for _ in 0..30 {
+                let maze = Maze::new_from((21, 29), CreationAlgorithm::Unicursal);
+                let (height, width) = maze.dimensions;
+                for (y, x) in (0..height).flat_map(|y| (0..width).map(move |x| (y, x))) {
+                    if maze.cells[y][x].wall {
+                        continue;
+                    }
+                    let expected = if (y, x) == maze.entrypoint || (y, x) == maze.goalpoint {
+                        1
+                    } else {
+                        2
+                    };
+                    assert_eq!(degree(&maze, (y, x)), expected, "cell {:?}", (y, x));
+                }
+            }
+        }
+
+        #[test]
+        fn unicursal_labyrinth_is_solved_by_walking_it() {
+            let maze = Maze::new_from((25, 25), CreationAlgorithm::Unicursal);
+            let passages = maze
+                .cells
+                .iter()
+                .flatten()
+                .filter(|cell| !cell.wall)
+                .count();
+            for algorithm in [
+                SolvingAlgorithm::RecursiveBacktracking,
+                SolvingAlgorithm::DeadEndFilling,
+            ] {
+                let mut maze = maze.clone();
+                assert!(maze.solve_from(algorithm).is_ok());
+                // the one path runs through every passage
+                let marked = maze.solution_length();
+                assert_eq!(marked, passages);
+            }
+        }
+
+        #[test]
+        fn caves_are_always_solvable() {
+            for size in [(21, 21), (31, 45), (41, 25)].iter().cycle().take(12) {
+                let cave = CreationAlgorithm::Cellular {
+                    fill_probability: 0.45,
+                    iterations: 4,
+                };
+                let mut maze = Maze::new_from(*size, cave);
+                let (height, width) = maze.dimensions;
+
+                // nothing is left cut off from the entrypoint
+                let mut reached = vec![vec![false; width]; height];
+                reached[maze.entrypoint.0][maze.entrypoint.1] = true;
+                let mut queue = VecDeque::from([maze.entrypoint]);
+                while let Some((y, x)) = queue.pop_front() {
+                    for (ny, nx) in [
+                        (y + 1, x),
+                        (y, x + 1),
+                        (y.wrapping_sub(1), x),
+                        (y, x.wrapping_sub(1)),
+                    ] {
+                        if ny < height && nx < width && !maze.cells[ny][nx].wall && !reached[ny][nx]
+                        {
+                            reached[ny][nx] = true;
+                            queue.push_back((ny, nx));
+                        }
+                    }
+                }
+                for (y, row) in maze.cells.iter().enumerate() {
+                    for (x, cell) in row.iter().enumerate() {
+                        assert!(cell.wall || reached[y][x], "{:?} is cut off", (y, x));
+                    }
+                }
+                assert!(maze
+                    .solve_from(SolvingAlgorithm::RecursiveBacktracking)
+                    .is_ok());
+            }
+        }
+
+        #[test]
+        fn cave_fill_probability_that_isnt_finite_counts_as_zero() {
+            let cave = |fill_probability| CreationAlgorithm::Cellular {
+                fill_probability,
+                iterations: 3,
+            };
+            for fill_probability in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+                let mut maze = Maze::new_from((21, 21), cave(fill_probability));
+                assert!(maze
+                    .solve_from(SolvingAlgorithm::RecursiveBacktracking)
+                    .is_ok());
+            }
+            // finite probabilities out of range are still clamped
+            let mut maze = Maze::new_from((21, 21), cave(1.5));
+            assert!(maze
+                .solve_from(SolvingAlgorithm::RecursiveBacktracking)
+                .is_ok());
+        }
+
+        #[test]
+        fn straighter_weighted_prim_has_fewer_junctions() {
+            let junctions = |straightness: f64| -> usize {
+                (0..6)
+                    .map(|_| {
+                        junction_count(&Maze::new_from(
+                            (41, 41),
+                            CreationAlgorithm::WeightedPrim { straightness },
+                        ))
+                    })
+                    .sum()
+            };
+            let (bushy, middling, straight) = (junctions(0.0), junctions(0.5), junctions(1.0));
+            assert!(straight < middling && middling < bushy);
+            assert!(straight * 4 < bushy, "{} vs {}", straight, bushy);
+        }
+
+        #[test]
+        fn straightness_that_isnt_finite_counts_as_zero() {
+            for straightness in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+                let mut maze =
+                    Maze::new_from((21, 21), CreationAlgorithm::WeightedPrim { straightness });
+                // like classic Prim, it's still a perfect Maze
+                assert!(maze.solve_from(SolvingAlgorithm::DeadEndFilling).is_ok());
+            }
+        }
+
+        #[cfg(feature = "image")]
+        #[test]
+        fn image_headers_claiming_more_pixels_than_the_file_has_are_rejected() {
+            let path =
+                std::env::temp_dir().join(format!("rust-maze-header-{}.pnm", std::process::id()));
+            for image in [
+                &b"P5 1 99999999999999 255\n"[..],
+                b"P6 99999999999 99999999999 65535\n",
+                b"P4 64 4\n\x00\x00",
+                b"P2 3 3 255\n0 255 0",
+                b"P1 0 3\n",
+            ] {
+                std::fs::write(&path, image).unwrap();
+                assert!(
+                    matches!(
+                        Maze::from_image_mask(&path, 128, CreationAlgorithm::Prim),
+                        Err(MazeError::ParseError(_))
+                    ),
+                    "{:?} was read",
+                    String::from_utf8_lossy(image)
+                );
+            }
+
+            // while one that has them all is still read
+            std::fs::write(
+                &path,
+                b"P5 5 5 255\n"
+                    .iter()
+                    .chain(&[255; 25])
+                    .copied()
+                    .collect::<Vec<u8>>(),
+            )
+            .unwrap();
+            assert!(Maze::from_image_mask(&path, 128, CreationAlgorithm::Prim).is_ok());
+            std::fs::remove_file(&path).unwrap();
+        }
+
+        #[test]
+        fn symmetric_mazes_mirror_their_walls_away_from_the_seams() {
+            for (name, symmetry) in [
+                ("horizontal", Symmetry::Horizontal),
+                ("vertical", Symmetry::Vertical),
+                ("quad", Symmetry::Quad),
+                ("half turn", Symmetry::Rotational180),
+            ] {
+                let mut maze =
+                    Maze::new_symmetric((21, 29), CreationAlgorithm::Kruskal, symmetry).unwrap();
+                let (height, width) = maze.dimensions;
+                let (flip_rows, flip_cols) = match symmetry {
+                    Symmetry::Horizontal => (false, true),
+                    Symmetry::Vertical => (true, false),
+                    _ => (true, true),
+                };
+                for (row, col) in (0..height).flat_map(|row| (0..width).map(move |col| (row, col)))
+                {
+                    // the passages stitching the copies together only go through the seams, and
+                    // only two copies of the entrypoint are left open, as the openings
+                    if (flip_rows && row == height / 2)
+                        || (flip_cols && col == width / 2)
+                        || row == 0
+                        || col == 0
+                        || row == height - 1
+                        || col == width - 1
+                    {
+                        continue;
+                    }
+                    let image = (
+                        if flip_rows { height - 1 - row } else { row },
+                        if flip_cols { width - 1 - col } else { col },
+                    );
+                    // a quad Maze is mirrored both ways, which a half turn alone isn't
+                    let images: Vec<(usize, usize)> = match symmetry {
+                        Symmetry::Quad => vec![(row, image.1), (image.0, col), image],
+                        _ => vec![image],
+                    };
+                    for (y, x) in images {
+                        assert_eq!(
+                            maze.cells[row][col].wall, maze.cells[y][x].wall,
+                            "{} at ({}, {})",
+                            name, row, col
+                        );
+                    }
+                }
+                let entrance = maze.entrypoint;
+                let mirrored_entrance = (
+                    if flip_rows {
+                        height - 1 - entrance.0
+                    } else {
+                        entrance.0
+                    },
+                    if flip_cols {
+                        width - 1 - entrance.1
+                    } else {
+                        entrance.1
+                    },
+                );
+                assert_eq!(maze.goalpoint, mirrored_entrance, "{}", name);
+                let reached = Maze::flood(&maze.cells, maze.entrypoint);
+                for (y, row) in maze.cells.iter().enumerate() {
+                    for (x, cell) in row.iter().enumerate() {
+                        assert!(cell.wall || reached[y][x], "{} cuts off {:?}", name, (y, x));
+                    }
+                }
+                assert!(maze
+                    .solve_from(SolvingAlgorithm::RecursiveBacktracking)
+                    .is_ok());
+            }
+        }
+
+        #[test]
+        fn symmetric_maze_reports_bad_dimensions() {
+            for symmetry in [Symmetry::None, Symmetry::Horizontal, Symmetry::Quad] {
+                assert!(matches!(
+                    Maze::new_symmetric((2, 21), CreationAlgorithm::Prim, symmetry),
+                    Err(MazeError::DimensionsTooSmall)
+                ));
+                assert!(matches!(
+                    Maze::new_symmetric((usize::MAX, 21), CreationAlgorithm::Prim, symmetry),
+                    Err(MazeError::DimensionsTooLarge)
+                ));
+            }
+            // rounding a mirrored side up can push it over too
+            assert!(matches!(
+                Maze::new_symmetric(
+                    (65_537, 65_535),
+                    CreationAlgorithm::Prim,
+                    Symmetry::Horizontal
+                ),
+                Err(MazeError::DimensionsTooLarge)
+            ));
+        }
+
+        #[test]
+        fn min_solution_maze_meets_a_reachable_minimum() {
+            let mut maze =
+                Maze::new_with_min_solution((21, 21), CreationAlgorithm::Prim, 0.1).unwrap();
+            let open = maze
+                .cells
+                .iter()
+                .flatten()
+                .filter(|cell| !cell.wall)
+                .count();
+            assert!(maze.solve());
+            let marked = maze.solution_length();
+            assert!(marked as f64 / open as f64 >= 0.1);
+        }
+
+        #[test]
+        fn min_solution_maze_reports_an_unreachable_minimum() {
+            // Prim's junctions make a solution through every open cell impossible
+            match Maze::new_with_min_solution((21, 21), CreationAlgorithm::Prim, 1.0) {
+                Err(MazeError::SolutionTooShort(best)) => assert!(best > 0.0 && best < 1.0),
+                other => panic!("expected SolutionTooShort, got {:?}", other.map(|_| ())),
+            }
+        }
+
+        #[test]
+        fn min_solution_maze_reports_bad_dimensions() {
+            assert!(matches!(
+                Maze::new_with_min_solution((2, 21), CreationAlgorithm::Prim, 0.1),
+                Err(MazeError::DimensionsTooSmall)
+            ));
+        }
+
+        #[test]
+        fn regenerated_region_stays_connected_and_leaves_the_rest_alone() {
+            for _ in 0..10 {
+                let mut maze = Maze::new_from((31, 41), CreationAlgorithm::Kruskal);
+                let before = maze.clone();
+                maze.regenerate_region((6, 10), (20, 28), CreationAlgorithm::HuntAndKill)
+                    .unwrap();
+                let reached = Maze::flood(&maze.cells, maze.entrypoint);
+                for (row, col) in (0..31).flat_map(|row| (0..41).map(move |col| (row, col))) {
+                    assert!(
+                        maze.cells[row][col].wall || reached[row][col],
+                        "({}, {}) is cut off",
+                        row,
+                        col
+                    );
+                    if (6..=20).contains(&row) && (10..=28).contains(&col) {
+                        continue;
+                    }
+                    assert_eq!(
+                        maze.cells[row][col].wall, before.cells[row][col].wall,
+                        "({}, {}) changed",
+                        row, col
+                    );
+                }
+                assert!(maze
+                    .solve_from(SolvingAlgorithm::RecursiveBacktracking)
+                    .is_ok());
+            }
+        }
+
+        #[test]
+        fn regenerating_a_region_clears_the_solution() {
+            let mut maze = Maze::new_from((21, 21), CreationAlgorithm::Prim);
+            assert!(maze.solve());
+            maze.regenerate_region((4, 4), (14, 14), CreationAlgorithm::Prim)
+                .unwrap();
+            assert!(!maze.is_solved());
+            assert!(maze.overlay().is_none());
+        }
+
+        #[test]
+        fn regenerating_a_region_it_cant_leaves_the_maze_alone() {
+            let mut maze = Maze::new_from((21, 21), CreationAlgorithm::Prim);
+            let walls = |maze: &Maze| -> Vec<bool> {
+                maze.cells.iter().flatten().map(|cell| cell.wall).collect()
+            };
+            let before = walls(&maze);
+            assert!(matches!(
+                maze.regenerate_region((4, 4), (30, 14), CreationAlgorithm::Prim),
+                Err(MazeError::InvalidRegion)
+            ));
+            let cave = CreationAlgorithm::Cellular {
+                fill_probability: f64::NAN,
+                iterations: 2,
+            };
+            assert!(matches!(
+                maze.regenerate_region((4, 4), (14, 14), cave),
+                Err(MazeError::InvalidParameter("fill_probability"))
+            ));
+            assert_eq!(walls(&maze), before);
+        }
+
+        #[test]
+        fn fractal_maze_is_perfect_and_rounds_up_to_a_power_of_two() {
+            for _ in 0..5 {
+                let mut maze = Maze::new_from((20, 40), CreationAlgorithm::Fractal);
+                assert_eq!(maze.dimensions, (33, 65));
+                assert!(is_perfect(&maze));
+                assert!(maze.solve_from(SolvingAlgorithm::DeadEndFilling).is_ok());
+            }
+        }
+
+        #[test]
+        #[ignore = "timing comparison; run with --release -- --ignored"]
+        fn fractal_generates_faster_than_prim() {
+            use std::time::Instant;
+
+            let start = Instant::now();
+            let fractal = Maze::new_from((1025, 1025), CreationAlgorithm::Fractal);
+            let fractal_time = start.elapsed();
+            let start = Instant::now();
+            let prim = Maze::new_from((1025, 1025), CreationAlgorithm::Prim);
+            let prim_time = start.elapsed();
+
+            assert!(is_perfect(&fractal) && is_perfect(&prim));
+            assert!(
+                fractal_time < prim_time,
+                "Fractal took {:?}, Prim took {:?}",
+                fractal_time,
+                prim_time
+            );
+        }
+
+        #[test]
+        fn a_star_finds_paths_as_short_as_breadth_first_search_on_braided_mazes() {
+            let marked = |maze: &Maze| maze.solution_length();
+            for _ in 0..10 {
+                let mut maze = Maze::new_from((41, 41), CreationAlgorithm::Prim);
+                maze.braid(0.5);
+                let mut bfs = maze.clone();
+                assert!(bfs.solve_from(SolvingAlgorithm::BreadthFirst).is_ok());
+                assert!(maze
+                    .solve_from(SolvingAlgorithm::AStar(Heuristic::Manhattan))
+                    .is_ok());
+                assert_eq!(marked(&maze), marked(&bfs));
+            }
+        }
+
+        #[test]
+        fn a_star_explores_fewer_cells_than_breadth_first_search() {
+            let (mut a_star, mut bfs) = (0, 0);
+            for _ in 0..10 {
+                let mut maze = Maze::new_from((81, 81), CreationAlgorithm::Prim);
+                maze.braid(0.5);
+                maze.solve_from(SolvingAlgorithm::BreadthFirst).unwrap();
+                bfs += maze.cells_explored();
+                maze.solve_from(SolvingAlgorithm::AStar(Heuristic::Manhattan))
+                    .unwrap();
+                a_star += maze.cells_explored();
+            }
+            assert!(a_star < bfs, "A* explored {}, BFS explored {}", a_star, bfs);
+        }
+
+        #[test]
+        fn a_star_breaks_ties_the_same_way_every_time() {
+            let mut maze = Maze::new_from((41, 41), CreationAlgorithm::Prim);
+            maze.braid(1.0);
+            let mut other = maze.clone();
+            maze.solve_from(SolvingAlgorithm::AStar(Heuristic::Manhattan))
+                .unwrap();
+            other
+                .solve_from(SolvingAlgorithm::AStar(Heuristic::Manhattan))
+                .unwrap();
+            let marks = |maze: &Maze| maze.overlay().cloned();
+            assert_eq!(marks(&maze), marks(&other));
+            assert_eq!(maze.cells_explored(), other.cells_explored());
+        }
+
+        #[test]
+        fn right_hand_walks_into_a_dead_end_the_left_hand_skips() {
+            // coming down from the entrance, the left hand turns east toward the exit, while the
+            // right hand turns west into the dead end and has to come back
+            let mut maze = from_rows(&["###S###", "#     G", "#######"]);
+            assert!(maze.solve_from(SolvingAlgorithm::WallFollowerLeft).is_ok());
+            assert_eq!(maze.solution_length(), 5);
+            assert!(maze.solve_from(SolvingAlgorithm::WallFollowerRight).is_ok());
+            assert_eq!(maze.solution_length(), 7);
+            assert_eq!(maze.last_solution_steps(), Some(8));
+        }
+
+        #[test]
+        fn left_hand_walks_into_a_dead_end_the_right_hand_skips() {
+            let mut maze = from_rows(&["###S###", "G     #", "#######"]);
+            assert!(maze.solve_from(SolvingAlgorithm::WallFollowerLeft).is_ok());
+            assert_eq!(maze.solution_length(), 7);
+            assert_eq!(maze.last_solution_steps(), Some(8));
+            assert!(maze.solve_from(SolvingAlgorithm::WallFollowerRight).is_ok());
+            assert_eq!(maze.solution_length(), 5);
+        }
+
+        #[test]
+        fn tremaux_solves_a_ring_that_dead_end_filling_cant_narrow_down() {
+            // every passage is on a loop, so there's no dead end to fill in
+            let maze = from_rows(&["#S#####", "#     #", "# # # #", "#     G", "#######"]);
+            let mut filled = maze.clone();
+            assert!(filled.solve_from(SolvingAlgorithm::DeadEndFilling).is_ok());
+            assert_eq!(filled.last_cells_filled(), Some(0));
+            assert!(filled.last_completed_by_bfs());
+
+            let mut tremaux = maze.clone();
+            assert!(tremaux.solve_from(SolvingAlgorithm::Tremaux).is_ok());
+            assert!(tremaux.verify_solution().unwrap().simple);
+        }
+
+        #[test]
+        fn tremaux_marks_a_simple_path_through_braided_mazes() {
+            for _ in 0..10 {
+                let mut maze = Maze::new_from((31, 31), CreationAlgorithm::Kruskal);
+                maze.braid(1.0);
+                assert!(maze.solve_from(SolvingAlgorithm::Tremaux).is_ok());
+                assert!(maze.verify_solution().unwrap().simple);
+            }
+        }
+
+        #[test]
+        fn bidirectional_path_runs_unbroken_from_entrance_to_exit() {
+            for _ in 0..10 {
+                let mut maze = Maze::new_from((41, 41), CreationAlgorithm::Prim);
+                maze.braid(0.5);
+                let mut bfs = maze.clone();
+                assert!(bfs.solve_from(SolvingAlgorithm::BreadthFirst).is_ok());
+                assert!(maze.solve_from(SolvingAlgorithm::BidirectionalBfs).is_ok());
+                // the cell where the frontiers met is marked like every other, and nothing else is
+                assert!(maze.verify_solution().unwrap().simple);
+                assert_eq!(maze.solution_length(), bfs.solution_length());
+            }
+        }
+
+        #[test]
+        fn iterative_backtracking_marks_the_same_path_as_recursive() {
+            let marks = |maze: &Maze| maze.overlay().cloned();
+            for braided in [false, true] {
+                for _ in 0..5 {
+                    let mut maze = Maze::new_from((41, 41), CreationAlgorithm::Kruskal);
+                    if braided {
+                        maze.braid(0.5);
+                    }
+                    let mut recursive = maze.clone();
+                    assert!(recursive
+                        .solve_from(SolvingAlgorithm::RecursiveBacktracking)
+                        .is_ok());
+                    assert!(maze
+                        .solve_from(SolvingAlgorithm::IterativeBacktracking)
+                        .is_ok());
+                    assert_eq!(marks(&maze), marks(&recursive));
+                }
+            }
+        }
+
+        #[test]
+        fn iterative_backtracking_solves_a_huge_maze() {
+            let mut maze = Maze::new_from((2001, 2001), CreationAlgorithm::Kruskal);
+            assert!(maze
+                .solve_from(SolvingAlgorithm::IterativeBacktracking)
+                .is_ok());
+            assert!(maze.verify_solution().unwrap().simple);
+        }
+
+        #[test]
+        fn pledge_leaves_an_island_that_traps_wall_followers() {
+            // starting beside a pillar in the middle, either hand just follows it around and
+            // around; the Pledge walker heads east for the exit once it's turned back that way
+            let maze = from_rows(&[
+                "#########",
+                "#       #",
+                "# S###  #",
+                "#  ###  G",
+                "#       #",
+                "#########",
+            ]);
+            for algorithm in [
+                SolvingAlgorithm::WallFollowerLeft,
+                SolvingAlgorithm::WallFollowerRight,
+            ] {
+                let mut follower = maze.clone();
+                assert!(follower.solve_from(algorithm).is_err());
+                assert!(!follower.is_solved());
+            }
+
+            let mut pledge = maze.clone();
+            assert!(pledge.solve_from(SolvingAlgorithm::Pledge).is_ok());
+            assert!(pledge.is_marked((3, 8)));
+            assert!(pledge.last_solution_steps().is_some());
+        }
+
+        #[test]
+        fn solving_between_a_cell_and_itself_marks_just_that_cell() {
+            let mut maze = from_rows(&["#S###", "#   #", "# ###", "#   G", "#####"]);
+            for algorithm in [
+                SolvingAlgorithm::BreadthFirst,
+                SolvingAlgorithm::RecursiveBacktracking,
+                SolvingAlgorithm::AStar(Heuristic::Manhattan),
+            ] {
+                let path = maze.solve_between((1, 3), (1, 3), algorithm).unwrap();
+                assert_eq!(path.cells, vec![(1, 3)]);
+                assert_eq!(maze.solution_length(), 1);
+                assert_eq!((maze.entrypoint, maze.goalpoint), ((0, 1), (3, 4)));
+                maze.unsolve();
+                assert_eq!(maze.solution_length(), 0);
+            }
+        }
+
+        #[test]
+        fn solving_between_adjacent_cells_takes_one_step() {
+            let mut maze = from_rows(&["#S###", "#   #", "# ###", "#   G", "#####"]);
+            let path = maze
+                .solve_between((3, 2), (3, 3), SolvingAlgorithm::BreadthFirst)
+                .unwrap();
+            assert_eq!(path.cells, vec![(3, 2), (3, 3)]);
+            assert_eq!((maze.entrypoint, maze.goalpoint), ((0, 1), (3, 4)));
+        }
+
+        #[test]
+        fn solving_between_cells_rejects_walls_and_cells_outside() {
+            let mut maze = from_rows(&["#S###", "#   #", "# ###", "#   G", "#####"]);
+            assert!(matches!(
+                maze.solve_between((2, 2), (3, 3), SolvingAlgorithm::BreadthFirst),
+                Err(MazeError::NotOpen((2, 2)))
+            ));
+            assert!(matches!(
+                maze.solve_between((1, 1), (5, 1), SolvingAlgorithm::BreadthFirst),
+                Err(MazeError::InvalidCoordinate((5, 1)))
+            ));
+            assert!(!maze.is_solved());
+            assert_eq!(maze.solution_length(), 0);
+        }
+
+        // searches depth-first from the entrance, giving up on any way that's taken more than limit
+        // cells without reaching the exit
+        struct DepthLimited {
+            limit: usize,
+        }
+
+        impl Solver for DepthLimited {
+            fn solve(&self, maze: &MazeView) -> Option<Path> {
+                fn search(maze: &MazeView, cells: &mut Vec<(usize, usize)>, limit: usize) -> bool {
+                    let here = *cells.last().expect("the search starts at the entrance");
+                    if here == maze.exit() {
+                        return true;
+                    }
+                    if cells.len() == limit {
+                        return false;
+                    }
+                    for next in maze.neighbors(here) {
+                        if cells.contains(&next) {
+                            continue;
+                        }
+                        cells.push(next);
+                        if search(maze, cells, limit) {
+                            return true;
+                        }
+                        cells.pop();
+                    }
+                    false
+                }
+
+                let mut cells = vec![maze.entrance()];
+                search(maze, &mut cells, self.limit).then(|| Path::new(cells))
+            }
+        }
+
+        #[test]
+        fn depth_limited_solver_finds_the_path_only_within_its_limit() {
+            let maze = Maze::new_from((21, 21), CreationAlgorithm::Prim);
+            let mut shortest = maze.clone();
+            assert!(shortest.solve_from(SolvingAlgorithm::BreadthFirst).is_ok());
+            let length = shortest.solution_length();
+
+            let mut deep_enough = maze.clone();
+            assert!(deep_enough.solve_with(&DepthLimited { limit: length }));
+            assert_eq!(deep_enough.marked_path(), shortest.marked_path());
+
+            let mut too_shallow = maze.clone();
+            assert!(!too_shallow.solve_with(&DepthLimited { limit: length - 1 }));
+            assert!(!too_shallow.is_solved());
+        }
+
+        #[test]
+        fn built_in_solver_marks_the_same_path_through_solve_with() {
+            for _ in 0..5 {
+                let mut maze = Maze::new_from((31, 31), CreationAlgorithm::Kruskal);
+                maze.braid(0.5);
+                let mut solved = maze.clone();
+                assert!(solved
+                    .solve_from(SolvingAlgorithm::AStar(Heuristic::Manhattan))
+                    .is_ok());
+                assert!(maze.solve_with(&SolvingAlgorithm::AStar(Heuristic::Manhattan)));
+                assert_eq!(maze.marked_path(), solved.marked_path());
+                assert_eq!(maze.solution_length(), solved.solution_length());
+            }
+        }
+
+        #[test]
+        fn weighted_a_star_explores_no_more_than_manhattan() {
+            // inflating the heuristic can still send the search a little further down a wrong way
+            // now and then, so what's compared is how much it explores over all of them
+            let (mut weighted_explored, mut manhattan_explored) = (0, 0);
+            for _ in 0..10 {
+                let mut maze = Maze::new_from((41, 41), CreationAlgorithm::Prim);
+                maze.braid(1.0);
+                assert!(maze.solve_from(SolvingAlgorithm::BreadthFirst).is_ok());
+                let shortest = maze.solution_length();
+                assert!(maze
+                    .solve_from(SolvingAlgorithm::AStar(Heuristic::Manhattan))
+                    .is_ok());
+                assert_eq!(maze.solution_length(), shortest);
+                manhattan_explored += maze.cells_explored();
+                assert!(maze
+                    .solve_from(SolvingAlgorithm::AStar(Heuristic::Weighted(2.0)))
+                    .is_ok());
+                assert!(maze.solution_length() >= shortest);
+                weighted_explored += maze.cells_explored();
+            }
+            assert!(
+                weighted_explored <= manhattan_explored,
+                "weighted explored {}, Manhattan {}",
+                weighted_explored,
+                manhattan_explored
+            );
+        }
+
+        #[test]
+        fn dead_end_filling_on_a_looped_maze_fills_the_dead_end_then_finishes_by_bfs() {
+            // the branch down the left side is the only dead end; past it, the two ways around the
+            // middle are still both open once it's filled in
+            let mut maze = from_rows(&[
+                "#S#####", "#     #", "# # # #", "#     G", "# #####", "# #####", "#######",
+            ]);
+            assert!(maze.solve_from(SolvingAlgorithm::DeadEndFilling).is_ok());
+            assert_eq!(maze.last_cells_filled(), Some(2));
+            assert!(maze.last_completed_by_bfs());
+            assert_eq!(maze.solution_length(), 9);
+            assert!(!maze.is_marked((4, 1)) && !maze.is_marked((5, 1)));
+            assert!(maze.verify_solution().unwrap().simple);
+        }
+
+        #[test]
+        fn dead_end_filling_on_a_perfect_maze_needs_no_bfs() {
+            for _ in 0..5 {
+                let mut maze = Maze::new_from((31, 31), CreationAlgorithm::Kruskal);
+                assert!(maze.solve_from(SolvingAlgorithm::DeadEndFilling).is_ok());
+                assert!(!maze.last_completed_by_bfs());
+                assert!(maze.last_cells_filled().is_some_and(|filled| filled > 0));
+                assert!(maze.verify_solution().unwrap().simple);
+            }
+        }
+
+        #[test]
+        fn lee_backtraces_a_path_as_short_as_breadth_first_search() {
+            for _ in 0..10 {
+                let mut maze = Maze::new_from((41, 41), CreationAlgorithm::Kruskal);
+                maze.braid(0.5);
+                let bfs = maze
+                    .clone()
+                    .solve_from(SolvingAlgorithm::BreadthFirst)
+                    .unwrap();
+                let lee = maze.solve_from(SolvingAlgorithm::Lee).unwrap();
+                assert_eq!(lee.path_length, bfs.path_length);
+                assert!(maze.verify_solution().unwrap().simple);
+                let waves = maze.last_wave_grid().unwrap();
+                let (y, x) = maze.goalpoint;
+                assert_eq!(waves[y][x], Some(lee.path_length - 1));
+            }
+        }
+
+        #[test]
+        fn lee_waves_number_every_cell_they_reach() {
+            let mut maze = from_rows(&["#S############", "#            G", "##############"]);
+            assert!(maze.render_waves().is_none());
+            maze.solve_from(SolvingAlgorithm::Lee).unwrap();
+            let waves = maze.last_wave_grid().unwrap();
+            assert_eq!(waves[1][12], Some(12));
+            assert_eq!(waves[0][12], None);
+            assert!(maze.render_waves().is_some());
+            // another solver's solve doesn't leave the waves behind
+            maze.solve_from(SolvingAlgorithm::BreadthFirst).unwrap();
+            assert!(maze.last_wave_grid().is_none());
+        }
+
+        #[test]
+        fn builder_places_the_openings_where_asked() {
+            let mut maze = Maze::builder()
+                .dimensions(11, 11)
+                .algorithm(CreationAlgorithm::Kruskal)
+                .entrance(Edge::Left, 2)
+                .exit(Edge::Top, 4)
+                .build()
+                .unwrap();
+            assert_eq!((maze.entrypoint, maze.goalpoint), ((5, 0), (0, 9)));
+            assert!(maze.solve_from(SolvingAlgorithm::BreadthFirst).is_ok());
+
+            let openings = |entrance, exit| {
+                Maze::builder()
+                    .dimensions(11, 11)
+                    .entrance(Edge::Top, entrance)
+                    .exit(Edge::Top, exit)
+                    .build()
+            };
+            assert!(matches!(
+                openings(0, 5),
+                Err(MazeError::OpeningOutOfRange(Edge::Top, 5))
+            ));
+            assert!(matches!(openings(3, 3), Err(MazeError::SameOpenings)));
+            assert!(matches!(
+                Maze::builder().dimensions(2, 11).build(),
+                Err(MazeError::DimensionsTooSmall)
+            ));
+        }
+
+        #[test]
+        fn builder_rejects_probabilities_that_arent_finite() {
+            let cave = CreationAlgorithm::Cellular {
+                fill_probability: f64::NAN,
+                iterations: 3,
+            };
+            assert!(matches!(
+                Maze::builder().dimensions(21, 21).algorithm(cave).build(),
+                Err(MazeError::InvalidParameter("fill_probability"))
+            ));
+            // while the constructors that can't fail count it as 0
+            assert!(Maze::new_with_loops((21, 21), cave, 0.5).validate_connected());
+        }
+
+        // hashes the openings and walls of the given Maze with 64-bit FNV-1a, which comes out the
+        // same on every platform and Rust version
+        fn walls_hash(maze: &Maze) -> u64 {
+            let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+            let cells = maze.cells.iter().flatten().map(|cell| cell.wall as u64);
+            let openings = [maze.entrypoint, maze.goalpoint]
+                .into_iter()
+                .flat_map(|(y, x)| [y as u64, x as u64]);
+            for value in openings.chain(cells) {
+                hash = (hash ^ value).wrapping_mul(0x0100_0000_01b3);
+            }
+            hash
+        }
+
+        #[test]
+        fn seeded_mazes_keep_their_exact_walls() {
+            use CreationAlgorithm::*;
+            // if one of these changes, every maze anyone saved by its seed changes with it
+            let pinned = [
+                (RandomWalk, 0x4ea6_957d_9e91_abd7),
+                (
+                    RecursiveDivision {
+                        room_chance: 0.2,
+                        min_room_size: 3,
+                    },
+                    0x06bf_9ca9_a423_a243,
+                ),
+                (Prim, 0x6df1_191c_24e8_fa5f),
+                (Kruskal, 0x69b4_bd01_34e1_f94f),
+                (Eller, 0xadff_6b57_91de_612f),
+                (Wilson, 0xb977_7427_bd01_7b2b),
+                (AldousBroder, 0xd5f3_51ac_a8e1_fb33),
+                (HuntAndKill, 0x81ac_2f32_4d10_8c6f),
+                (Sidewinder, 0xd52d_eca2_49bb_fe4f),
+                (BinaryTree(Bias::NorthEast), 0x3e8d_0f1c_6d82_5163),
+                (GrowingTree(GrowStrategy::Mix(50)), 0x07ff_c56c_3e85_852b),
+                (RecursiveBacktracker, 0xa839_75b0_7baf_080b),
+                (OriginShift { iterations: 500 }, 0x2e01_a46c_9492_4d27),
+                (
+                    Dungeon {
+                        room_attempts: 20,
+                        min_room: 3,
+                        max_room: 7,
+                    },
+                    0x6fb5_4b7a_e1be_57af,
+                ),
+                (Unicursal, 0x7595_fb62_3a88_53e3),
+                (
+                    Cellular {
+                        fill_probability: 0.45,
+                        iterations: 4,
+                    },
+                    0x7b81_6a83_4138_3f52,
+                ),
+                (WeightedPrim { straightness: 0.5 }, 0x1657_c18b_b41a_cd8b),
+                (Fractal, 0xf345_8a48_26f1_9803),
+            ];
+            for (index, (algorithm, hash)) in pinned.into_iter().enumerate() {
+                let maze = Maze::new_seeded((21, 21), algorithm, 2024);
+                assert_eq!(walls_hash(&maze), hash, "algorithm {}", index);
+            }
+        }
+
+        #[test]
+        fn seeded_changes_to_a_maze_repeat_themselves() {
+            let changed = |seed: u64| {
+                let mut rng = StdRng::seed_from_u64(seed);
+                let mut maze = Maze::new_symmetric_from_rng(
+                    (21, 29),
+                    CreationAlgorithm::Kruskal,
+                    Symmetry::Quad,
+                    &mut rng,
+                )
+                .unwrap();
+                maze.shift_origin_from_rng(50, &mut rng);
+                maze.regenerate_region_from_rng(
+                    (4, 4),
+                    (14, 14),
+                    CreationAlgorithm::Wilson,
+                    &mut rng,
+                )
+                .unwrap();
+                maze.to_string()
+            };
+            assert_eq!(changed(3), changed(3));
+            assert_ne!(changed(3), changed(4));
+        }
+
+        #[test]
+        fn try_new_reports_what_new_from_would_panic_on() {
+            assert!(matches!(
+                Maze::try_new((2, 21), CreationAlgorithm::Prim),
+                Err(MazeError::DimensionsTooSmall)
+            ));
+            assert!(matches!(
+                Maze::try_new((usize::MAX, 3), CreationAlgorithm::Prim),
+                Err(MazeError::DimensionsTooLarge)
+            ));
+            // tessellation rounds up to one more than a power of two, which can be what's too large
+            assert!(matches!(
+                Maze::try_new((65_537, 40_000), CreationAlgorithm::Fractal),
+                Err(MazeError::DimensionsTooLarge)
+            ));
+            let weighted = CreationAlgorithm::WeightedPrim {
+                straightness: f64::NAN,
+            };
+            assert!(matches!(
+                Maze::try_new((21, 21), weighted),
+                Err(MazeError::InvalidParameter("straightness"))
+            ));
+            assert!(matches!(
+                Maze::new_symmetric((21, 21), weighted, Symmetry::Quad),
+                Err(MazeError::InvalidParameter("straightness"))
+            ));
+            assert!(Maze::try_new((10, 10), CreationAlgorithm::Wilson).is_ok());
+        }
+
+        #[test]
+        fn searchers_head_for_the_nearest_exit_and_walkers_stop_at_the_first() {
+            // the nearer exit is at the bottom, down the left side, and the other off a branch to
+            // the right
+            let mut maze = from_rows(&[
+                "#S#####", "# #   #", "# # # #", "#   # #", "# ### #", "#     #", "####G##",
+            ]);
+            maze.add_exit((1, 6)).unwrap();
+            let report = maze.solve_from(SolvingAlgorithm::BreadthFirst).unwrap();
+            assert_eq!((report.exit, report.path_length), ((6, 4), 10));
+
+            // the left hand takes the branch first, and stops at the exit on it
+            let report = maze.solve_from(SolvingAlgorithm::WallFollowerLeft).unwrap();
+            assert_eq!(report.exit, (1, 6));
+            assert!(maze.verify_solution().unwrap().simple);
+
+            maze.remove_exit((1, 6)).unwrap();
+            maze.remove_exit((6, 4)).unwrap();
+            assert!(matches!(
+                maze.solve_from(SolvingAlgorithm::BreadthFirst),
+                Err(MazeError::NoExit)
+            ));
+        }
+
+        #[test]
+        fn indexing_reaches_every_corner() {
+            let maze = from_rows(&["#S#####", "#     #", "# ### #", "#     #", "#####G#"]);
+            for corner in [(0, 0), (0, 6), (4, 0), (4, 6)] {
+                assert_eq!(maze[corner], CellKind::Wall, "{:?}", corner);
+                assert_eq!(maze.get(corner), Some(&CellKind::Wall), "{:?}", corner);
+            }
+        }
+
+        #[test]
+        fn indexing_goes_by_row_then_column_out_to_the_last_of_each() {
+            let maze = from_rows(&["#S#####", "#     #", "# ### #", "#     #", "#####G#"]);
+            assert_eq!(maze[(0, 1)], CellKind::Entrance);
+            assert_eq!(maze[(4, 5)], CellKind::Exit);
+            assert_eq!(maze[(3, 5)], CellKind::Passage);
+            assert_eq!(maze[(2, 6)], CellKind::Wall);
+            assert_eq!(maze[(2, 4)], CellKind::Wall);
+            assert_eq!(maze.get((4, 6)), Some(&CellKind::Wall));
+            assert_eq!(maze.get((5, 0)), None);
+            assert_eq!(maze.get((0, 7)), None);
+            assert_eq!(maze.get((usize::MAX, usize::MAX)), None);
+        }
+
+        #[test]
+        #[should_panic(expected = "(5, 0) is outside the 5x7 Maze")]
+        fn indexing_past_the_last_row_panics() {
+            let maze = from_rows(&["#S#####", "#     #", "# ### #", "#     #", "#####G#"]);
+            let _ = maze[(5, 0)];
+        }
+
+        #[test]
+        fn backtracking_counts_the_dead_end_it_backed_out_of() {
+            // backtracking tries South first, so it walks into the dead end below (1, 1), backs
+            // out, and then heads East: seven cells entered, six of them on the path
+            let mut maze = from_rows(&["#S####", "#    G", "# ####", "######"]);
+            let report = maze
+                .solve_from(SolvingAlgorithm::RecursiveBacktracking)
+                .unwrap();
+            assert_eq!(report.path_length, 6);
+            assert_eq!(report.cells_visited, 7);
+            assert_eq!(report.cells_filled, None);
+            assert_eq!(report.exit, (1, 5));
+            assert_eq!(report.path_cost, 5);
+        }
+
+        #[test]
+        fn dead_end_filling_counts_the_cells_it_filled() {
+            // both branches hanging below the corridor lead nowhere, so all four of their cells are
+            // filled in, leaving just the path
+            let mut maze = from_rows(&["#S####", "#    G", "# # ##", "# # ##", "######"]);
+            let report = maze.solve_from(SolvingAlgorithm::DeadEndFilling).unwrap();
+            assert_eq!(report.cells_filled, Some(4));
+            assert_eq!(report.path_length, 6);
+            assert!(!maze.last_completed_by_bfs());
+        }
+
+        #[test]
+        fn solving_a_copy_keeps_it_structurally_equal_but_not_equal() {
+            let maze = Maze::new_seeded((21, 21), CreationAlgorithm::Prim, 5);
+            let mut copy = maze.clone();
+            assert!(copy == maze);
+            assert!(copy.solve());
+            assert!(copy != maze);
+            assert!(copy.structurally_equal(&maze));
+            copy.unsolve();
+            assert!(copy == maze);
+        }
+
+        #[test]
+        fn changing_a_wall_breaks_both_kinds_of_equality() {
+            let maze = Maze::new_seeded((21, 21), CreationAlgorithm::Prim, 5);
+            let mut carved = maze.clone();
+            let (y, x) = maze
+                .walls()
+                .map(|(pos, _)| pos)
+                .find(|&(y, x)| y > 0 && x > 0 && y < 20 && x < 20)
+                .unwrap();
+            carved.cells[y][x].wall = false;
+            assert!(carved != maze);
+            assert!(!carved.structurally_equal(&maze));
+        }
+
+        #[test]
+        fn equal_mazes_collapse_in_a_hash_set() {
+            let maze = Maze::new_seeded((21, 21), CreationAlgorithm::Kruskal, 8);
+            let mut solved = maze.clone();
+            solved.solve();
+            let other = Maze::new_seeded((21, 21), CreationAlgorithm::Kruskal, 9);
+            let set: HashSet<Maze> = [maze.clone(), maze.clone(), solved.clone(), solved, other]
+                .into_iter()
+                .collect();
+            assert_eq!(set.len(), 3);
+            assert!(set.contains(&maze));
+        }
+
+        #[test]
+        fn debug_sums_a_maze_up_without_its_grid() {
+            let mut maze = Maze::new_seeded((21, 21), CreationAlgorithm::Kruskal, 8);
+            let unsolved = format!("{:?}", maze);
+            assert!(unsolved.contains("dimensions: (21, 21)"), "{}", unsolved);
+            assert!(
+                unsolved.contains("algorithm: Some(Kruskal)"),
+                "{}",
+                unsolved
+            );
+            assert!(unsolved.contains("solved: false"), "{}", unsolved);
+            assert!(!unsolved.contains('\u{2588}'), "{}", unsolved);
+            maze.solve();
+            assert!(format!("{:?}", maze).contains("solved: true"));
+        }
+
+        #[cfg(feature = "serde")]
+        #[test]
+        fn serde_json_writes_a_maze_as_to_json_compact_does() {
+            let mut maze = Maze::new_seeded((21, 21), CreationAlgorithm::Kruskal, 71);
+            maze.solve_from(SolvingAlgorithm::BreadthFirst).unwrap();
+            let json = serde_json::to_string(&maze).unwrap();
+            assert_eq!(json, maze.to_json_compact());
+            let read: Maze = serde_json::from_str(&json).unwrap();
+            assert_eq!(read, maze);
+            assert_eq!(read.solution(), maze.solution());
+            let cells: Vec<(usize, usize)> = maze.passages().map(|(pos, _)| pos).collect();
+            maze.set_tag(cells[3], 7).unwrap();
+            maze.set_cost(cells[5], 4).unwrap();
+            let json = serde_json::to_string(&maze).unwrap();
+            assert_eq!(json, maze.to_json_compact());
+            let read: Maze = serde_json::from_str(&json).unwrap();
+            assert_eq!(read.get_tag(cells[3]), Some(7));
+            assert_eq!(read.get_cost(cells[5]), Some(4));
+        }
+
+        #[cfg(feature = "serde")]
+        #[test]
+        fn bincode_packs_a_maze_as_to_bytes_does() {
+            let mut maze = Maze::new_seeded((21, 31), CreationAlgorithm::Eller, 71);
+            let decoded: Maze = bincode::deserialize(&bincode::serialize(&maze).unwrap()).unwrap();
+            assert_eq!(decoded, maze);
+            assert!(!decoded.is_solved());
+            maze.solve_from(SolvingAlgorithm::BreadthFirst).unwrap();
+            let encoded = bincode::serialize(&maze).unwrap();
+            assert!(encoded.ends_with(&maze.to_bytes()));
+            let decoded: Maze = bincode::deserialize(&encoded).unwrap();
+            assert_eq!(decoded, maze);
+        }
+
+        #[cfg(feature = "serde")]
+        #[test]
+        fn serde_refuses_a_corrupt_maze() {
+            let fields = |walls_rle: &str, solution: &str| {
+                format!(
+                    r#"{{"rows":3,"cols":3,"entrance":[1,0],"exit":[1,2],"exits":[[1,2]],"walls_rle":{},"solution":{}}}"#,
+                    walls_rle, solution
+                )
+            };
+            let error = serde_json::from_str::<Maze>(&fields("[3,2]", "[]")).unwrap_err();
+            assert!(error.to_string().contains("don't add up"), "{}", error);
+            assert!(serde_json::from_str::<Maze>(&fields("[3,3,3]", "[]")).is_ok());
+            // the exit is walled up
+            let error = serde_json::from_str::<Maze>(&fields("[3,2,4]", "[]")).unwrap_err();
+            assert!(error.to_string().contains("(1, 2)"), "{}", error);
+            // the solution stops short of the exit
+            let solution = "[[1,0],[1,1]]";
+            assert!(serde_json::from_str::<Maze>(&fields("[3,3,3]", solution)).is_err());
+            let read: Maze =
+                serde_json::from_str(&fields("[3,3,3]", "[[1,0],[1,1],[1,2]]")).unwrap();
+            assert!(read.is_solved());
+        }
+
+        #[cfg(feature = "serde")]
+        #[test]
+        fn serde_round_trips_cell_kinds_and_algorithms() {
+            for kind in [CellKind::Wall, CellKind::Entrance, CellKind::Solution] {
+                let json = serde_json::to_string(&kind).unwrap();
+                assert_eq!(serde_json::from_str::<CellKind>(&json).unwrap(), kind);
+            }
+            for algorithm in [
+                SolvingAlgorithm::BreadthFirst,
+                SolvingAlgorithm::AStar(Heuristic::Weighted(1.5)),
+                SolvingAlgorithm::RandomMouse { max_steps: 9 },
+            ] {
+                let json = serde_json::to_string(&algorithm).unwrap();
+                assert_eq!(
+                    serde_json::from_str::<SolvingAlgorithm>(&json).unwrap(),
+                    algorithm
+                );
+                let encoded = bincode::serialize(&algorithm).unwrap();
+                assert_eq!(
+                    bincode::deserialize::<SolvingAlgorithm>(&encoded).unwrap(),
+                    algorithm
+                );
+            }
+        }
+
+        #[test]
+        fn printing_and_parsing_a_maze_gives_it_back() {
+            for (algorithm, size) in [
+                (CreationAlgorithm::Prim, (15, 25)),
+                (CreationAlgorithm::Kruskal, (21, 21)),
+                (CreationAlgorithm::Sidewinder, (11, 31)),
+                (CreationAlgorithm::HuntAndKill, (25, 9)),
+            ] {
+                for seed in [1, 2, 3] {
+                    let mut maze = Maze::new_seeded(size, algorithm, seed);
+                    for solved in [false, true] {
+                        if solved {
+                            maze.solve();
+                        }
+                        let read: Maze = format!("{}", maze).parse().unwrap();
+                        assert!(
+                            read.structurally_equal(&maze),
+                            "{:?}, seed {}",
+                            algorithm,
+                            seed
+                        );
+                        assert_eq!(read, maze, "{:?}, seed {}", algorithm, seed);
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn parsing_a_maze_says_where_it_goes_wrong() {
+            let wall = "\u{2588}\u{2588}";
+            let entrance = "\u{2592}\u{2592}";
+            let exit = "\u{2591}\u{2591}";
+            let parse = |rows: &[String]| rows.join("\n").parse::<Maze>();
+            let full = format!("{}{}{}", wall, wall, wall);
+            let middle = format!("{}  {}", entrance, exit);
+            assert!(parse(&[full.clone(), middle.clone(), full.clone()]).is_ok());
+
+            let message = |result: Result<Maze, MazeError>| match result {
+                Err(MazeError::ParseError(message)) => message,
+                other => panic!("expected a ParseError, got {:?}", other.map(|_| ())),
+            };
+            let ragged = parse(&[full.clone(), middle.clone(), format!("{}{}", wall, wall)]);
+            assert!(message(ragged).starts_with("line 3, column 3"));
+            let unknown = parse(&[
+                full.clone(),
+                format!("{}xx{}", entrance, exit),
+                full.clone(),
+            ]);
+            assert!(message(unknown).starts_with("line 2, column 2"));
+            let two_entrances = parse(&[
+                full.clone(),
+                middle.clone(),
+                format!("{}{}{}", wall, entrance, wall),
+            ]);
+            assert!(message(two_entrances).starts_with("line 3, column 2"));
+            let no_exit = parse(&[full.clone(), format!("{}  {}", entrance, wall), full]);
+            assert!(message(no_exit).contains("no exit"));
+        }
+
+        #[test]
+        fn from_bytes_refuses_every_truncation() {
+            let mut maze = Maze::new_seeded((21, 21), CreationAlgorithm::Prim, 73);
+            maze.solve();
+            let bytes = maze.to_bytes();
+            for end in 0..bytes.len() {
+                match Maze::from_bytes(&bytes[..end]) {
+                    Err(MazeError::ParseError(problem)) => assert!(
+                        problem == "the bytes end too soon" || problem == "the exits are cut off",
+                        "{} bytes: {}",
+                        end,
+                        problem
+                    ),
+                    other => panic!("{} bytes: {:?}", end, other),
+                }
+            }
+        }
+
+        #[test]
+        fn from_bytes_refuses_bad_magic() {
+            let mut bytes = Maze::new_seeded((21, 21), CreationAlgorithm::Prim, 73).to_bytes();
+            bytes[..4].copy_from_slice(b"MAZY");
+            assert!(matches!(
+                Maze::from_bytes(&bytes),
+                Err(MazeError::ParseError(problem)) if problem == "not a maze"
+            ));
+        }
+
+        #[test]
+        fn from_bytes_refuses_a_future_version() {
+            let mut bytes = Maze::new_seeded((21, 21), CreationAlgorithm::Prim, 73).to_bytes();
+            bytes[4] = BYTES_VERSION + 1;
+            assert!(matches!(
+                Maze::from_bytes(&bytes),
+                Err(MazeError::ParseError(problem)) if problem == "version 2 isn't supported"
+            ));
+        }
+
+        #[test]
+        fn from_bytes_refuses_walls_that_dont_match_the_size() {
+            let bytes = Maze::new_seeded((21, 21), CreationAlgorithm::Prim, 73).to_bytes();
+            // 21 more cells need two more bytes of walls than there are
+            let mut taller = bytes.clone();
+            taller[5..9].copy_from_slice(&22u32.to_le_bytes());
+            assert!(matches!(
+                Maze::from_bytes(&taller),
+                Err(MazeError::ParseError(problem)) if problem == "the bytes end too soon"
+            ));
+            // and 21 fewer leave three of them over
+            let mut shorter = bytes;
+            shorter[5..9].copy_from_slice(&20u32.to_le_bytes());
+            assert!(matches!(
+                Maze::from_bytes(&shorter),
+                Err(MazeError::ParseError(problem)) if problem == "3 bytes left over at the end"
+            ));
+        }
+
+        #[test]
+        fn from_bytes_refuses_an_entrance_outside_the_maze() {
+            let mut bytes = Maze::new_seeded((21, 21), CreationAlgorithm::Prim, 73).to_bytes();
+            // the entrance's row comes right after the flags
+            bytes[14..18].copy_from_slice(&21u32.to_le_bytes());
+            assert!(matches!(
+                Maze::from_bytes(&bytes),
+                Err(MazeError::ParseError(problem))
+                    if problem == "the entrance (21, 0) is outside the maze"
+            ));
+        }
+
+        #[test]
+        fn from_bytes_refuses_a_size_too_small() {
+            let mut bytes = Maze::new_seeded((21, 21), CreationAlgorithm::Prim, 73).to_bytes();
+            bytes[5..9].copy_from_slice(&2u32.to_le_bytes());
+            assert!(matches!(
+                Maze::from_bytes(&bytes),
+                Err(MazeError::DimensionsTooSmall)
+            ));
+        }
+
+        #[cfg(feature = "serde")]
+        #[test]
+        fn bincode_refuses_what_from_bytes_would() {
+            let mut bytes = Maze::new_seeded((21, 21), CreationAlgorithm::Prim, 71).to_bytes();
+            bytes.truncate(bytes.len() / 2);
+            let encoded = bincode::serialize(&bytes).unwrap();
+            let error = bincode::deserialize::<Maze>(&encoded).unwrap_err();
+            assert!(error.to_string().contains("end too soon"), "{}", error);
+        }
+
+        #[test]
+        fn json_round_trips_every_algorithm_with_tags_costs_and_exits() {
+            use CreationAlgorithm::*;
+            let algorithms = [
+                RandomWalk,
+                RecursiveDivision {
+                    room_chance: 0.2,
+                    min_room_size: 3,
+                },
+                Prim,
+                Kruskal,
+                Eller,
+                Wilson,
+                AldousBroder,
+                HuntAndKill,
+                Sidewinder,
+                BinaryTree(Bias::SouthWest),
+                GrowingTree(GrowStrategy::Mix(50)),
+                RecursiveBacktracker,
+                OriginShift { iterations: 500 },
+                Dungeon {
+                    room_attempts: 20,
+                    min_room: 3,
+                    max_room: 7,
+                },
+                Unicursal,
+                Cellular {
+                    fill_probability: 0.45,
+                    iterations: 4,
+                },
+                WeightedPrim { straightness: 0.5 },
+                Fractal,
+            ];
+            for algorithm in algorithms {
+                for seed in [1, 2, 3] {
+                    let mut maze = Maze::new_seeded((17, 17), algorithm, seed);
+                    let cells: Vec<(usize, usize)> = maze.passages().map(|(pos, _)| pos).collect();
+                    maze.set_tag(cells[cells.len() / 2], seed as u32).unwrap();
+                    maze.set_cost(cells[cells.len() / 3], 5).unwrap();
+                    maze.add_exit((0, 1)).unwrap();
+                    // JSON only keeps the path, so solve with something that marks nothing else
+                    maze.solve_from(SolvingAlgorithm::BreadthFirst).unwrap();
+                    for json in [maze.to_json(), maze.to_json_compact()] {
+                        let read = Maze::from_json(&json).unwrap();
+                        assert_eq!(read, maze, "{:?}, seed {}", algorithm, seed);
+                        assert_eq!(read.exits(), maze.exits(), "{:?}", algorithm);
+                        assert_eq!(read.to_json(), maze.to_json(), "{:?}", algorithm);
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn from_json_refuses_runs_that_dont_add_up() {
+            let json = Maze::new_from((5, 3), CreationAlgorithm::Prim).to_json_compact();
+            // the runs for a 5x3 maze are [3,2,2,1,2,2,3]
+            let short = json.replace("\"walls_rle\":[3,", "\"walls_rle\":[2,");
+            let long = json.replace("\"walls_rle\":[3,", "\"walls_rle\":[4294967295,");
+            for json in [short, long] {
+                assert!(matches!(
+                    Maze::from_json(&json),
+                    Err(MazeError::ParseError(problem))
+                        if problem == "the runs of walls_rle don't add up to 15 cells"
+                ));
+            }
+        }
+
+        #[test]
+        fn from_json_refuses_more_cells_than_the_json_could_describe() {
+            // a few runs this long would add up, but would take gigabytes to hold
+            let json = concat!(
+                r#"{"rows":60000,"cols":60000,"entrance":[1,0],"exit":[1,59999],"#,
+                r#""walls_rle":[60000,59999,4294967295,4294967295,4294967295],"solution":[]}"#
+            );
+            assert!(matches!(
+                Maze::from_json(json),
+                Err(MazeError::ParseError(problem))
+                    if problem == "16 values of JSON are too few to describe 60000x60000 cells"
+            ));
+        }
+
+        #[test]
+        fn from_json_still_reads_big_open_mazes() {
+            let algorithm = CreationAlgorithm::RecursiveDivision {
+                room_chance: 1.0,
+                min_room_size: 50,
+            };
+            let maze = Maze::new_seeded((401, 401), algorithm, 75);
+            assert_eq!(Maze::from_json(&maze.to_json_compact()).unwrap(), maze);
+        }
+
+        #[test]
+        fn four_quarter_turns_give_the_maze_back() {
+            for algorithm in [
+                CreationAlgorithm::Kruskal,
+                CreationAlgorithm::Sidewinder,
+                CreationAlgorithm::Dungeon {
+                    room_attempts: 20,
+                    min_room: 3,
+                    max_room: 7,
+                },
+            ] {
+                for seed in [1, 2, 3] {
+                    let mut maze = Maze::new_seeded((15, 27), algorithm, seed);
+                    maze.solve();
+                    let clockwise = maze.rotate_cw().rotate_cw().rotate_cw().rotate_cw();
+                    assert_eq!(clockwise, maze, "{:?}, seed {}", algorithm, seed);
+                    let counterclockwise = maze.rotate_ccw().rotate_ccw().rotate_ccw().rotate_ccw();
+                    assert_eq!(counterclockwise, maze, "{:?}, seed {}", algorithm, seed);
+                    assert_eq!(maze.rotate_cw().rotate_cw(), maze.rotate_ccw().rotate_ccw());
+                }
+            }
+        }
+
+        #[test]
+        fn every_transform_carries_the_solution_along() {
+            for seed in [1, 2, 3] {
+                let mut maze = Maze::new_seeded((15, 27), CreationAlgorithm::Prim, seed);
+                maze.solve_from(SolvingAlgorithm::BreadthFirst).unwrap();
+                let length = maze.solution_length();
+                for (name, transformed) in [
+                    ("rotate_cw", maze.rotate_cw()),
+                    ("rotate_ccw", maze.rotate_ccw()),
+                    ("mirror_horizontal", maze.mirror_horizontal()),
+                    ("mirror_vertical", maze.mirror_vertical()),
+                    ("transpose", maze.transpose()),
+                ] {
+                    let check = transformed.verify_solution().unwrap();
+                    assert!(check.simple, "{}, seed {}", name, seed);
+                    assert_eq!(check.path_length, length, "{}, seed {}", name, seed);
+                    let mut resolved = transformed.clone();
+                    resolved.solve_from(SolvingAlgorithm::BreadthFirst).unwrap();
+                    assert_eq!(resolved, transformed, "{}, seed {}", name, seed);
+                }
+            }
+        }
+
+        #[test]
+        fn is_perfect_accepts_a_tree_of_passages() {
+            let maze = from_rows(&[
+                "#######", "S     #", "# ### #", "# #   #", "# #####", "#     G", "#######",
+            ]);
+            assert!(maze.is_perfect());
+            assert!(Maze::new_seeded((21, 21), CreationAlgorithm::Kruskal, 80).is_perfect());
+        }
+
+        #[test]
+        fn is_perfect_refuses_a_loop() {
+            // joining the top row to the branch below it closes a loop
+            let maze = from_rows(&[
+                "#######", "S     #", "# # # #", "# #   #", "# #####", "#     G", "#######",
+            ]);
+            assert!(!maze.is_perfect());
+            let braided = Maze::builder()
+                .dimensions(21, 21)
+                .algorithm(CreationAlgorithm::Kruskal)
+                .seed(80)
+                .braid(1.0)
+                .build()
+                .unwrap();
+            assert!(!braided.is_perfect());
+        }
+
+        #[test]
+        fn is_perfect_refuses_a_cut_off_passage() {
+            // (3, 3) can't be reached from anywhere
+            let maze = from_rows(&[
+                "#######", "S     #", "# ### #", "# # # #", "# #####", "#     G", "#######",
+            ]);
+            assert!(!maze.is_perfect());
+        }
+
+        #[test]
+        fn is_perfect_isnt_fooled_by_a_loop_and_a_cut_off_passage() {
+            // the loop through the top left has one connection too many, and the cut-off (5, 1) one
+            // too few, so counting connections alone would pass it
+            let maze = from_rows(&[
+                "#######", "S     #", "# # ###", "#     #", "### ###", "# #   G", "#######",
+            ]);
+            let passages = maze.passages().count();
+            let connections = maze
+                .passages()
+                .map(|(pos, _)| maze.degree(pos))
+                .sum::<usize>()
+                / 2;
+            assert_eq!(connections, passages - 1);
+            assert!(!maze.is_perfect());
+        }
+
+        #[test]
+        fn longest_path_runs_the_length_of_a_corridor() {
+            let maze = from_rows(&["#####", "S   G", "#####"]);
+            let path = maze.longest_path();
+            assert_eq!(path.len(), 5);
+            let mut ends = [path.cells()[0], path.cells()[4]];
+            ends.sort();
+            assert_eq!(ends, [(1, 0), (1, 4)]);
+        }
+
+        #[test]
+        fn longest_path_finds_the_far_end_of_a_side_branch() {
+            // the branch off the top row ends at (3, 3), farther from the exit than the entrance is
+            let maze = from_rows(&[
+                "#######", "S     #", "# ### #", "# #   #", "# #####", "#     G", "#######",
+            ]);
+            let path = maze.longest_path();
+            assert_eq!(path.len(), 18);
+            assert!(Path::try_new(path.cells().to_vec()).is_ok());
+            let mut ends = [path.cells()[0], path.cells()[17]];
+            ends.sort();
+            assert_eq!(ends, [(3, 3), (5, 6)]);
+        }
+
+        #[test]
+        fn longest_path_goes_the_short_way_around_a_loop() {
+            // the openings hang off opposite corners of a ring of eight passages
+            let maze = from_rows(&["#####", "S   #", "# # #", "#   G", "#####"]);
+            let path = maze.longest_path();
+            assert_eq!(path.len(), 7);
+            let mut ends = [path.cells()[0], path.cells()[6]];
+            ends.sort();
+            assert_eq!(ends, [(1, 0), (3, 4)]);
+        }
+
+        #[test]
+        fn relocated_openings_end_up_farther_apart() {
+            let mut maze = from_rows(&[
+                "#######", "S     #", "# ### #", "# #   #", "# #####", "#     G", "#######",
+            ]);
+            assert_eq!(maze.distance_between((1, 0), (5, 6)), Some(10));
+            maze.relocate_openings_to_longest_path();
+            // the path inside runs from (3, 3), which opens onto the wall at the nearest spot it
+            // can, to (5, 5), right above the bottom wall; the old openings are walled up
+            assert_eq!(maze.openings(), ((3, 6), (6, 5)));
+            assert_eq!(maze.distance_between((3, 6), (6, 5)), Some(16));
+            assert_eq!(maze[(1, 0)], CellKind::Wall);
+            assert_eq!(maze[(5, 6)], CellKind::Wall);
+        }
+
+        #[test]
+        fn distance_map_leaves_cut_off_passages_unreached() {
+            // (3, 3) can't be reached from anywhere
+            let maze = from_rows(&[
+                "#######", "S     #", "# ### #", "# # # #", "# #####", "#     G", "#######",
+            ]);
+            let distances = maze.distance_map((1, 0)).unwrap();
+            assert_eq!(distances[1][0], Some(0));
+            assert_eq!(distances[5][6], Some(10));
+            assert_eq!(distances[3][5], Some(7));
+            assert_eq!(distances[3][3], None);
+            assert_eq!(distances[0][0], None);
+            assert_eq!(maze.distance_between((1, 0), (3, 3)), None);
+            // and from the cut-off passage, nothing else can be reached
+            let alone = maze.distance_map((3, 3)).unwrap();
+            let reached: Vec<(usize, usize)> = (0..7)
+                .flat_map(|y| (0..7).map(move |x| (y, x)))
+                .filter(|&(y, x)| alone[y][x].is_some())
+                .collect();
+            assert_eq!(reached, vec![(3, 3)]);
+        }
+
+        #[test]
+        fn distance_map_refuses_walls_and_cells_outside_the_maze() {
+            let maze = Maze::new_seeded((21, 21), CreationAlgorithm::Kruskal, 82);
+            assert!(matches!(
+                maze.distance_map((0, 0)),
+                Err(MazeError::NotOpen((0, 0)))
+            ));
+            assert!(matches!(
+                maze.distance_map((21, 3)),
+                Err(MazeError::InvalidCoordinate((21, 3)))
+            ));
+        }
+
+        #[test]
+        fn distance_map_leaves_the_maze_as_it_was() {
+            let mut maze = Maze::new_seeded((21, 21), CreationAlgorithm::Kruskal, 82);
+            maze.solve_from(SolvingAlgorithm::BreadthFirst).unwrap();
+            let before = (maze.clone(), maze.to_bytes(), maze.to_string());
+            let (entrance, exit) = maze.openings();
+            let distances = maze.distance_map(entrance).unwrap();
+            assert_eq!(distances[exit.0][exit.1], Some(maze.solution_length() - 1));
+            assert_eq!((maze.clone(), maze.to_bytes(), maze.to_string()), before);
+        }
+
+        #[test]
+        fn dead_ends_and_junctions_of_a_tree() {
+            // one branch ends at (3, 3), and it splits from the way through at (1, 1)
+            let mut maze = from_rows(&[
+                "#######", "S     #", "# ### #", "# #   #", "# #####", "#     G", "#######",
+            ]);
+            assert_eq!(maze.dead_ends(), vec![(3, 3)]);
+            assert_eq!(maze.junctions(), vec![(1, 1)]);
+            // marking the solution over the junction doesn't hide it
+            maze.solve();
+            assert_eq!(maze.dead_ends(), vec![(3, 3)]);
+            assert_eq!(maze.junctions(), vec![(1, 1)]);
+        }
+
+        #[test]
+        fn dead_ends_and_junctions_of_a_crossroads() {
+            let maze = from_rows(&["#####", "## ##", "S   G", "## ##", "#####"]);
+            assert_eq!(maze.dead_ends(), vec![(1, 2), (3, 2)]);
+            assert_eq!(maze.junctions(), vec![(2, 2)]);
+        }
+
+        #[test]
+        fn an_open_room_has_junctions_but_no_dead_ends() {
+            let maze = from_rows(&["#####", "#   #", "S   G", "#   #", "#####"]);
+            assert!(maze.dead_ends().is_empty());
+            // the corners of the room only have two ways out
+            assert_eq!(
+                maze.junctions(),
+                vec![(1, 2), (2, 1), (2, 2), (2, 3), (3, 2)]
+            );
+        }
+
+        #[test]
+        fn unsolving_gives_back_the_exact_bytes_for_every_solver() {
+            let mut maze = Maze::new_seeded((21, 21), CreationAlgorithm::Kruskal, 88);
+            let cells: Vec<(usize, usize)> = maze.passages().map(|(pos, _)| pos).collect();
+            maze.set_tag(cells[10], 3).unwrap();
+            maze.set_cost(cells[20], 6).unwrap();
+            let bytes = maze.to_bytes();
+            let json = maze.to_json();
+            for algorithm in [
+                SolvingAlgorithm::RecursiveBacktracking,
+                SolvingAlgorithm::IterativeBacktracking,
+                SolvingAlgorithm::DeadEndFilling,
+                SolvingAlgorithm::BreadthFirst,
+                SolvingAlgorithm::AStar(Heuristic::Manhattan),
+                SolvingAlgorithm::Dijkstra,
+                SolvingAlgorithm::WallFollowerLeft,
+                SolvingAlgorithm::WallFollowerRight,
+                SolvingAlgorithm::Tremaux,
+                SolvingAlgorithm::BidirectionalBfs,
+                SolvingAlgorithm::GreedyBestFirst,
+                SolvingAlgorithm::Pledge,
+                SolvingAlgorithm::RandomMouse {
+                    max_steps: 1_000_000,
+                },
+                SolvingAlgorithm::FloodFill,
+                SolvingAlgorithm::JumpPoint,
+                SolvingAlgorithm::Lee,
+                SolvingAlgorithm::IdaStar,
+                SolvingAlgorithm::ParallelBidirectional,
+            ] {
+                let mut solved = maze.clone();
+                solved.solve_from(algorithm).unwrap();
+                assert_ne!(solved.to_bytes(), bytes, "{:?}", algorithm);
+                assert!(solved.unsolve(), "{:?}", algorithm);
+                assert_eq!(solved.to_bytes(), bytes, "{:?}", algorithm);
+                assert_eq!(solved.to_json(), json, "{:?}", algorithm);
+                assert_eq!(solved, maze, "{:?}", algorithm);
+            }
+        }
+
+        #[test]
+        fn draining_the_steps_solves_like_solve_from() {
+            let maze = Maze::builder()
+                .dimensions(21, 21)
+                .algorithm(CreationAlgorithm::Kruskal)
+                .seed(90)
+                .braid(0.5)
+                .build()
+                .unwrap();
+            // the random mouse is left out, since it wanders differently every time
+            for algorithm in [
+                SolvingAlgorithm::RecursiveBacktracking,
+                SolvingAlgorithm::IterativeBacktracking,
+                SolvingAlgorithm::DeadEndFilling,
+                SolvingAlgorithm::BreadthFirst,
+                SolvingAlgorithm::AStar(Heuristic::Manhattan),
+                SolvingAlgorithm::Dijkstra,
+                SolvingAlgorithm::WallFollowerLeft,
+                SolvingAlgorithm::WallFollowerRight,
+                SolvingAlgorithm::Tremaux,
+                SolvingAlgorithm::BidirectionalBfs,
+                SolvingAlgorithm::GreedyBestFirst,
+                SolvingAlgorithm::Pledge,
+                SolvingAlgorithm::FloodFill,
+                SolvingAlgorithm::JumpPoint,
+                SolvingAlgorithm::Lee,
+                SolvingAlgorithm::IdaStar,
+                SolvingAlgorithm::ParallelBidirectional,
+            ] {
+                let mut batch = maze.clone();
+                let report = batch.solve_from(algorithm).unwrap();
+                let mut stepped = maze.clone();
+                let mut steps = stepped.solve_stepwise(algorithm);
+                let expected = steps.len();
+                let events: Vec<SolveEvent> = steps.by_ref().collect();
+                drop(steps);
+                assert_eq!(events.len(), expected, "{:?}", algorithm);
+                let Some(SolveEvent::Found(path)) = events.last() else {
+                    panic!("{:?} didn't end with Found", algorithm);
+                };
+                if algorithm == SolvingAlgorithm::ParallelBidirectional {
+                    // its two halves race each other, so it can find any one of the shortest paths
+                    assert!(Path::try_new(path.cells().to_vec()).is_ok());
+                    assert_eq!(path.len(), report.path.len());
+                    assert_eq!(stepped.solution(), Some(path.cells().to_vec()));
+                    continue;
+                }
+                assert_eq!(path, &report.path, "{:?}", algorithm);
+                assert_eq!(stepped, batch, "{:?}", algorithm);
+                assert_eq!(stepped.to_bytes(), batch.to_bytes(), "{:?}", algorithm);
+            }
+        }
+
+        #[test]
+        fn steps_with_no_way_through_end_without_a_solution() {
+            let mut maze = Maze::new_seeded((21, 21), CreationAlgorithm::Kruskal, 90);
+            let (_, exit) = maze.openings();
+            maze.remove_exit(exit).unwrap();
+            let events: Vec<SolveEvent> = maze
+                .solve_stepwise(SolvingAlgorithm::BreadthFirst)
+                .collect();
+            assert!(!events
+                .iter()
+                .any(|event| matches!(event, SolveEvent::Found(_))));
+            assert!(!maze.is_solved());
+        }
+
+        #[test]
+        fn tags_follow_their_cells_through_every_transform() {
+            let (height, width) = (15, 27);
+            let mut maze = Maze::new_seeded((height, width), CreationAlgorithm::Kruskal, 92);
+            let cells: Vec<(usize, usize)> = maze.passages().map(|(pos, _)| pos).collect();
+            let tagged = [(cells[5], 1), (cells[40], 2), (cells[90], 3)];
+            for (pos, tag) in tagged {
+                maze.set_tag(pos, tag).unwrap();
+            }
+            // where each transform moves a (y, x) cell to
+            type Move = fn((usize, usize)) -> (usize, usize);
+            let transforms: [(&str, Maze, Move); 5] = [
+                ("rotate_cw", maze.rotate_cw(), |(y, x)| (x, 14 - y)),
+                ("rotate_ccw", maze.rotate_ccw(), |(y, x)| (26 - x, y)),
+                ("mirror_horizontal", maze.mirror_horizontal(), |(y, x)| {
+                    (y, 26 - x)
+                }),
+                ("mirror_vertical", maze.mirror_vertical(), |(y, x)| {
+                    (14 - y, x)
+                }),
+                ("transpose", maze.transpose(), |(y, x)| (x, y)),
+            ];
+            for (name, transformed, map) in transforms {
+                assert_eq!(transformed.tagged().count(), 3, "{}", name);
+                for (pos, tag) in tagged {
+                    assert_eq!(transformed.get_tag(map(pos)), Some(tag), "{}", name);
+                }
+            }
+        }
+
+        #[test]
+        fn tags_inside_a_crop_are_kept_where_they_were() {
+            let mut maze = Maze::new_seeded((21, 31), CreationAlgorithm::Kruskal, 92);
+            let inside = maze
+                .passages()
+                .map(|(pos, _)| pos)
+                .find(|&(y, x)| (5..=9).contains(&y) && (5..=15).contains(&x))
+                .unwrap();
+            let outside = maze
+                .passages()
+                .map(|(pos, _)| pos)
+                .find(|&(y, _)| y > 15)
+                .unwrap();
+            maze.set_tag(inside, 4).unwrap();
+            maze.set_tag(outside, 5).unwrap();
+            let cropped = maze.crop((2, 2), (14, 20)).unwrap();
+            assert_eq!(cropped.get_tag((inside.0 - 2, inside.1 - 2)), Some(4));
+            assert_eq!(cropped.tagged().count(), 1);
+        }
+
+        #[test]
+        fn regenerating_a_region_drops_the_tags_inside_it() {
+            let mut maze = Maze::new_seeded((31, 31), CreationAlgorithm::Kruskal, 92);
+            let inside = maze
+                .passages()
+                .map(|(pos, _)| pos)
+                .find(|&(y, x)| (12..=18).contains(&y) && (12..=18).contains(&x))
+                .unwrap();
+            let outside = maze
+                .passages()
+                .map(|(pos, _)| pos)
+                .find(|&(y, _)| y > 25)
+                .unwrap();
+            maze.set_tag(inside, 6).unwrap();
+            maze.set_tag(outside, 7).unwrap();
+            maze.regenerate_region((10, 10), (20, 20), CreationAlgorithm::Prim)
+                .unwrap();
+            assert_eq!(maze.get_tag(inside), None);
+            assert_eq!(maze.get_tag(outside), Some(7));
+        }
+
+        #[test]
+        fn render_tags_labels_each_tagged_passage() {
+            let mut maze = from_rows(&["#####", "S   G", "#####"]);
+            maze.set_tag((1, 2), 9).unwrap();
+            let rendered = maze.render_tags(|tag| char::from_digit(tag, 10).unwrap());
+            let middle = rendered.lines().nth(1).unwrap();
+            assert!(middle.contains(" 9"), "{}", rendered);
+        }
+
+        #[test]
+        fn printing_a_solved_maze_looks_the_same_as_it_always_has() {
+            // what these mazes printed as before solutions moved onto an overlay
+            let expected = [
+                (
+                    CreationAlgorithm::Kruskal,
+                    98,
+                    [
+                        "#########",
+                        "S.  # # #",
+                        "#.### # #",
+                        "#.......#",
+                        "### ###.#",
+                        "#   #  .G",
+                        "#########",
+                    ],
+                ),
+                (
+                    CreationAlgorithm::Prim,
+                    7,
+                    [
+                        "#########",
+                        "S...    #",
+                        "# #.#####",
+                        "# #.....#",
+                        "# #####.#",
+                        "#     #.G",
+                        "#########",
+                    ],
+                ),
+                (
+                    CreationAlgorithm::RecursiveBacktracker,
+                    3,
+                    [
+                        "#########",
+                        "S.......#",
+                        "# #####.#",
+                        "# # #  .#",
+                        "# # # #.#",
+                        "# #   #.G",
+                        "#########",
+                    ],
+                ),
+            ];
+            let print = |rows: &[&str], glyph: fn(char) -> String| {
+                rows.iter()
+                    .map(|row| row.chars().map(glyph).collect::<String>() + "\u{a0}")
+                    .collect::<Vec<String>>()
+                    .join("\n")
+            };
+            colored::control::set_override(true);
+            for (algorithm, seed, rows) in expected {
+                let mut maze = Maze::new_seeded((7, 9), algorithm, seed);
+                maze.solve_from(SolvingAlgorithm::BreadthFirst).unwrap();
+                let colored = print(&rows, |c| match c {
+                    '#' => "\x1b[47;37m██\x1b[0m".to_string(),
+                    'S' => "\x1b[41;31m▒▒\x1b[0m".to_string(),
+                    'G' => "\x1b[42;32m░░\x1b[0m".to_string(),
+                    '.' => "\x1b[44;34m▓▓\x1b[0m".to_string(),
+                    _ => "\u{a0}\u{a0}".to_string(),
+                });
+                let plain = print(&rows, |c| match c {
+                    '#' => "██".to_string(),
+                    'S' => "▒▒".to_string(),
+                    'G' => "░░".to_string(),
+                    '.' => "▓▓".to_string(),
+                    _ => "\u{a0}\u{a0}".to_string(),
+                });
+                assert_eq!(format!("{}", maze), colored, "seed {}", seed);
+                assert_eq!(format!("{:#}", maze), plain, "seed {}", seed);
+            }
+        }
+
+        #[test]
+        fn structurally_equal_mazes_share_a_fingerprint() {
+            let maze = Maze::new_seeded((21, 21), CreationAlgorithm::Kruskal, 99);
+            let mut marked = Maze::new_seeded((21, 21), CreationAlgorithm::Kruskal, 99);
+            marked.solve();
+            let passage = marked.passages().nth(7).unwrap().0;
+            marked.set_tag(passage, 1).unwrap();
+            marked.set_cost(passage, 3).unwrap();
+            assert!(marked.structurally_equal(&maze));
+            let loaded = Maze::from_bytes(&marked.to_bytes()).unwrap();
+            let turned = maze.rotate_cw().rotate_cw().rotate_cw().rotate_cw();
+            for copy in [marked, loaded, turned] {
+                assert_eq!(copy.fingerprint(), maze.fingerprint());
+            }
+        }
+
+        #[test]
+        fn any_one_carve_or_fill_changes_the_fingerprint() {
+            let maze = Maze::new_seeded((21, 21), CreationAlgorithm::Kruskal, 99);
+            let fingerprint = maze.fingerprint();
+            let mut changed = 0;
+            for (y, x) in (0..21).flat_map(|y| (0..21).map(move |x| (y, x))) {
+                let mut copy = maze.clone();
+                let result = match maze[(y, x)] {
+                    CellKind::Wall => copy.carve((y, x)),
+                    _ => copy.fill((y, x)),
+                };
+                if result.is_ok() && !copy.structurally_equal(&maze) {
+                    assert_ne!(copy.fingerprint(), fingerprint, "({}, {})", y, x);
+                    changed += 1;
+                }
+            }
+            // every interior wall can be carved, and every dead end filled
+            assert!(changed > 150, "{}", changed);
+        }
+
+        #[test]
+        fn adding_an_exit_changes_the_fingerprint() {
+            let maze = Maze::new_seeded((21, 21), CreationAlgorithm::Kruskal, 99);
+            let mut more = maze.clone();
+            more.add_exit((0, 1)).unwrap();
+            assert_ne!(more.fingerprint(), maze.fingerprint());
         }
     }
 }
@@ -0,0 +1,200 @@
+/*
+ * A module for reading images in the netpbm formats--PBM, PGM, and PPM, in both their plain (text)
+ * and raw (binary) flavors--as grids of brightness values, which is all a Maze needs to take the
+ * shape of an image.
+ *
+ * Authors: Brandon Ikeler, Travis Hahn
+ */
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/*
+ * Reads the netpbm image at the given path, and returns the brightness of each of its pixels from
+ * 0 (black) to 255 (white), row by row. Color images are converted to their luminance.
+ */
+pub fn read_brightness(path: &Path) -> io::Result<Vec<Vec<u8>>> {
+    let bytes = fs::read(path)?;
+    let mut reader = Reader { bytes, pos: 0 };
+
+    let magic = reader.token()?;
+    let (plain, channels) = match magic.as_str() {
+        "P1" => (true, 0),
+        "P4" => (false, 0),
+        "P2" => (true, 1),
+        "P5" => (false, 1),
+        "P3" => (true, 3),
+        "P6" => (false, 3),
+        _ => return Err(invalid("not a netpbm image")),
+    };
+    let width = reader.number()?;
+    let height = reader.number()?;
+    // bitmaps have no maximum value, since every pixel is either black or white
+    let max = match channels {
+        0 => 1,
+        _ => reader.number()?,
+    };
+    if max == 0 || max > u16::MAX as usize {
+        return Err(invalid("maximum value out of range"));
+    }
+
+    // raw images have exactly one whitespace character between the header and the pixels
+    if !plain {
+        reader.pos += 1;
+    }
+
+    if width == 0 || height == 0 {
+        return Err(invalid("the image has no pixels"));
+    }
+    // every row takes up at least this many of the bytes left, so a header that claims more
+    // pixels than would fit is wrong, and mustn't be trusted with an allocation
+    let row_bytes = match (channels, plain) {
+        (0, false) => Some(width.div_ceil(8)),
+        (0, true) => Some(width),
+        _ => width.checked_mul(channels * if !plain && max > 255 { 2 } else { 1 }),
+    };
+    let left = reader.bytes.len().saturating_sub(reader.pos);
+    if row_bytes
+        .and_then(|row_bytes| row_bytes.checked_mul(height))
+        .is_none_or(|needed| needed > left)
+    {
+        return Err(invalid("the image is smaller than its header says"));
+    }
+
+    let mut pixels: Vec<Vec<u8>> = Vec::with_capacity(height);
+    for _ in 0..height {
+        let mut row: Vec<u8> = Vec::with_capacity(width);
+        match (channels, plain) {
+            // in a bitmap, 1 is black and 0 is white
+            (0, true) => {
+                for _ in 0..width {
+                    row.push(if reader.bit()? { 0 } else { 255 });
+                }
+            }
+            // raw bitmaps pack eight pixels into each byte, padding the end of each row
+            (0, false) => {
+                let packed = reader.raw(width.div_ceil(8))?;
+                for x in 0..width {
+                    let black = packed[x / 8] & (0x80 >> (x % 8)) != 0;
+                    row.push(if black { 0 } else { 255 });
+                }
+            }
+            _ => {
+                for _ in 0..width {
+                    let mut samples = [0; 3];
+                    for sample in samples.iter_mut().take(channels) {
+                        let value = if plain {
+                            reader.number()?
+                        } else {
+                            reader.sample(max)?
+                        };
+                        *sample = value.min(max) * 255 / max;
+                    }
+                    row.push(match channels {
+                        1 => samples[0] as u8,
+                        _ => {
+                            ((299 * samples[0] + 587 * samples[1] + 114 * samples[2]) / 1000) as u8
+                        }
+                    });
+                }
+            }
+        }
+        pixels.push(row);
+    }
+    Ok(pixels)
+}
+
+fn invalid(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/*
+ * Steps through the bytes of a netpbm image.
+ */
+struct Reader {
+    bytes: Vec<u8>,
+    pos: usize,
+}
+
+impl Reader {
+    /*
+     * Skips past any whitespace and comments (which run from # to the end of the line).
+     */
+    fn skip_whitespace(&mut self) {
+        while let Some(&byte) = self.bytes.get(self.pos) {
+            if byte == b'#' {
+                while self.bytes.get(self.pos).is_some_and(|&byte| byte != b'\n') {
+                    self.pos += 1;
+                }
+            } else if byte.is_ascii_whitespace() {
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+    }
+
+    /*
+     * Reads the next whitespace-separated token.
+     */
+    fn token(&mut self) -> io::Result<String> {
+        self.skip_whitespace();
+        let start = self.pos;
+        while self
+            .bytes
+            .get(self.pos)
+            .is_some_and(|byte| !byte.is_ascii_whitespace() && *byte != b'#')
+        {
+            self.pos += 1;
+        }
+        if start == self.pos {
+            return Err(invalid("unexpected end of image"));
+        }
+        Ok(String::from_utf8_lossy(&self.bytes[start..self.pos]).into_owned())
+    }
+
+    fn number(&mut self) -> io::Result<usize> {
+        self.token()?
+            .parse()
+            .map_err(|_| invalid("expected a number"))
+    }
+
+    /*
+     * Reads a single pixel of a plain bitmap, whose pixels don't need to be separated by
+     * whitespace.
+     */
+    fn bit(&mut self) -> io::Result<bool> {
+        self.skip_whitespace();
+        let bit = match self.bytes.get(self.pos) {
+            Some(b'0') => false,
+            Some(b'1') => true,
+            _ => return Err(invalid("expected a 0 or 1")),
+        };
+        self.pos += 1;
+        Ok(bit)
+    }
+
+    fn raw(&mut self, count: usize) -> io::Result<&[u8]> {
+        let start = self.pos;
+        if start + count > self.bytes.len() {
+            return Err(invalid("unexpected end of image"));
+        }
+        self.pos += count;
+        Ok(&self.bytes[start..self.pos])
+    }
+
+    /*
+     * Reads a single sample of a raw image, which takes up two bytes if the maximum value needs
+     * them.
+     */
+    fn sample(&mut self, max: usize) -> io::Result<usize> {
+        Ok(match max {
+            0..=255 => self.raw(1)?[0] as usize,
+            _ => {
+                let bytes = self.raw(2)?;
+                (bytes[0] as usize) << 8 | bytes[1] as usize
+            }
+        })
+    }
+}